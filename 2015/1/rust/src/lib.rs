@@ -0,0 +1,74 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(contents: &String) -> impl Display {
+        let mut counter = 0; // level counter
+        for c in contents.chars() {
+            match c {
+                '(' => counter += 1,
+                ')' => counter -= 1,
+                _ => continue,
+            }
+        }
+        counter
+    }
+
+    fn part2(contents: &String) -> impl Display {
+        let mut counter = 0; // first character to reach the basement (-1)
+        for (i, c) in contents.chars().enumerate() {
+            match c {
+                '(' => counter += 1,
+                ')' => counter -= 1,
+                _ => continue,
+            }
+            if counter < 0 {
+                return i + 1;
+            }
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn floor(directions: &str) -> String {
+        Day01::part1(&Day01::parse(directions).unwrap()).to_string()
+    }
+
+    fn basement(directions: &str) -> String {
+        Day01::part2(&Day01::parse(directions).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_final_floor() {
+        assert_eq!(floor("(())"), "0");
+        assert_eq!(floor("()()"), "0");
+        assert_eq!(floor("((("), "3");
+        assert_eq!(floor("(()(()("), "3");
+        assert_eq!(floor("))((((("), "3");
+        assert_eq!(floor("())"), "-1");
+        assert_eq!(floor("))("), "-1");
+        assert_eq!(floor(")))"), "-3");
+        assert_eq!(floor(")())())"), "-3");
+    }
+
+    #[test]
+    fn test_first_basement_position() {
+        assert_eq!(basement(")"), "1");
+        assert_eq!(basement("()())"), "5");
+    }
+}
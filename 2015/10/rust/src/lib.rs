@@ -0,0 +1,66 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+fn solve(data: &str, n_iter: usize) -> usize {
+    let mut text = data.to_string();
+
+    for _ in 0..n_iter {
+        let mut out_str = String::new();
+        let mut chars = text.chars().peekable();
+        let mut count = 1;
+
+        let mut prev = chars.next().unwrap();
+
+        while let Some(&next) = chars.peek() {
+            if next == prev {
+                count += 1;
+            } else {
+                out_str.push_str(&count.to_string());
+                out_str.push(prev);
+                count = 1;
+            }
+            prev = chars.next().unwrap();
+        }
+        out_str.push_str(&count.to_string());
+        out_str.push(prev);
+        text = out_str;
+    }
+    text.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(data: &String) -> impl Display {
+        solve(data, 40)
+    }
+
+    fn part2(data: &String) -> impl Display {
+        solve(data, 50)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_look_and_say() {
+        // 1 -> 11 -> 21 -> 1211 -> 111221 -> 312211
+        assert_eq!(solve("1", 1), 2);
+        assert_eq!(solve("11", 1), 2);
+        assert_eq!(solve("21", 1), 4);
+        assert_eq!(solve("1211", 1), 6);
+        assert_eq!(solve("111221", 1), 6);
+        assert_eq!(solve("1", 5), 6);
+    }
+}
@@ -1,5 +1,6 @@
+use aoc_common::{ParseError, Solution};
 use std::collections::HashSet;
-use std::{char, fs};
+use std::fmt::Display;
 
 const FORBIDEN: &[char] = &['i','o','l'];
 
@@ -64,8 +65,8 @@ fn generate_password(mut password: String) -> String {
             let mut chars: Vec<char> = password.chars().collect();
             chars[idx] = ((chars[idx] as u8 - b'a' + 1) % 26 + b'a') as char;
 
-            for j in (idx + 1)..chars.len() {
-                chars[j] = 'a';
+            for c in &mut chars[idx + 1..] {
+                *c = 'a';
             }
             password = chars.iter().collect();
             continue;
@@ -77,13 +78,42 @@ fn generate_password(mut password: String) -> String {
     }
 }
 
-fn main() {
-    let data = fs::read_to_string("../input.txt").expect("Error reading file").trim().to_string();
-    let sep = "=".repeat(20);
-    println!("{} Part 1 {}", sep, sep);
-    let password = generate_password(data.clone());
-    println!("Original: {}, updated: {}", data, password);
-    println!("{} Part 2 {}", sep, sep);
-    let new_password = generate_password("hepxxzaa".to_string());
-    println!("Original: {}, updated: {}", password.clone(), new_password);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(password: &String) -> impl Display {
+        generate_password(password.clone())
+    }
+
+    fn part2(password: &String) -> impl Display {
+        generate_password(generate_password(password.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirements() {
+        assert!(has_increasing_straight("hijklmmn"));
+        assert!(has_two_pairs("abbceffg"));
+        assert!(!has_increasing_straight("abbceffg"));
+        assert!(!has_two_pairs("abbcegjk"));
+    }
+
+    #[test]
+    fn test_next_password() {
+        assert_eq!(generate_password("abcdefgh".to_string()), "abcdffaa");
+        assert_eq!(generate_password("ghijklmn".to_string()), "ghjaabcc");
+    }
 }
@@ -0,0 +1,95 @@
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+use serde_json::Value;
+use std::fmt::Display;
+
+fn total_sum_1(data: &str) -> i32 {
+    let re = Regex::new(r"-?\d+").unwrap();
+    re.find_iter(data)
+    .filter_map(|m| m.as_str().parse::<i32>().ok())
+    .sum()
+}
+
+fn sum_non_red(data: &Value) -> i32 {
+    match data {
+        Value::Number(n)        => n.as_i64().unwrap_or(0) as i32,
+        Value::Array(arr) => arr.iter().map(sum_non_red).sum(),
+        Value::Object(obj) => {
+            if obj.values().any(|v| v == "red") {
+                return 0;
+            }
+            obj.values().map(sum_non_red).sum()
+        }
+        _ => 0,
+    }
+}
+
+fn total_sum_2(data: &str) -> i32 {
+    let json_data: Value = serde_json::from_str(data).unwrap_or(Value::Null);
+    sum_non_red(&json_data)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let data = input.trim();
+        // Part 2 walks the document, so reject anything that is not JSON now.
+        if let Err(err) = serde_json::from_str::<Value>(data) {
+            let line_start: usize = data
+                .split_inclusive('\n')
+                .take(err.line() - 1)
+                .map(str::len)
+                .sum();
+            let offset = (line_start + err.column().saturating_sub(1)).min(data.len());
+            let rest = &data[offset..];
+            let end = rest.char_indices().nth(16).map_or(rest.len(), |(i, _)| i);
+            return Err(ParseError::at(data, &rest[..end], "a JSON document"));
+        }
+        Ok(data.to_string())
+    }
+
+    fn part1(data: &String) -> impl Display {
+        total_sum_1(data)
+    }
+
+    fn part2(data: &String) -> impl Display {
+        total_sum_2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_json() {
+        let err = Day12::parse("[1,{\"c\":\"red\",\"b\"2},3]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 18, "2},3]"));
+    }
+
+    #[test]
+    fn test_sum_of_all_numbers() {
+        assert_eq!(total_sum_1("[1,2,3]"), 6);
+        assert_eq!(total_sum_1(r#"{"a":2,"b":4}"#), 6);
+        assert_eq!(total_sum_1("[[[3]]]"), 3);
+        assert_eq!(total_sum_1(r#"{"a":{"b":4},"c":-1}"#), 3);
+        assert_eq!(total_sum_1(r#"{"a":[-1,1]}"#), 0);
+        assert_eq!(total_sum_1(r#"[-1,{"a":1}]"#), 0);
+        assert_eq!(total_sum_1("[]"), 0);
+        assert_eq!(total_sum_1("{}"), 0);
+    }
+
+    #[test]
+    fn test_ignoring_red() {
+        assert_eq!(total_sum_2("[1,2,3]"), 6);
+        assert_eq!(total_sum_2(r#"[1,{"c":"red","b":2},3]"#), 4);
+        assert_eq!(total_sum_2(r#"{"d":"red","e":[1,2,3,4],"f":5}"#), 0);
+        assert_eq!(total_sum_2(r#"[1,"red",5]"#), 6);
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::{ParseError, Solution, parse};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type Preferences = HashMap<String, HashMap<String, i32>>;
+
+fn parse_line(line: &str) -> Result<(String, String, i32), ParseError> {
+    let person1 = parse::word(line, 0, "a guest")?.to_string();
+    let person2 = parse::word(line, 10, "a neighbour")?
+        .trim_end_matches('.')
+        .to_string();
+
+    let sign = parse::word(line, 2, "`gain` or `lose`")?;
+    let mut happiness = parse::number::<i32>(line, parse::word(line, 3, "a number of units")?)?;
+    match sign {
+        "gain" => {}
+        "lose" => happiness = -happiness,
+        _ => return Err(ParseError::at(line, sign, "`gain` or `lose`")),
+    }
+    Ok((person1, person2, happiness))
+}
+
+fn parse_preferences(data: &str) -> Result<Preferences, ParseError> {
+    let mut out_hash: Preferences = HashMap::new();
+
+    for (person1, person2, happiness) in parse::lines(data, parse_line)? {
+        out_hash
+            .entry(person1)
+            .or_default()
+            .insert(person2, happiness);
+    }
+    Ok(out_hash)
+}
+
+fn with_neutral_guest(preferences: &Preferences) -> Preferences {
+    let mut preferences = preferences.clone();
+    let mut all_guests: Vec<String> = preferences.keys().cloned().collect();
+
+    for prefs in preferences.values() {
+        for guest in prefs.keys() {
+            if !all_guests.contains(guest) {
+                all_guests.push(guest.clone());
+            }
+        }
+    }
+
+    let me = "me".to_string();
+    preferences.insert(me.clone(), HashMap::new());
+    for guest in &all_guests {
+        preferences.get_mut(&me).unwrap().insert(guest.clone(), 0);
+        preferences.entry(guest.clone()).or_default().insert(me.clone(), 0);
+    }
+    preferences
+}
+
+fn calculate_happines(
+    arrangement: &[&String], 
+    preferences: &HashMap<String, HashMap<String,i32>>) 
+    -> i32 {
+        let mut total_happines = 0;
+        let num_people = arrangement.len();
+
+        for i in 0..num_people {
+            let person = arrangement[i];
+            let left = arrangement[(i + num_people - 1) % num_people];
+            let rigth = arrangement[(i + 1) % num_people];
+
+            total_happines += preferences[person][left];
+            total_happines += preferences[person][rigth];
+        }
+        total_happines
+}
+
+fn find_best_seating(preferences: &HashMap<String, HashMap<String,i32>>) -> i32{
+    let people: Vec<&String> = preferences.keys().collect();
+    let mut max_happiness = i32::MIN;
+
+    for arrangement in people.iter().permutations(people.len()) {
+        let arrangement: Vec<&String> = arrangement.into_iter().cloned().collect();
+        max_happiness = max_happiness.max(calculate_happines(&arrangement, preferences));
+    }
+    max_happiness
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Input = Preferences;
+
+    fn parse(input: &str) -> Result<Preferences, ParseError> {
+        parse_preferences(input)
+    }
+
+    fn part1(preferences: &Preferences) -> impl Display {
+        find_best_seating(preferences)
+    }
+
+    fn part2(preferences: &Preferences) -> impl Display {
+        find_best_seating(&with_neutral_guest(preferences))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_malformed_preference() {
+        let err =
+            Day13::parse("Alice would win 54 happiness units by sitting next to Bob.").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 13, "win"));
+    }
+
+    #[test]
+    fn test_best_seating() {
+        let preferences = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(find_best_seating(&preferences), 330);
+    }
+
+    #[test]
+    fn test_neutral_guest() {
+        let preferences = with_neutral_guest(&Day13::parse(EXAMPLE).unwrap());
+        assert_eq!(preferences["me"].len(), 4);
+        assert!(preferences.values().all(|prefs| prefs.len() == 4));
+    }
+}
@@ -0,0 +1,117 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub struct Reindeer {
+    name: String,
+    speed: i32,
+    time: i32,
+    rest: i32,
+}
+
+fn parse_line(line: &str) -> Result<Reindeer, ParseError> {
+    Ok(Reindeer {
+        name: parse::word(line, 0, "a reindeer")?.to_string(),
+        speed: parse::number(line, parse::word(line, 3, "a speed")?)?,
+        time: parse::number(line, parse::word(line, 6, "a flying time")?)?,
+        rest: parse::number(line, parse::word(line, 13, "a resting time")?)?,
+    })
+}
+
+fn calculate_distance(reindeer: &Reindeer, seconds: i32) -> i32 {
+    let cycle_time = reindeer.time + reindeer.rest;
+    let full_cycles = seconds / cycle_time;
+    let remaining_time = seconds % cycle_time;
+
+    let mut total = full_cycles * (reindeer.speed * reindeer.time);
+    total += reindeer.speed * remaining_time.min(reindeer.time);
+    
+    total
+}
+
+fn get_fastest(reindeers: &[Reindeer], seconds: i32) -> (String, i32) {
+    let mut reindeer_list: Vec<(String, i32)> = Vec::new();
+
+    for info in reindeers {
+        let distance = calculate_distance(info, seconds);
+        reindeer_list.push((info.name.clone(), distance));
+    }
+    
+    reindeer_list.sort_by_key(|&(_, distance)| distance);
+
+    reindeer_list.pop().unwrap()
+}
+
+fn calculate_points(reindeers_info: &[Reindeer], seconds: i32) -> HashMap<String, i32> {
+    let mut points: HashMap<String, i32> = HashMap::new();
+
+    for reindeer in reindeers_info {
+        points.insert(reindeer.name.clone(), 0);
+    }
+
+    for second in 1..=seconds {
+        let mut distances = Vec::new();
+
+        for reindeer in reindeers_info {
+            let distance = calculate_distance(reindeer, second);
+            distances.push((reindeer.name.clone(), distance));
+        }
+
+        let max_distance = distances.iter().map(|(_,d)| *d).max().unwrap_or(0);
+
+        for (name, distance) in distances {
+            if distance == max_distance {
+                *points.entry(name).or_insert(0) += 1;
+            }
+        }
+    }
+    points
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+        parse::lines(input.trim(), parse_line)
+    }
+
+    fn part1(data: &Vec<Reindeer>) -> impl Display {
+        get_fastest(data, 2503).1
+    }
+
+    fn part2(data: &Vec<Reindeer>) -> impl Display {
+        let points = calculate_points(data, 2503);
+        points.into_values().max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn test_distance() {
+        let comet = parse_line(EXAMPLE.lines().next().unwrap()).unwrap();
+        assert_eq!(calculate_distance(&comet, 1), 14);
+        assert_eq!(calculate_distance(&comet, 10), 140);
+        assert_eq!(calculate_distance(&comet, 1000), 1120);
+        let reindeers = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(get_fastest(&reindeers, 1000), ("Comet".to_string(), 1120));
+    }
+
+    #[test]
+    fn test_points() {
+        let points = calculate_points(&Day14::parse(EXAMPLE).unwrap(), 1000);
+        assert_eq!(points["Dancer"], 689);
+        assert_eq!(points["Comet"], 312);
+    }
+}
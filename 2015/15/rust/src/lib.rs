@@ -1,6 +1,8 @@
-use std::{collections::HashMap, fs};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
-struct Ingredients {
+pub struct Ingredients {
     name:String,
     capacity:i32,
     durability:i32,
@@ -9,17 +11,23 @@ struct Ingredients {
     calories:i32,
 }
 
-fn parse(data: &str) -> Ingredients {
-    let binding = data.replace(",","");
-    let data: Vec<&str> = binding.split_whitespace().collect();
-    Ingredients {
-        name: data[0].trim_end_matches(':').to_string(),
-        capacity: data[2].parse::<i32>().expect("Error parsing capacity"),
-        durability: data[4].parse::<i32>().expect("Error parsing durability"),
-        flavor: data[6].parse::<i32>().expect("Error parsing flavor"),
-        texture: data[8].parse::<i32>().expect("Error parsing texture"),
-        calories: data[10].parse::<i32>().expect("Error parsing calories"),
-    }
+fn parse_ingredient(line: &str) -> Result<Ingredients, ParseError> {
+    let property = |index, expected| {
+        parse::number(
+            line,
+            parse::word(line, index, expected)?.trim_end_matches(','),
+        )
+    };
+    Ok(Ingredients {
+        name: parse::word(line, 0, "an ingredient")?
+            .trim_end_matches(':')
+            .to_string(),
+        capacity: property(2, "a capacity")?,
+        durability: property(4, "a durability")?,
+        flavor: property(6, "a flavor")?,
+        texture: property(8, "a texture")?,
+        calories: property(10, "calories")?,
+    })
 }
 
 fn calculate_score(amounts: &[i32], ingredients: &[Ingredients], target_calories: Option<i32>) -> i32 {
@@ -38,11 +46,10 @@ fn calculate_score(amounts: &[i32], ingredients: &[Ingredients], target_calories
         calories += amounts[i] * ingredients[i].calories;
     }
 
-    if let Some(target) = target_calories {
-        if calories != target {
+    if let Some(target) = target_calories
+        && calories != target {
             return 0;
         }
-    }
     let properties = [capacity, durability, flavor, texture];
     properties.iter().map(|&p| p.max(0)).product()
 }
@@ -104,18 +111,51 @@ fn calculate_max_score(
         (max_score, best_amounts)
 }
 
-fn main() {
-    let data: Vec<Ingredients> = fs::read_to_string("../input.txt")
-    .expect("Error reading input file")
-    .trim()
-    .lines()
-    .map(|l| parse(l))
-    .collect();
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Ingredients>;
 
-    let sep = "=".repeat(20);
-    let (max_score_1, best_amounts_1) = calculate_max_score(&data, 100, None);
-    println!("{} Part 1 {}\nmax_score: {}\nbest_amounts: {:?}", sep,sep, max_score_1, best_amounts_1);
+    fn parse(input: &str) -> Result<Vec<Ingredients>, ParseError> {
+        parse::lines(input.trim(), parse_ingredient)
+    }
+
+    fn part1(ingredients: &Vec<Ingredients>) -> impl Display {
+        calculate_max_score(ingredients, 100, None).0
+    }
 
-    let (max_score_2, best_amounts_2) = calculate_max_score(&data, 100, Some(500));
-    println!("{} Part 2 {}\nmax_score: {}\nbest_amounts: {:?}", sep,sep, max_score_2, best_amounts_2);
+    fn part2(ingredients: &Vec<Ingredients>) -> impl Display {
+        calculate_max_score(ingredients, 100, Some(500)).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn test_best_cookie() {
+        let ingredients = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_score(&[44, 56], &ingredients, None), 62842880);
+        let (score, amounts) = calculate_max_score(&ingredients, 100, None);
+        assert_eq!(score, 62842880);
+        assert_eq!(amounts["Butterscotch"], 44);
+        assert_eq!(amounts["Cinnamon"], 56);
+    }
+
+    #[test]
+    fn test_500_calorie_cookie() {
+        let ingredients = Day15::parse(EXAMPLE).unwrap();
+        let (score, amounts) = calculate_max_score(&ingredients, 100, Some(500));
+        assert_eq!(score, 57600000);
+        assert_eq!(amounts["Butterscotch"], 40);
+        assert_eq!(amounts["Cinnamon"], 60);
+    }
 }
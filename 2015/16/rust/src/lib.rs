@@ -1,11 +1,13 @@
-use std::{collections::HashMap, fs};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Sue {
     number: i32,
     properties: HashMap<String, i32>,
 }
 
-struct GiftAnalyzer {
+pub struct GiftAnalyzer {
     sues: Vec<Sue>,
 }
 
@@ -26,46 +28,23 @@ impl GiftAnalyzer {
     const GREATER_THAN_PROPS: [&'static str; 2] = ["cats", "trees"];
     const LESS_THAN_PROPS: [&'static str; 2] = ["pomeranians", "goldfish"];
 
-    pub fn new(path: &str) -> Self {
-        let mut sues = Vec::new();
-
-        let data = fs::read_to_string(path)
-            .expect("Error reading input file")
-            .trim()
-            .lines()
-            .map(|l| l.to_string())
-            .collect::<Vec<_>>();
-
-        for line in data {
-            let sue: Vec<&str> = line.split_whitespace().collect();
-
-            if sue.len() < 8 {
-                eprintln!("Skipping malformed line: {}", line);
-                continue;
-            }
-
-            let number = sue[1].trim_end_matches(':').parse::<i32>().unwrap();
-            let item_1_nm = sue[2].trim_end_matches(':').to_string();
-            let item_1_qtd = sue[3].trim_end_matches(',').parse::<i32>().unwrap();
-
-            let item_2_nm = sue[4].trim_end_matches(':').to_string();
-            let item_2_qtd = sue[5].trim_end_matches(',').parse::<i32>().unwrap();
-
-            let item_3_nm = sue[6].trim_end_matches(':').to_string();
-            let item_3_qtd = sue[7].trim_end_matches(',').parse::<i32>().unwrap();
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let sues = parse::lines(input.trim(), Self::parse_sue)?;
+        Ok(Self { sues })
+    }
 
-            let mut properties = HashMap::new();
-            properties.insert(item_1_nm, item_1_qtd);
-            properties.insert(item_2_nm, item_2_qtd);
-            properties.insert(item_3_nm, item_3_qtd);
+    fn parse_sue(line: &str) -> Result<Sue, ParseError> {
+        let number = parse::word(line, 1, "a Sue number")?.trim_end_matches(':');
+        let number = parse::number(line, number)?;
 
-            sues.push(Sue {
-                number,
-                properties,
-            });
+        let mut properties = HashMap::new();
+        for item in 0..3 {
+            let name = parse::word(line, 2 + 2 * item, "a compound")?.trim_end_matches(':');
+            let quantity = parse::word(line, 3 + 2 * item, "a quantity")?.trim_end_matches(',');
+            properties.insert(name.to_string(), parse::number(line, quantity)?);
         }
 
-        Self { sues }
+        Ok(Sue { number, properties })
     }
 
     fn is_exact_match(&self, sue: &Sue) -> bool {
@@ -74,11 +53,9 @@ impl GiftAnalyzer {
                 .iter()
                 .find(|&&(k, _)| k == prop)
                 .map(|&(_, v)| v)
-            {
-                if value != expected {
+                && value != expected {
                     return false;
                 }
-            }
         }
         true
     }
@@ -132,18 +109,44 @@ impl GiftAnalyzer {
     }
 }
 
-fn main() {
-    let analyzer = GiftAnalyzer::new("../input.txt");
-    let sep = "=".repeat(20);
-    let exact_match = analyzer.find_exact_match().unwrap();
-    println!(
-        "{} Part 1 {}\nThe aunt sue that got you the gift is {}",
-        sep, sep, exact_match
-    );
-
-    let range_match = analyzer.find_range_match().unwrap();
-    println!(
-        "{} Part 2 {}\nThe aunt sue that got you the gift is {}",
-        sep, sep, range_match
-    );
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+
+    type Input = GiftAnalyzer;
+
+    fn parse(input: &str) -> Result<GiftAnalyzer, ParseError> {
+        GiftAnalyzer::new(input)
+    }
+
+    fn part1(analyzer: &GiftAnalyzer) -> impl Display {
+        analyzer.find_exact_match().unwrap()
+    }
+
+    fn part2(analyzer: &GiftAnalyzer) -> impl Display {
+        analyzer.find_range_match().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The puzzle has no worked example, so these Sues are made up around the
+    // MFCSAM reading it gives.
+    const SUES: &str = "Sue 1: cars: 9, akitas: 3, goldfish: 0
+Sue 2: cats: 7, trees: 3, goldfish: 5
+Sue 3: cats: 8, trees: 4, goldfish: 4";
+
+    #[test]
+    fn test_exact_match() {
+        assert_eq!(GiftAnalyzer::new(SUES).unwrap().find_exact_match(), Some(2));
+    }
+
+    #[test]
+    fn test_range_match() {
+        assert_eq!(GiftAnalyzer::new(SUES).unwrap().find_range_match(), Some(3));
+    }
 }
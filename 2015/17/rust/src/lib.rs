@@ -1,5 +1,8 @@
-use std::{collections::HashMap, fs, num::ParseIntError};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
+const TARGET_VOLUME: i32 = 150;
 
 fn backtrack(
     index: usize, 
@@ -29,8 +32,7 @@ fn backtrack(
             containers);
 }
 
-fn find_combinations(containers: &mut Vec<i32>, target: i32) -> (i32, i32) {
-    containers.sort_by(|a,b| b.cmp(a));
+fn find_combinations(containers: &[i32], target: i32) -> (i32, i32) {
     let mut combinations_by_length: HashMap<i32, i32> = HashMap::new();
     
     backtrack(0, target, 0, &mut combinations_by_length, containers);
@@ -46,30 +48,40 @@ fn find_combinations(containers: &mut Vec<i32>, target: i32) -> (i32, i32) {
     (total_combinations, min_length_combinations)
 }
 
-fn parse_input(path: &str) -> Result<Vec<i32>, Box<dyn std::error::Error>> {
-    let data = fs::read_to_string(path)
-    .expect("Error reading input file")
-    .trim()
-    .lines()
-    .map(|n| n.parse::<i32>())
-    .collect::<Result<Vec<_>, ParseIntError>>()?;
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut data: Vec<i32> = parse::lines(input.trim(), |n| parse::number(n, n))?;
+    data.sort_by(|a, b| b.cmp(a));
     Ok(data)
 }
 
-fn main() {
-    let sep = "=".repeat(20);
-    let target_volume = 150;
-    
-    match parse_input("../input.txt") {
-        Ok(mut data) => {
-            if data.is_empty() {
-                println!("No valid data in file");
-                return;
-            }
-            let (total_combinations, min_combinations) = find_combinations(&mut data, target_volume);
-            println!("{} Part 1 {}\nTotal combinations: {}", sep, sep, total_combinations);
-            println!("{} Part 2 {}\nMinimum containers combinations: {}", sep, sep, min_combinations);
-        }
-        Err(e) => println!("Error parsing input file with error: {}", e),
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(containers: &Vec<i32>) -> impl Display {
+        find_combinations(containers, TARGET_VOLUME).0
+    }
+
+    fn part2(containers: &Vec<i32>) -> impl Display {
+        find_combinations(containers, TARGET_VOLUME).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combinations() {
+        let containers = parse_input("20\n15\n10\n5\n5\n").unwrap();
+        assert_eq!(find_combinations(&containers, 25), (4, 3));
     }
 }
@@ -0,0 +1,85 @@
+use aoc_common::{Grid, ParseError, Solution};
+use std::fmt::Display;
+
+fn gen_grid(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(
+        input.trim(),
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "`#` or `.`",
+    )
+}
+
+fn simulate_step(grid: &Grid<bool>, stuck: bool) -> Grid<bool> {
+    let (w, h) = (grid.width(), grid.height());
+    let corners = [(0, 0), (w - 1, 0), (0, h - 1), (w - 1, h - 1)];
+
+    let mut current = grid.clone();
+    if stuck {
+        for &corner in &corners {
+            current[corner] = true;
+        }
+    }
+
+    Grid::from_fn(w, h, |pos| {
+        if stuck && corners.contains(&pos) {
+            return true;
+        }
+        let n_lights = current.neighbors8(pos).filter(|&n| current[n]).count();
+        matches!((current[pos], n_lights), (true, 2) | (_, 3))
+    })
+}
+
+fn simulate_n_steps(grid: &Grid<bool>, n_steps: i32, stuck: bool) -> usize {
+    let mut grid = grid.clone();
+    for _ in 0..n_steps {
+        grid = simulate_step(&grid, stuck);
+    }
+    grid.count(|&on| on)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        gen_grid(input)
+    }
+
+    fn part1(grid: &Grid<bool>) -> impl Display {
+        simulate_n_steps(grid, 100, false)
+    }
+
+    fn part2(grid: &Grid<bool>) -> impl Display {
+        simulate_n_steps(grid, 100, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn test_animation() {
+        assert_eq!(simulate_n_steps(&gen_grid(EXAMPLE).unwrap(), 4, false), 4);
+    }
+
+    #[test]
+    fn test_stuck_corners() {
+        assert_eq!(simulate_n_steps(&gen_grid(EXAMPLE).unwrap(), 5, true), 17);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+fn parse_rule(line: &str) -> Result<(String, String), ParseError> {
+    let (from, to) = line
+        .split_once(" => ")
+        .ok_or_else(|| ParseError::at(line, line, "a replacement `A => B`"))?;
+    Ok((from.to_string(), to.to_string()))
+}
+
+fn parse_input(input: &str) -> Result<(Vec<(String, String)>, String), ParseError> {
+    let input = input.trim();
+    let (rules_data, molecule) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(
+            input,
+            &input[input.len()..],
+            "a blank line and the molecule",
+        )
+    })?;
+
+    let rules = parse::lines(rules_data, parse_rule)?;
+
+    Ok((rules, molecule.to_string()))
+}
+
+fn gen_replacements(molecule: &str, rules: &[(String, String)]) -> usize {
+    let mut molecules: HashSet<String> = HashSet::new();
+
+    for (from, to) in rules {
+        let mut start_index = 0;
+        while let Some(pos) = molecule[start_index..].find(from) {
+            let pos = start_index + pos;
+            let new_molecule = format!(
+                "{}{}{}",
+                &molecule[..pos],
+                to,
+                &molecule[pos + from.len()..],
+            );
+            molecules.insert(new_molecule);
+            start_index = pos + 1;
+        }
+    }
+
+    molecules.len()
+}
+
+fn gen_from_e(molecule: &str, rules: &[(String, String)]) -> i32 {
+    let mut steps = 0;
+    let mut current = molecule.to_string();
+
+    while current != "e" {
+        let mut replaced = false;
+        
+        for (from, to) in rules {
+            // `e` is only ever the starting molecule, never part of one
+            if from == "e" && current != *to {
+                continue;
+            }
+            if let Some(pos) = current.find(to) {
+                current = format!(
+                    "{}{}{}",
+                    &current[..pos],
+                    from,
+                    &current[pos + to.len()..]
+                );
+                steps += 1;
+                replaced = true;
+                break;
+            }
+        }
+        if !replaced {
+            return -1;
+        }
+    }
+    steps
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+
+    type Input = (Vec<(String, String)>, String);
+
+    fn parse(input: &str) -> Result<(Vec<(String, String)>, String), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<(String, String)>, String)) -> impl Display {
+        let (rules, molecule) = input;
+        gen_replacements(molecule, rules)
+    }
+
+    fn part2(input: &(Vec<(String, String)>, String)) -> impl Display {
+        let (rules, molecule) = input;
+        gen_from_e(molecule, rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = "e => H
+e => O
+H => HO
+H => OH
+O => HH";
+
+    #[test]
+    fn test_distinct_molecules() {
+        let (rules, _) = parse_input(&format!("{RULES}\n\nHOH")).unwrap();
+        assert_eq!(gen_replacements("HOH", &rules), 4);
+        assert_eq!(gen_replacements("HOHOHO", &rules), 7);
+    }
+
+    #[test]
+    fn test_fabrication_steps() {
+        let (rules, _) = parse_input(&format!("{RULES}\n\nHOH")).unwrap();
+        assert_eq!(gen_from_e("HOH", &rules), 3);
+        assert_eq!(gen_from_e("HOHOHO", &rules), 6);
+    }
+}
@@ -13,7 +13,7 @@
 
 
 // fn part_1() -> Result<(), Error>{
-//     let data: &Vec<String> = &vec_lines("../input.txt")?;
+//     let data: &Vec<String> = &vec_lines(path)?;
 //     let mut total: i32 = 0;
 //     for line in data.iter(){
 //         let dimension: Vec<i32> = line
@@ -36,7 +36,7 @@
 // }
 
 // fn part_2() -> Result<(), Error>{
-//     let data: &Vec<String> = &vec_lines("../input.txt")?;
+//     let data: &Vec<String> = &vec_lines(path)?;
 //     let mut total: i32 = 0;
 //     for line in data.iter(){
 //         let mut dimension: Vec<i32> = line
@@ -61,28 +61,30 @@
 //     part_2();
 //     }
 
-use std::fs;
-use std::io::Error;
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
 
-struct Present {
+pub struct Present {
     length: i32,
     width: i32,
     height: i32,
 }
 
 impl Present {
-    fn from_str(s: &str) -> Option<Self> {
-        let mut dims = s
-            .split('x')
-            .filter_map(|s| s.parse::<i32>().ok());
-        
-        match (dims.next(), dims.next(), dims.next()) {
-            (Some(l), Some(w), Some(h)) => Some(Present {
-                length: l,
-                width: w,
-                height: h,
-            }),
-            _ => None,
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let mut dims = line.split('x');
+        let mut next = || match dims.next() {
+            Some(dim) => parse::number(line, dim),
+            None => Err(ParseError::at(line, line, "dimensions `LxWxH`")),
+        };
+        let present = Present {
+            length: next()?,
+            width: next()?,
+            height: next()?,
+        };
+        match dims.next() {
+            Some(extra) => Err(ParseError::at(line, extra, "the end of the line")),
+            None => Ok(present),
         }
     }
 
@@ -105,16 +107,6 @@ impl Present {
     }
 }
 
-fn read_presents(file_path: &str) -> Result<Vec<Present>, Error> {
-    fs::read_to_string(file_path)?
-        .lines()
-        .filter_map(Present::from_str)
-        .collect::<Vec<_>>()
-        .into_iter()
-        .map(Ok)
-        .collect()
-}
-
 fn calculate_total_paper(presents: &[Present]) -> i32 {
     presents.iter().map(Present::surface_area).sum()
 }
@@ -123,14 +115,47 @@ fn calculate_total_ribbon(presents: &[Present]) -> i32 {
     presents.iter().map(Present::ribbon_length).sum()
 }
 
-fn main() -> Result<(), Error> {
-    let presents = read_presents("../input.txt")?;
-    
-    println!("Part 1");
-    println!("{}", calculate_total_paper(&presents));
-    
-    println!("Part 2");
-    println!("{}", calculate_total_ribbon(&presents));
-    
-    Ok(())
-}
\ No newline at end of file
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Present>;
+
+    fn parse(input: &str) -> Result<Vec<Present>, ParseError> {
+        parse::lines(input, Present::parse)
+    }
+
+    fn part1(presents: &Vec<Present>) -> impl Display {
+        calculate_total_paper(presents)
+    }
+
+    fn part2(presents: &Vec<Present>) -> impl Display {
+        calculate_total_ribbon(presents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_paper() {
+        assert_eq!(Present::parse("2x3x4").unwrap().surface_area(), 58);
+        assert_eq!(Present::parse("1x1x10").unwrap().surface_area(), 43);
+    }
+
+    #[test]
+    fn test_ribbon() {
+        assert_eq!(Present::parse("2x3x4").unwrap().ribbon_length(), 34);
+        assert_eq!(Present::parse("1x1x10").unwrap().ribbon_length(), 14);
+    }
+
+    #[test]
+    fn test_totals() {
+        let presents = Day02::parse("2x3x4\n1x1x10\n").unwrap();
+        assert_eq!(calculate_total_paper(&presents), 58 + 43);
+        assert_eq!(calculate_total_ribbon(&presents), 34 + 14);
+    }
+}
@@ -1,5 +1,5 @@
-use std::fs;
-
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
 
 fn min_houses(target: &i32) -> i32 {
     let max_house = target / 10;
@@ -11,7 +11,7 @@ fn min_houses(target: &i32) -> i32 {
         }
     }
     for (house, total) in presents.iter().enumerate(){
-        if total >= &target{
+        if total >= target{
             return house as i32;
         }
     }
@@ -33,23 +33,45 @@ fn min_11_houses(target: &i32) -> i32 {
         }
     }
     for (house, total) in presents.iter().enumerate(){
-        if total >= &target{
+        if total >= target{
             return house as i32;
         }
     }
     -1
 }
 
-fn main() {
-    let data = fs::read_to_string("../input.txt")
-    .expect("Error reading input file")
-    .trim()
-    .parse::<i32>()
-    .expect("Failed to parse input to i32");
-
-    let sep = "=".repeat(20);
-    let part_1 = min_houses(&data);
-    let part_2 = min_11_houses(&data);
-    println!("{} Part 1 {}\nThe first house to recive at least {} presents is house nº {}", sep, sep, data, part_1);
-    println!("{} Part 2 {}\nThe first house to recive at least {} presents is house nº {}", sep, sep, data, part_2);
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, ParseError> {
+        parse::number(input, input.trim())
+    }
+
+    fn part1(target: &i32) -> impl Display {
+        min_houses(target)
+    }
+
+    fn part2(target: &i32) -> impl Display {
+        min_11_houses(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_houses() {
+        // House 1 gets 10 presents, 2 gets 30, 3 gets 40, 4 gets 70, 5 gets 60,
+        // 6 gets 120, 7 gets 80, 8 gets 150 and 9 gets 130.
+        assert_eq!(min_houses(&10), 1);
+        assert_eq!(min_houses(&70), 4);
+        assert_eq!(min_houses(&120), 6);
+        assert_eq!(min_houses(&130), 8);
+    }
 }
@@ -1,8 +1,34 @@
-use std::{collections::HashMap, fs};
+use aoc_common::{ParseError, Solution, parse};
 use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const SHOP: &str = "\
+Weapons:    Cost  Damage  Armor
+Dagger        8     4       0
+Shortsword   10     5       0
+Warhammer    25     6       0
+Longsword    40     7       0
+Greataxe     74     8       0
+
+Armor:      Cost  Damage  Armor
+Leather      13     0       1
+Chainmail    31     0       2
+Splintmail   53     0       3
+Bandedmail   75     0       4
+Platemail   102     0       5
+
+Rings:      Cost  Damage  Armor
+Damage +1    25     1       0
+Damage +2    50     2       0
+Damage +3   100     3       0
+Defense +1   20     0       1
+Defense +2   40     0       2
+Defense +3   80     0       3
+";
 
 #[derive(Copy,Clone)]
-struct Entity {
+pub struct Entity {
     hp: i32,
     armor: i32,
     dmg: i32,
@@ -25,12 +51,28 @@ impl Entity {
     }
 }
 
-fn simulate_combat(player_stats: HashMap<String, i32>) -> bool {
+fn parse_boss(input: &str) -> Result<Entity, ParseError> {
+    let stat = |name: &str| -> Result<i32, ParseError> {
+        let value = input
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .ok_or_else(|| {
+                ParseError::at(input, &input[input.len()..], format!("a `{name}: N` line"))
+            })?;
+        parse::number(input, value.trim_start_matches(':').trim())
+    };
+    Ok(Entity::new(
+        stat("Hit Points")?,
+        stat("Armor")?,
+        stat("Damage")?,
+    ))
+}
+
+fn simulate_combat(player_stats: HashMap<String, i32>, mut boss: Entity) -> bool {
     let mut player = Entity::new(
         100,
         *player_stats.get("Armor").unwrap_or(&0), 
         *player_stats.get("Damage").unwrap_or(&0));
-    let mut boss = Entity::new(109, 2, 8);
     
     loop {
         player.attack(&mut boss);
@@ -45,13 +87,10 @@ fn simulate_combat(player_stats: HashMap<String, i32>) -> bool {
     }
 }
 
-fn parse_items(path: &str) ->
-(HashMap<String, HashMap<String, i32>>, 
-HashMap<String, HashMap<String, i32>>, 
-HashMap<String, HashMap<String, i32>>) {
-    let binding = fs::read_to_string(path)
-    .expect("Error parsing input");
-    let data: Vec<&str> = binding
+type Items = HashMap<String, HashMap<String, i32>>;
+
+fn parse_items(shop: &str) -> (Items, Items, Items) {
+    let data: Vec<&str> = shop
     .trim()
     .split("\n\n")
     .collect();
@@ -64,18 +103,15 @@ HashMap<String, HashMap<String, i32>>) {
         let lines: Vec<&str> = cat.lines().collect();
 
         for item in &lines[1..]{
-            let info: Vec<&str> = if i == 2 {
-                item.split("   ").collect()
-            } else {
-                item.split_whitespace().collect()
-            };
+            let info: Vec<&str> = item.split_whitespace().collect();
 
             if info.len() < 4 {continue;}
 
-            let name = info[0].trim().to_string();
-            let cost = info[1].trim().parse::<i32>().unwrap_or(0);
-            let damage = info[2].trim().parse::<i32>().unwrap_or(0);
-            let armor = info[3].trim().parse::<i32>().unwrap_or(0);
+            let n = info.len();
+            let name = info[..n - 3].join(" ");
+            let cost = info[n - 3].parse::<i32>().unwrap_or(0);
+            let damage = info[n - 2].parse::<i32>().unwrap_or(0);
+            let armor = info[n - 1].parse::<i32>().unwrap_or(0);
 
             let entry = HashMap::from([
                 ("Cost".to_string(), cost),
@@ -166,6 +202,7 @@ fn find_optimal_equipment(
 weapons: &HashMap<String, HashMap<String, i32>>,
 armors: &HashMap<String, HashMap<String, i32>>,
 rings: &HashMap<String, HashMap<String, i32>>,
+boss: Entity,
 optimize_for_cost: bool,
 ) -> (Vec<String>, HashMap<String, i32>){
     let mut optimal_comb = Vec::new();
@@ -174,7 +211,7 @@ optimize_for_cost: bool,
 
     for combo in gen_item_combinations(weapons, armors, rings){
         let stats = calculate_stats(&combo, weapons, armors, rings);
-        let player_wins = simulate_combat(stats.clone());
+        let player_wins = simulate_combat(stats.clone(), boss);
 
         if (optimize_for_cost && player_wins && stats["Cost"] < optimal_cost)
         || (!optimize_for_cost && !player_wins && stats["Cost"] > optimal_cost) 
@@ -187,20 +224,60 @@ optimize_for_cost: bool,
     (optimal_comb, optimal_stats)
 }
 
-fn main() {
-    let sep = "=".repeat(20);
-    let (weapons, armors, rings) = parse_items("../items.txt");
-    
-    let (winning_combo, winning_stats) = find_optimal_equipment(&weapons, &armors, &rings, true);
-    
-    println!("{} Part 1 {}", sep, sep);
-    println!("Cheapest winning combination: {:?}", winning_combo);
-    println!("Stats: {:?}", winning_stats);
-    println!("Total cost: {} gold\n", winning_stats["Cost"]);
-    
-    println!("{} Part 2 {}", sep, sep);
-    let (losing_combo, losing_stats) = find_optimal_equipment(&weapons, &armors, &rings, false);
-    println!("Most expensive losing combination: {:?}", losing_combo);
-    println!("Stats: {:?}", losing_stats);
-    println!("Total cost: {} gold", losing_stats["Cost"]);
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+
+    type Input = Entity;
+
+    fn parse(input: &str) -> Result<Entity, ParseError> {
+        parse_boss(input)
+    }
+
+    fn part1(boss: &Entity) -> impl Display {
+        let (weapons, armors, rings) = parse_items(SHOP);
+        let (_, winning_stats) = find_optimal_equipment(&weapons, &armors, &rings, *boss, true);
+        winning_stats["Cost"]
+    }
+
+    fn part2(boss: &Entity) -> impl Display {
+        let (weapons, armors, rings) = parse_items(SHOP);
+        let (_, losing_stats) = find_optimal_equipment(&weapons, &armors, &rings, *boss, false);
+        losing_stats["Cost"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_fight() {
+        let mut player = Entity::new(8, 5, 5);
+        let mut boss = Entity::new(12, 2, 7);
+        let mut rounds = Vec::new();
+        loop {
+            player.attack(&mut boss);
+            if !boss.is_alive() {
+                break;
+            }
+            boss.attack(&mut player);
+            rounds.push((player.hp, boss.hp));
+            assert!(player.is_alive());
+        }
+        assert_eq!(rounds, [(6, 9), (4, 6), (2, 3)]);
+        assert_eq!(boss.hp, 0);
+    }
+
+    #[test]
+    fn test_parse() {
+        let boss = parse_boss("Hit Points: 12\nDamage: 7\nArmor: 2\n").unwrap();
+        assert_eq!((boss.hp, boss.dmg, boss.armor), (12, 7, 2));
+
+        let (weapons, armors, rings) = parse_items(SHOP);
+        assert_eq!((weapons.len(), armors.len(), rings.len()), (5, 5, 6));
+        assert_eq!(rings["Defense +2"]["Cost"], 40);
+    }
 }
@@ -0,0 +1,175 @@
+use aoc_common::{ParseError, Solution, parse, search};
+use std::fmt::Display;
+
+#[derive(Clone, Eq, PartialEq)]
+struct Spell {
+    cost: i32,
+    damage: i32,
+    heal: i32,
+    armor: i32,
+    mana_gain: i32,
+    duration: i32,
+}
+
+// Magic Missile, Drain, Shield, Poison and Recharge.
+const SPELLS: [Spell; 5] = [
+    Spell { cost: 53, damage: 4, heal: 0, armor: 0, mana_gain: 0, duration: 0 },
+    Spell { cost: 73, damage: 2, heal: 2, armor: 0, mana_gain: 0, duration: 0 },
+    Spell { cost: 113, damage: 0, heal: 0, armor: 7, mana_gain: 0, duration: 6 },
+    Spell { cost: 173, damage: 3, heal: 0, armor: 0, mana_gain: 0, duration: 6 },
+    Spell { cost: 229, damage: 0, heal: 0, armor: 0, mana_gain: 101, duration: 5 },
+];
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct GameState {
+    player_hp: i32,
+    player_mana: i32,
+    boss_hp: i32,
+    // Turns left on the effect of each spell of `SPELLS`
+    timers: [i32; 5],
+}
+
+impl GameState {
+    // Applies the active effects and returns the player's armor for the turn
+    fn apply_effects(&mut self) -> i32 {
+        let mut armor = 0;
+        for (spell, timer) in SPELLS.iter().zip(&mut self.timers) {
+            if *timer > 0 {
+                self.boss_hp -= spell.damage;
+                self.player_mana += spell.mana_gain;
+                armor += spell.armor;
+                *timer -= 1;
+            }
+        }
+        armor
+    }
+
+    // Every state a round (the player's turn, then the boss's) can lead to,
+    // with the mana spent on it. A fight is won once the boss is at 0 HP.
+    fn rounds(&self, boss_damage: i32, hard_mode: bool) -> Vec<(GameState, i32)> {
+        let mut state = self.clone();
+        if hard_mode {
+            state.player_hp -= 1;
+            if state.player_hp <= 0 {
+                return Vec::new();
+            }
+        }
+
+        state.apply_effects();
+        if state.boss_hp <= 0 {
+            return vec![(state, 0)];
+        }
+
+        let mut next = Vec::new();
+        for (i, spell) in SPELLS.iter().enumerate() {
+            if spell.cost > state.player_mana || state.timers[i] > 0 {
+                continue;
+            }
+            let mut new_state = state.clone();
+            new_state.player_mana -= spell.cost;
+
+            if spell.duration > 0 {
+                new_state.timers[i] = spell.duration;
+            } else {
+                new_state.boss_hp -= spell.damage;
+                new_state.player_hp += spell.heal;
+            }
+
+            if new_state.boss_hp > 0 {
+                let armor = new_state.apply_effects();
+                if new_state.boss_hp > 0 {
+                    new_state.player_hp -= (boss_damage - armor).max(1);
+                }
+            }
+
+            if new_state.boss_hp <= 0 || new_state.player_hp > 0 {
+                next.push((new_state, spell.cost));
+            }
+        }
+        next
+    }
+}
+
+pub struct Boss {
+    hp: i32,
+    damage: i32,
+}
+
+fn parse_boss(input: &str) -> Result<Boss, ParseError> {
+    let stat = |name: &str| -> Result<i32, ParseError> {
+        let value = input
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .ok_or_else(|| {
+                ParseError::at(input, &input[input.len()..], format!("a `{name}: N` line"))
+            })?;
+        parse::number(input, value.trim_start_matches(':').trim())
+    };
+    Ok(Boss {
+        hp: stat("Hit Points")?,
+        damage: stat("Damage")?,
+    })
+}
+
+fn find_least_mana_to_win(player_hp: i32, player_mana: i32, boss_hp: i32, boss_damage: i32, hard_mode: bool) -> i32 {
+    let initial_state = GameState {
+        player_hp,
+        player_mana,
+        boss_hp,
+        timers: [0; 5],
+    };
+
+    search::dijkstra(
+        initial_state,
+        |state| state.rounds(boss_damage, hard_mode),
+        |state| state.boss_hp <= 0,
+    )
+    .map_or(i32::MAX, |found| found.cost)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 22;
+
+    type Input = Boss;
+
+    fn parse(input: &str) -> Result<Boss, ParseError> {
+        parse_boss(input)
+    }
+
+    fn part1(boss: &Boss) -> impl Display {
+        find_least_mana_to_win(50, 500, boss.hp, boss.damage, false)
+    }
+
+    fn part2(boss: &Boss) -> impl Display {
+        find_least_mana_to_win(50, 500, boss.hp, boss.damage, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_example_fight() {
+        // Poison, then Magic Missile.
+        assert_eq!(find_least_mana_to_win(10, 250, 13, 8, false), 173 + 53);
+    }
+
+    #[test]
+    fn test_second_example_fight() {
+        // Recharge, Shield, Drain, Poison, then Magic Missile.
+        assert_eq!(
+            find_least_mana_to_win(10, 250, 14, 8, false),
+            229 + 113 + 73 + 173 + 53
+        );
+    }
+
+    #[test]
+    fn test_parse_boss() {
+        let boss = parse_boss("Hit Points: 13\nDamage: 8\n").unwrap();
+        assert_eq!((boss.hp, boss.damage), (13, 8));
+    }
+}
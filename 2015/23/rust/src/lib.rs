@@ -0,0 +1,53 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::turing::{self, Instruction};
+use aoc_vm::{Machine, Word};
+use std::fmt::Display;
+
+fn run_program(program: &[Instruction], a: Word) -> Word {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_register('a', a);
+    machine.run();
+    machine.register('b')
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        turing::parse(input)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> impl Display {
+        run_program(program, 0)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> impl Display {
+        run_program(program, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_program() {
+        let program = Day23::parse("inc a\njio a, +2\ntpl a\ninc a").unwrap();
+        let mut machine = Machine::new(program);
+        machine.run();
+        assert_eq!((machine.register('a'), machine.register('b')), (2, 0));
+    }
+
+    #[test]
+    fn test_malformed_program() {
+        let err = Day23::parse("inc a\njio c, +2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "c,"));
+        let err = Day23::parse("inc a\njmp +x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "+x"));
+    }
+}
@@ -1,11 +1,12 @@
+use aoc_common::{ParseError, Solution, parse};
 use itertools::Itertools;
-use std::fs;
+use std::fmt::Display;
 
-fn calc_qe(group: &Vec<u64>) -> u64 {
+fn calc_qe(group: &[u64]) -> u64 {
     group.iter().product()
 }
 
-fn find_valid_groups(numbers: &Vec<u64>, target: u64, max_size: usize) -> Vec<Vec<u64>> {
+fn find_valid_groups(numbers: &[u64], target: u64, max_size: usize) -> Vec<Vec<u64>> {
     let mut valid_groups = Vec::new();
 
     for size in 1..=max_size {
@@ -22,7 +23,7 @@ fn find_valid_groups(numbers: &Vec<u64>, target: u64, max_size: usize) -> Vec<Ve
     valid_groups
 }
 
-fn can_split_remaining(numbers: &Vec<u64>, target: u64, groups_left: usize) -> bool {
+fn can_split_remaining(numbers: &[u64], target: u64, groups_left: usize) -> bool {
     if groups_left == 0 {
         return numbers.is_empty();
     }
@@ -51,15 +52,15 @@ fn can_split_remaining(numbers: &Vec<u64>, target: u64, groups_left: usize) -> b
     false
 }
 
-fn match_groups(presents: Vec<u64>, compartments: usize) -> Option<(u64, Vec<u64>)> {
+fn match_groups(presents: &[u64], compartments: usize) -> Option<(u64, Vec<u64>)> {
     let total_weight: u64 = presents.iter().sum();
-    if total_weight % compartments as u64 != 0 {
+    if !total_weight.is_multiple_of(compartments as u64) {
         return None;
     }
     let target_w = total_weight / compartments as u64;
     let max_group_size = presents.len() / compartments;
 
-    let mut first_groups = find_valid_groups(&presents, target_w, max_group_size);
+    let mut first_groups = find_valid_groups(presents, target_w, max_group_size);
     first_groups.sort_by_key(|g| calc_qe(g));
 
     for g1 in &first_groups {
@@ -75,20 +76,40 @@ fn match_groups(presents: Vec<u64>, compartments: usize) -> Option<(u64, Vec<u64
     None
 }
 
-fn main() {
-    let data: Vec<u64> = fs::read_to_string("../input.txt")
-        .expect("Failed to read file")
-        .lines()
-        .filter_map(|x| x.parse().ok())
-        .collect();
+pub struct Day24;
 
-    println!("==================== Part 1 ====================");
-    if let Some((qe, group)) = match_groups(data.clone(), 3) {
-        println!("QE: {}, First Group: {:?}", qe, group);
+impl Solution for Day24 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        parse::lines(input, |line| parse::number(line, line))
+    }
+
+    fn part1(presents: &Vec<u64>) -> impl Display {
+        match_groups(presents, 3).expect("No valid grouping found").0
+    }
+
+    fn part2(presents: &Vec<u64>) -> impl Display {
+        match_groups(presents, 4).expect("No valid grouping found").0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRESENTS: [u64; 10] = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+    #[test]
+    fn test_three_compartments() {
+        assert_eq!(match_groups(&PRESENTS, 3), Some((99, vec![9, 11])));
     }
 
-    println!("==================== Part 2 ====================");
-    if let Some((qe, group)) = match_groups(data, 4) {
-        println!("QE: {}, First Group: {:?}", qe, group);
+    #[test]
+    fn test_four_compartments() {
+        assert_eq!(match_groups(&PRESENTS, 4), Some((44, vec![4, 11])));
     }
 }
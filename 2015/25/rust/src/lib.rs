@@ -0,0 +1,72 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn calc_value(x: u64) -> u64 {
+    (x * 252533) % 33554393
+}
+
+fn get_code(r: u64, c: u64) -> u64 {
+    let mut current_value: u64 = 20151125;
+    let target_index: u64 = (1..(r + c)).sum::<u64>() - (r - 1);
+
+    for _ in 1..target_index {
+        current_value = calc_value(current_value);
+    }
+    current_value
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        let number = |label: &str| -> Result<u64, ParseError> {
+            let (_, rest) = input
+                .split_once(label)
+                .ok_or_else(|| ParseError::at(input, input.trim_end(), format!("`{label} N`")))?;
+            let digits = rest.trim_start();
+            let end = digits
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(digits.len());
+            parse::number(input, &digits[..end])
+        };
+        Ok((number("row")?, number("column")?))
+    }
+
+    fn part1(position: &(u64, u64)) -> impl Display {
+        let &(row, column) = position;
+        get_code(row, column)
+    }
+
+    fn part2(_position: &(u64, u64)) -> impl Display {
+        // There is no second puzzle on the last day.
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_table() {
+        assert_eq!(get_code(1, 1), 20151125);
+        assert_eq!(get_code(2, 1), 31916031);
+        assert_eq!(get_code(1, 2), 18749137);
+        assert_eq!(get_code(4, 4), 9380097);
+        assert_eq!(get_code(5, 1), 77061);
+        assert_eq!(get_code(6, 6), 27995004);
+        assert_eq!(get_code(1, 6), 33511524);
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let input = "To continue, please consult the code grid in the manual.  \
+                     Enter the code at row 2947, column 3029.";
+        assert_eq!(Day25::parse(input).unwrap(), (2947, 3029));
+    }
+}
@@ -1,5 +1,6 @@
+use aoc_common::{ParseError, Solution};
 use std::collections::HashSet;
-use std::fs;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Position {
@@ -95,22 +96,47 @@ impl DeliverySystem {
     }
 }
 
-fn read_input(file_path: &str) -> Result<String, std::io::Error> {
-    fs::read_to_string(file_path)
-}
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(movements: &String) -> impl Display {
+        // Santa alone
+        DeliverySystem::new().deliver_presents(movements)
+    }
 
-fn main() -> Result<(), std::io::Error> {
-    let movements = read_input("../input.txt")?;
+    fn part2(movements: &String) -> impl Display {
+        // Santa and Robo-Santa
+        DeliverySystem::new().deliver_presents_with_robo(movements)
+    }
+}
 
-    // Part 1: Santa alone
-    let mut delivery_system = DeliverySystem::new();
-    let houses_visited = delivery_system.deliver_presents(&movements);
-    println!("Part 1: {}", houses_visited);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Part 2: Santa and Robo-Santa
-    let mut delivery_system = DeliverySystem::new();
-    let houses_visited = delivery_system.deliver_presents_with_robo(&movements);
-    println!("Part 2: {}", houses_visited);
+    #[test]
+    fn test_santa_alone() {
+        assert_eq!(DeliverySystem::new().deliver_presents(">"), 2);
+        assert_eq!(DeliverySystem::new().deliver_presents("^>v<"), 4);
+        assert_eq!(DeliverySystem::new().deliver_presents("^v^v^v^v^v"), 2);
+    }
 
-    Ok(())
-}
\ No newline at end of file
+    #[test]
+    fn test_santa_and_robo_santa() {
+        assert_eq!(DeliverySystem::new().deliver_presents_with_robo("^v"), 3);
+        assert_eq!(DeliverySystem::new().deliver_presents_with_robo("^>v<"), 3);
+        assert_eq!(
+            DeliverySystem::new().deliver_presents_with_robo("^v^v^v^v^v"),
+            11
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use aoc_common::{ParseError, Solution};
+use aoc_hash::mining;
+use std::fmt::Display;
+
+// The lowest number that, after the key, hashes to `n_zeros` leading zeros.
+fn brute_force_check(data: &str, n_zeros: usize) -> u64 {
+    let (answer, ()) = mining::search(data, 0, |digest| {
+        digest.starts_with_zeros(n_zeros).then_some(())
+    })
+    .next()
+    .expect("the search never ends");
+    answer
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(key: &String) -> impl Display {
+        brute_force_check(key, 5)
+    }
+
+    fn part2(key: &String) -> impl Display {
+        brute_force_check(key, 6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_five_zeros() {
+        assert_eq!(brute_force_check("abcdef", 5), 609043);
+        assert_eq!(brute_force_check("pqrstuv", 5), 1048970);
+    }
+}
@@ -1,5 +1,5 @@
-use std::fs::read_to_string;
-use std::path::Path;
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct StringChecker;
@@ -66,16 +66,52 @@ fn part2(strings: &[String]) -> usize {
         .count()
 }
 
-fn main() -> std::io::Result<()> {
-    let input_path = Path::new("../input.txt");
-    let content = read_to_string(input_path)?;
-    let strings: Vec<String> = content.lines().map(String::from).collect();
+pub struct Day05;
 
-    let nice_count_part1 = part1(&strings);
-    println!("Part 1 - Number of nice strings: {}", nice_count_part1);
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
 
-    let nice_count_part2 = part2(&strings);
-    println!("Part 2 - Number of nice strings: {}", nice_count_part2);
+    type Input = Vec<String>;
 
-    Ok(())
-}
\ No newline at end of file
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(strings: &Vec<String>) -> impl Display {
+        part1(strings)
+    }
+
+    fn part2(strings: &Vec<String>) -> impl Display {
+        part2(strings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nice_strings_part1() {
+        assert!(StringChecker::is_nice_string_part1("ugknbfddgicrmopn"));
+        assert!(StringChecker::is_nice_string_part1("aaa"));
+        assert!(!StringChecker::is_nice_string_part1("jchzalrnumimnmhp"));
+        assert!(!StringChecker::is_nice_string_part1("haegwjzuvuyypxyu"));
+        assert!(!StringChecker::is_nice_string_part1("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_nice_strings_part2() {
+        assert!(StringChecker::is_nice_string_part2("qjhvhtzxzqqjkmpb"));
+        assert!(StringChecker::is_nice_string_part2("xxyxx"));
+        assert!(!StringChecker::is_nice_string_part2("uurcxstgmygtbttf"));
+        assert!(!StringChecker::is_nice_string_part2("ieodomkazucvgmuy"));
+    }
+
+    #[test]
+    fn test_counts() {
+        let strings = Day05::parse("ugknbfddgicrmopn\naaa\njchzalrnumimnmhp\nxxyxx\n").unwrap();
+        assert_eq!(part1(&strings), 2);
+        assert_eq!(part2(&strings), 1);
+    }
+}
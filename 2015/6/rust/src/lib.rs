@@ -0,0 +1,257 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+#[derive(Debug)]
+struct Coordinate {
+    x: u64,
+    y: u64,
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    start: Coordinate,
+    end: Coordinate,
+    op_type: OperationType,
+}
+
+impl Operation {
+    // The number of lights the operation covers, if a `u128` can hold it.
+    fn area(&self) -> Option<u128> {
+        let side = |start: u64, end: u64| u128::from(start.abs_diff(end)) + 1;
+        side(self.start.x, self.end.x).checked_mul(side(self.start.y, self.end.y))
+    }
+}
+
+#[derive(Debug)]
+enum OperationType {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+fn parse_coordinate(line: &str, text: &str) -> Result<Coordinate, ParseError> {
+    let Some((x, y)) = text.split_once(',') else {
+        return Err(ParseError::at(line, text, "a coordinate like `499,500`"));
+    };
+    Ok(Coordinate {
+        x: parse::number(line, x)?,
+        y: parse::number(line, y)?,
+    })
+}
+
+fn parse_operation(line: &str) -> Result<Operation, ParseError> {
+    let (op_type, coords) = if let Some(rest) = line.strip_prefix("turn on ") {
+        (OperationType::TurnOn, rest)
+    } else if let Some(rest) = line.strip_prefix("turn off ") {
+        (OperationType::TurnOff, rest)
+    } else if let Some(rest) = line.strip_prefix("toggle ") {
+        (OperationType::Toggle, rest)
+    } else {
+        return Err(ParseError::at(
+            line,
+            line,
+            "`turn on`, `turn off` or `toggle`",
+        ));
+    };
+
+    let Some((start, end)) = coords.split_once(" through ") else {
+        return Err(ParseError::at(line, coords, "`<x>,<y> through <x>,<y>`"));
+    };
+    Ok(Operation {
+        start: parse_coordinate(line, start)?,
+        end: parse_coordinate(line, end)?,
+        op_type,
+    })
+}
+
+fn toggle_cell_part1(light: u32, op_type: &OperationType) -> u32 {
+    match op_type {
+        OperationType::TurnOn => 1,
+        OperationType::TurnOff => 0,
+        OperationType::Toggle => 1 - light,
+    }
+}
+
+fn toggle_cell_part2(light: u32, op_type: &OperationType) -> u32 {
+    match op_type {
+        OperationType::TurnOn => light + 1,
+        OperationType::TurnOff => light.saturating_sub(1), // lock the result to 0 in case of overflow
+        OperationType::Toggle => light + 2,
+    }
+}
+
+// The grid cut along the edges of every rectangle the operations name, so
+// that all the lights in a block always have the same value. Block (i, j)
+// holds the lights from xs[i] up to xs[i + 1] and from ys[j] up to ys[j + 1],
+// which keeps the work down to the number of operations however large the
+// coordinates get. The edges are exclusive, so they are kept in `u128` to
+// leave room for one past `u64::MAX`.
+struct Blocks {
+    xs: Vec<u128>,
+    ys: Vec<u128>,
+    lights: Vec<u32>,
+}
+
+impl Blocks {
+    fn new(ops: &[Operation]) -> Self {
+        let edges = |coordinate: fn(&Coordinate) -> u64| {
+            let mut edges: Vec<u128> = ops
+                .iter()
+                .flat_map(|op| {
+                    let (start, end) = (coordinate(&op.start), coordinate(&op.end));
+                    [u128::from(start.min(end)), u128::from(start.max(end)) + 1]
+                })
+                .collect();
+            edges.sort_unstable();
+            edges.dedup();
+            edges
+        };
+        let (xs, ys) = (edges(|c| c.x), edges(|c| c.y));
+        let lights = vec![0; xs.len().saturating_sub(1) * ys.len().saturating_sub(1)];
+        Blocks { xs, ys, lights }
+    }
+
+    // The blocks from `start` through `end` along one axis.
+    fn span(edges: &[u128], start: u64, end: u64) -> std::ops::Range<usize> {
+        let (start, end) = (u128::from(start.min(end)), u128::from(start.max(end)));
+        let first = edges.partition_point(|&edge| edge < start);
+        let last = edges.partition_point(|&edge| edge <= end);
+        first..last
+    }
+
+    fn apply<F>(&mut self, op: &Operation, toggle_func: &F)
+    where
+        F: Fn(u32, &OperationType) -> u32,
+    {
+        let width = self.ys.len() - 1;
+        let columns = Self::span(&self.ys, op.start.y, op.end.y);
+        for i in Self::span(&self.xs, op.start.x, op.end.x) {
+            for light in &mut self.lights[i * width + columns.start..i * width + columns.end] {
+                *light = toggle_func(*light, &op.op_type);
+            }
+        }
+    }
+
+    // The value of every light added up, each block counting once per light.
+    // Parsing makes sure this fits in a `u128`.
+    fn total(&self) -> u128 {
+        let width = self.ys.len().saturating_sub(1);
+        let mut total = 0;
+        for (i, x) in self.xs.windows(2).enumerate() {
+            for (j, y) in self.ys.windows(2).enumerate() {
+                total += (x[1] - x[0]) * (y[1] - y[0]) * u128::from(self.lights[i * width + j]);
+            }
+        }
+        total
+    }
+}
+
+fn process_lights<F>(ops: &[Operation], toggle_func: F) -> u128
+where
+    F: Fn(u32, &OperationType) -> u32,
+{
+    let mut blocks = Blocks::new(ops);
+    for op in ops {
+        blocks.apply(op, &toggle_func);
+    }
+    blocks.total()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Vec<Operation>, ParseError> {
+        let ops = parse::lines(input, parse_operation)?;
+        // Each operation adds at most 2 to each of its lights, so the lights
+        // cannot add up to more than twice the areas of the operations.
+        let mut most: u128 = 0;
+        for (op, line) in ops.iter().zip(input.lines()) {
+            most = (op.area().and_then(|area| area.checked_mul(2)))
+                .and_then(|brightness| most.checked_add(brightness))
+                .ok_or_else(|| {
+                    ParseError::at(input, line, "lights that add up to less than 2^128")
+                })?;
+        }
+        Ok(ops)
+    }
+
+    fn part1(ops: &Vec<Operation>) -> impl Display {
+        process_lights(ops, toggle_cell_part1)
+    }
+
+    fn part2(ops: &Vec<Operation>) -> impl Display {
+        process_lights(ops, toggle_cell_part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(instructions: &str) -> u128 {
+        process_lights(&Day06::parse(instructions).unwrap(), toggle_cell_part1)
+    }
+
+    fn brightness(instructions: &str) -> u128 {
+        process_lights(&Day06::parse(instructions).unwrap(), toggle_cell_part2)
+    }
+
+    #[test]
+    fn test_on_off() {
+        assert_eq!(lit("turn on 0,0 through 999,999"), 1_000_000);
+        assert_eq!(lit("toggle 0,0 through 999,0"), 1000);
+        assert_eq!(
+            lit("turn on 0,0 through 999,999\nturn off 499,499 through 500,500"),
+            1_000_000 - 4
+        );
+    }
+
+    #[test]
+    fn test_brightness() {
+        assert_eq!(brightness("turn on 0,0 through 0,0"), 1);
+        assert_eq!(brightness("toggle 0,0 through 999,999"), 2_000_000);
+        assert_eq!(brightness("turn off 0,0 through 0,0"), 0);
+    }
+
+    #[test]
+    fn test_huge_grid() {
+        let ops = "turn on 0,0 through 999999,999999
+toggle 500000,0 through 999999,999999
+turn off 999999,999999 through 0,0
+turn on 123456789012,5 through 123456789013,5";
+        assert_eq!(lit(ops), 2);
+        assert_eq!(
+            brightness(ops),
+            1_000_000 * 1_000_000 + 500_000 * 1_000_000 * 2 - 1_000_000 * 1_000_000 + 2
+        );
+    }
+
+    #[test]
+    fn test_edge_of_u64() {
+        let max = u64::MAX;
+        assert_eq!(lit(&format!("turn on {},0 through {max},0", max - 1)), 2);
+        assert_eq!(lit(&format!("toggle {max},{max} through {max},{max}")), 1);
+
+        // 2^32 + 1 columns by 2^32 rows
+        let wide = "toggle 0,0 through 4294967296,4294967295";
+        assert_eq!(lit(wide), (1 << 64) + (1 << 32));
+        assert_eq!(brightness(wide), 2 * ((1 << 64) + (1 << 32)));
+
+        let everything = format!("turn on 0,0 through {max},{max}");
+        let err = Day06::parse(&format!("{wide}\n{everything}")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_malformed_instruction() {
+        let err = Day06::parse("toggle 0,0 through 9,9\nturn up 1,1 through 2,2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Day06::parse("turn on 0,0 through 9;9").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 21, "9;9"));
+    }
+}
@@ -0,0 +1,574 @@
+//! A circuit of wires, each driven by one gate, held as a dependency graph.
+//!
+//! Wires are numbered in the order they are first named, and every gate reads
+//! its inputs by number. Values are worked out in topological order with an
+//! explicit stack, so a deep circuit cannot overflow the call stack and a loop
+//! is reported with the wires in it. Values are kept until a wire they depend
+//! on is overridden, and only those downstream of it are worked out again.
+//!
+//! A circuit carries words of any [`Word`] width, 16 bits as in the puzzle
+//! unless asked otherwise. A word is just its bits: literals may be written
+//! unsigned or as negative two's complement numbers, and a wire can be read
+//! either way.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::Instruction;
+
+/// The words a circuit carries.
+pub trait Word:
+    Copy
+    + Eq
+    + fmt::Debug
+    + fmt::Display
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+{
+    /// The same bits read as a two's complement number.
+    type Signed: Copy + Eq + fmt::Debug + fmt::Display;
+
+    const BITS: u32;
+
+    /// A literal, written unsigned or as a two's complement number.
+    fn literal(text: &str) -> Option<Self>;
+
+    fn to_signed(self) -> Self::Signed;
+
+    /// Shifts left by `n`, giving zero once every bit is shifted out.
+    fn shl(self, n: u32) -> Self;
+
+    /// Shifts right by `n`, filling with zeros.
+    fn shr(self, n: u32) -> Self;
+
+    /// Shifts right by `n`, filling with copies of the top bit.
+    fn ashr(self, n: u32) -> Self;
+}
+
+macro_rules! word {
+    ($($unsigned:ty => $signed:ty),*) => {$(
+        impl Word for $unsigned {
+            type Signed = $signed;
+
+            const BITS: u32 = <$unsigned>::BITS;
+
+            fn literal(text: &str) -> Option<Self> {
+                (text.parse().ok()).or_else(|| text.parse::<$signed>().ok().map(|n| n as $unsigned))
+            }
+
+            fn to_signed(self) -> $signed {
+                self as $signed
+            }
+
+            fn shl(self, n: u32) -> Self {
+                self.checked_shl(n).unwrap_or(0)
+            }
+
+            fn shr(self, n: u32) -> Self {
+                self.checked_shr(n).unwrap_or(0)
+            }
+
+            fn ashr(self, n: u32) -> Self {
+                ((self as $signed) >> n.min(Self::BITS - 1)) as $unsigned
+            }
+        }
+    )*};
+}
+
+word!(u8 => i8, u16 => i16, u32 => i32, u64 => i64);
+
+/// What a gate reads: another wire, or a fixed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal<V> {
+    Wire(usize),
+    Value(V),
+}
+
+/// Why a wire has no value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The wire is read, or asked for, but no gate drives it.
+    Undriven(String),
+    /// The instructions drive the wire more than once.
+    Redriven(String),
+    /// These wires each read the next, and the last one reads the first.
+    Cycle(Vec<String>),
+    /// A literal that is not a number of the circuit's width.
+    Literal { text: String, bits: u32 },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CircuitError::Undriven(wire) => write!(f, "no gate drives wire `{wire}`"),
+            CircuitError::Redriven(wire) => write!(f, "wire `{wire}` is driven twice"),
+            CircuitError::Cycle(wires) => {
+                write!(f, "wires read each other in a loop: ")?;
+                for wire in wires {
+                    write!(f, "{wire} <- ")?;
+                }
+                write!(f, "{}", wires[0])
+            }
+            CircuitError::Literal { text, bits } => {
+                write!(f, "`{text}` is not a {bits}-bit number")
+            }
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    New,
+    Open,
+    Done,
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit<V = u16> {
+    names: Vec<String>,
+    numbers: HashMap<String, usize>,
+    /// The gate driving each wire, if any.
+    gates: Vec<Option<Instruction<Signal<V>>>>,
+    /// The wires whose gates read each wire.
+    readers: Vec<Vec<usize>>,
+    /// The fixed values driving wires in place of their gates.
+    overrides: Vec<Option<V>>,
+    values: Vec<Option<V>>,
+}
+
+impl<V: Word> Circuit<V> {
+    /// Wires up `instructions`. Operands that start with a digit or a minus
+    /// sign are literals, and must fit in the width of `V`. Each wire is
+    /// driven by one instruction at most; only an override may take its
+    /// place later.
+    pub fn from_instructions(instructions: &[(String, Instruction)]) -> Result<Self, CircuitError> {
+        let mut circuit = Circuit {
+            names: Vec::new(),
+            numbers: HashMap::new(),
+            gates: Vec::new(),
+            readers: Vec::new(),
+            overrides: Vec::new(),
+            values: Vec::new(),
+        };
+        for (wire, instruction) in instructions {
+            let mut literal = Ok(());
+            let gate = instruction.clone().map(|input| {
+                circuit.signal(&input).unwrap_or_else(|err| {
+                    literal = Err(err);
+                    Signal::Wire(0)
+                })
+            });
+            literal?;
+            let number = circuit.wire(wire);
+            if circuit.gates[number].is_some() {
+                return Err(CircuitError::Redriven(wire.clone()));
+            }
+            circuit.drive(number, gate);
+        }
+        Ok(circuit)
+    }
+
+    // The number of a wire, naming it if it is new.
+    fn wire(&mut self, name: &str) -> usize {
+        if let Some(&wire) = self.numbers.get(name) {
+            return wire;
+        }
+        let wire = self.names.len();
+        self.names.push(name.to_string());
+        self.numbers.insert(name.to_string(), wire);
+        self.gates.push(None);
+        self.readers.push(Vec::new());
+        self.overrides.push(None);
+        self.values.push(None);
+        wire
+    }
+
+    fn signal(&mut self, input: &str) -> Result<Signal<V>, CircuitError> {
+        if !input.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+            return Ok(Signal::Wire(self.wire(input)));
+        }
+        let value = V::literal(input).ok_or_else(|| CircuitError::Literal {
+            text: input.to_string(),
+            bits: V::BITS,
+        })?;
+        Ok(Signal::Value(value))
+    }
+
+    // The wires the gate of `wire` reads.
+    fn inputs(&self, wire: usize) -> impl Iterator<Item = usize> + '_ {
+        wires_read(self.gates[wire].as_ref())
+    }
+
+    // The wires the value of `wire` depends on: none while it is overridden.
+    fn reads(&self, wire: usize) -> impl Iterator<Item = usize> + '_ {
+        let gate = match self.overrides[wire] {
+            Some(_) => None,
+            None => self.gates[wire].as_ref(),
+        };
+        wires_read(gate)
+    }
+
+    // Connects the gate driving `wire`, noting it among the readers of each
+    // of its inputs.
+    fn drive(&mut self, wire: usize, gate: Instruction<Signal<V>>) {
+        self.gates[wire] = Some(gate);
+        let new: Vec<usize> = self.inputs(wire).collect();
+        for input in new {
+            self.readers[input].push(wire);
+        }
+    }
+
+    // Forgets the value of `wire` and of every wire downstream of it.
+    fn invalidate(&mut self, wire: usize) {
+        let mut stale = vec![wire];
+        while let Some(wire) = stale.pop() {
+            self.values[wire] = None;
+            stale.extend(
+                (self.readers[wire].iter()).filter(|&&reader| self.values[reader].is_some()),
+            );
+        }
+    }
+
+    // The number of a wire named in the instructions, without adding one.
+    fn lookup(&self, wire: &str) -> Result<usize, CircuitError> {
+        (self.numbers.get(wire).copied()).ok_or_else(|| CircuitError::Undriven(wire.to_string()))
+    }
+
+    fn set_override(&mut self, wire: usize, value: Option<V>) {
+        if self.overrides[wire] != value {
+            self.overrides[wire] = value;
+            self.invalidate(wire);
+        }
+    }
+
+    /// Drives `wire` with a fixed `value` instead of its gate, until the
+    /// override is removed. Any number of wires can be overridden at once,
+    /// but only wires the instructions name.
+    pub fn override_wire(&mut self, wire: &str, value: V) -> Result<(), CircuitError> {
+        let wire = self.lookup(wire)?;
+        self.set_override(wire, Some(value));
+        Ok(())
+    }
+
+    /// Drives `wire` with its gate again.
+    pub fn remove_override(&mut self, wire: &str) {
+        if let Some(&wire) = self.numbers.get(wire) {
+            self.set_override(wire, None);
+        }
+    }
+
+    /// The signal on `wire` were `overrides` in place as well, leaving the
+    /// circuit as it was. Only what is downstream of the overridden wires is
+    /// worked out again, so one wire can be swept through every value.
+    pub fn what_if(&mut self, overrides: &[(&str, V)], wire: &str) -> Result<V, CircuitError> {
+        let wires = (overrides.iter())
+            .map(|&(name, value)| Ok((self.lookup(name)?, value)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let mut before = Vec::with_capacity(wires.len());
+        for (overridden, value) in wires {
+            before.push((overridden, self.overrides[overridden]));
+            self.set_override(overridden, Some(value));
+        }
+        let value = self.get_wire_value(wire);
+        for (overridden, old) in before.into_iter().rev() {
+            self.set_override(overridden, old);
+        }
+        value
+    }
+
+    // Every wire that `roots` read, directly or not, with each one after the
+    // wires it reads. Wires that `known` accepts are not looked into.
+    fn order(
+        &self,
+        roots: impl IntoIterator<Item = usize>,
+        known: impl Fn(usize) -> bool,
+    ) -> Result<Vec<usize>, CircuitError> {
+        let mut marks = vec![Mark::New; self.names.len()];
+        let mut order = Vec::new();
+        for root in roots {
+            if marks[root] != Mark::New || known(root) {
+                continue;
+            }
+            // Each wire on the path down, with the inputs still to visit
+            marks[root] = Mark::Open;
+            let mut path = vec![(root, self.reads(root))];
+            while let Some((wire, inputs)) = path.last_mut() {
+                let wire = *wire;
+                match inputs.next() {
+                    Some(input) if known(input) => {}
+                    Some(input) => match marks[input] {
+                        Mark::New => {
+                            marks[input] = Mark::Open;
+                            path.push((input, self.reads(input)));
+                        }
+                        Mark::Open => {
+                            let start = path.iter().position(|&(w, _)| w == input).unwrap();
+                            let wires = path[start..].iter().map(|&(w, _)| self.names[w].clone());
+                            return Err(CircuitError::Cycle(wires.collect()));
+                        }
+                        Mark::Done => {}
+                    },
+                    None => {
+                        marks[wire] = Mark::Done;
+                        order.push(wire);
+                        path.pop();
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// Every wire, each one after the wires it reads.
+    pub fn topological_order(&self) -> Result<Vec<&str>, CircuitError> {
+        let order = self.order(0..self.names.len(), |_| false)?;
+        Ok(order.into_iter().map(|w| self.names[w].as_str()).collect())
+    }
+
+    fn value(&self, signal: Signal<V>) -> V {
+        match signal {
+            Signal::Wire(wire) => self.values[wire].expect("inputs are worked out first"),
+            Signal::Value(value) => value,
+        }
+    }
+
+    /// The signal on `wire`, working out the wires it reads first.
+    pub fn get_wire_value(&mut self, wire: &str) -> Result<V, CircuitError> {
+        let target = self.lookup(wire)?;
+        for wire in self.order([target], |w| self.values[w].is_some())? {
+            if let Some(value) = self.overrides[wire] {
+                self.values[wire] = Some(value);
+                continue;
+            }
+            let gate = self.gates[wire]
+                .clone()
+                .ok_or_else(|| CircuitError::Undriven(self.names[wire].clone()))?;
+            let value = match gate {
+                Instruction::Direct(x) => self.value(x),
+                Instruction::Not(x) => !self.value(x),
+                Instruction::And(x, y) => self.value(x) & self.value(y),
+                Instruction::Or(x, y) => self.value(x) | self.value(y),
+                Instruction::Xor(x, y) => self.value(x) ^ self.value(y),
+                Instruction::Nand(x, y) => !(self.value(x) & self.value(y)),
+                Instruction::LShift(x, n) => self.value(x).shl(n),
+                Instruction::RShift(x, n) => self.value(x).shr(n),
+                Instruction::ArShift(x, n) => self.value(x).ashr(n),
+            };
+            self.values[wire] = Some(value);
+        }
+        Ok(self.values[target].unwrap())
+    }
+
+    /// The signal on `wire` read as a two's complement number.
+    pub fn get_signed_value(&mut self, wire: &str) -> Result<V::Signed, CircuitError> {
+        self.get_wire_value(wire).map(Word::to_signed)
+    }
+
+    fn sorted(&self, wires: impl Iterator<Item = usize>) -> Vec<&str> {
+        let mut names: Vec<&str> = wires.map(|w| self.names[w].as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// The wires no gate reads, which are the outputs of the circuit.
+    pub fn unused_wires(&self) -> Vec<&str> {
+        self.sorted((0..self.names.len()).filter(|&w| self.readers[w].is_empty()))
+    }
+
+    /// The wires that are read but driven by neither a gate nor an
+    /// override.
+    pub fn undriven_wires(&self) -> Vec<&str> {
+        self.sorted(
+            (0..self.names.len())
+                .filter(|&w| self.gates[w].is_none() && self.overrides[w].is_none()),
+        )
+    }
+
+    /// The circuit as a Graphviz graph: a node per wire, labelled with the
+    /// gate driving it, and an edge from every wire to the wires reading it.
+    /// Undriven wires are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+        for (wire, name) in self.names.iter().enumerate() {
+            match &self.gates[wire] {
+                Some(gate) => {
+                    let gate = gate.clone().map(|signal| match signal {
+                        Signal::Wire(input) => self.names[input].clone(),
+                        Signal::Value(value) => value.to_string(),
+                    });
+                    writeln!(dot, "    \"{name}\" [label=\"{name} = {gate}\"];")
+                }
+                None => writeln!(dot, "    \"{name}\" [style=dashed];"),
+            }
+            .unwrap();
+        }
+        for (wire, name) in self.names.iter().enumerate() {
+            for input in self.inputs(wire) {
+                writeln!(dot, "    \"{}\" -> \"{name}\";", self.names[input]).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// The wires `gate` reads, leaving out fixed values.
+fn wires_read<V>(gate: Option<&Instruction<Signal<V>>>) -> impl Iterator<Item = usize> + '_ {
+    (gate.into_iter())
+        .flat_map(|gate| gate.inputs())
+        .filter_map(|signal| match *signal {
+            Signal::Wire(input) => Some(input),
+            Signal::Value(_) => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_instruction;
+
+    fn circuit<V: Word>(netlist: &str) -> Circuit<V> {
+        let instructions: Vec<_> = netlist
+            .lines()
+            .map(|line| parse_instruction(line).unwrap())
+            .collect();
+        Circuit::from_instructions(&instructions).unwrap()
+    }
+
+    #[test]
+    fn loops_and_missing_wires_are_reported() {
+        let mut looped = circuit::<u16>("b -> a\nc AND 1 -> b\nNOT b -> c\n7 -> d");
+        assert_eq!(
+            looped.get_wire_value("a"),
+            Err(CircuitError::Cycle(vec!["b".into(), "c".into()]))
+        );
+        assert_eq!(
+            looped.get_wire_value("a").unwrap_err().to_string(),
+            "wires read each other in a loop: b <- c <- b"
+        );
+        assert_eq!(looped.get_wire_value("d"), Ok(7));
+
+        let mut open = circuit::<u16>("x OR y -> z\n1 -> x");
+        assert_eq!(open.undriven_wires(), ["y"]);
+        assert_eq!(open.unused_wires(), ["z"]);
+        assert_eq!(
+            open.get_wire_value("z"),
+            Err(CircuitError::Undriven("y".into()))
+        );
+        assert_eq!(
+            open.get_wire_value("w"),
+            Err(CircuitError::Undriven("w".into()))
+        );
+    }
+
+    #[test]
+    fn wires_are_driven_once() {
+        let instructions: Vec<_> = ["1 -> x", "NOT x -> y", "2 -> x"]
+            .into_iter()
+            .map(|line| parse_instruction(line).unwrap())
+            .collect();
+        let redriven = Circuit::<u16>::from_instructions(&instructions).unwrap_err();
+        assert_eq!(redriven, CircuitError::Redriven("x".into()));
+        assert_eq!(redriven.to_string(), "wire `x` is driven twice");
+    }
+
+    #[test]
+    fn long_chains_do_not_overflow() {
+        let netlist: Vec<String> = (0..100_000)
+            .map(|i| format!("w{} -> w{i}", i + 1))
+            .chain(["5 -> w100000".to_string()])
+            .collect();
+        let mut chain = circuit::<u16>(&netlist.join("\n"));
+        assert_eq!(chain.get_wire_value("w0"), Ok(5));
+        let order = chain.topological_order().unwrap();
+        assert_eq!((order[0], order[100_000]), ("w100000", "w0"));
+    }
+
+    #[test]
+    fn overrides_only_redo_what_is_downstream() {
+        let mut circuit =
+            circuit::<u16>("x AND y -> d\nNOT x -> h\nNOT y -> i\n123 -> x\n456 -> y");
+        assert_eq!(circuit.get_wire_value("d"), Ok(72));
+        assert_eq!(circuit.get_wire_value("i"), Ok(65079));
+
+        circuit.override_wire("x", 1).unwrap();
+        circuit.override_wire("y", 3).unwrap();
+        let number = |name: &str| circuit.numbers[name];
+        assert_eq!(circuit.values[number("d")], None);
+        assert_eq!(circuit.values[number("i")], None);
+        circuit.remove_override("y");
+        assert_eq!(circuit.get_wire_value("d"), Ok(0));
+        assert_eq!(circuit.get_wire_value("i"), Ok(65079));
+
+        circuit.override_wire("x", 7).unwrap();
+        let number = |name: &str| circuit.numbers[name];
+        assert_eq!(circuit.values[number("i")], Some(65079));
+        assert_eq!(circuit.values[number("h")], None);
+
+        for x in 0..=u16::MAX {
+            assert_eq!(circuit.what_if(&[("x", x)], "d"), Ok(x & 456));
+        }
+        assert_eq!(circuit.what_if(&[("x", 5), ("y", 6)], "d"), Ok(4));
+        assert_eq!(circuit.get_wire_value("d"), Ok(7 & 456));
+    }
+
+    #[test]
+    fn unknown_wires_cannot_be_overridden() {
+        let mut circuit = circuit::<u16>("123 -> x\nNOT x -> h");
+        let wires = circuit.names.len();
+        let unknown = CircuitError::Undriven("xx".into());
+        assert_eq!(circuit.override_wire("xx", 1), Err(unknown.clone()));
+        assert_eq!(circuit.what_if(&[("x", 0), ("xx", 1)], "h"), Err(unknown));
+        assert_eq!(circuit.names.len(), wires);
+        assert_eq!(circuit.undriven_wires(), Vec::<&str>::new());
+        assert_eq!(circuit.get_wire_value("h"), Ok(65412));
+    }
+
+    #[test]
+    fn widths_and_signs() {
+        let netlist = "-100 -> x\n200 -> y\nx XOR y -> z\nx NAND y -> n
+x ARSHIFT 2 -> a\nx RSHIFT 2 -> r\nx LSHIFT 9 -> l\nx ARSHIFT 40 -> s";
+        let mut byte = circuit::<u8>(netlist);
+        assert_eq!(byte.get_wire_value("x"), Ok(156));
+        assert_eq!(byte.get_wire_value("z"), Ok(156 ^ 200));
+        assert_eq!(byte.get_wire_value("n"), Ok(!(156 & 200)));
+        assert_eq!(byte.get_signed_value("a"), Ok(-25));
+        assert_eq!(byte.get_wire_value("r"), Ok(39));
+        assert_eq!(byte.get_wire_value("l"), Ok(0));
+        assert_eq!(byte.get_signed_value("s"), Ok(-1));
+
+        let mut wide = circuit::<u64>(netlist);
+        assert_eq!(wide.get_signed_value("x"), Ok(-100));
+        assert_eq!(wide.get_signed_value("a"), Ok(-25));
+        assert_eq!(wide.get_wire_value("r"), Ok((-100i64 as u64) >> 2));
+        assert_eq!(wide.get_signed_value("l"), Ok(-100 << 9));
+
+        let instructions = [parse_instruction("300 -> x").unwrap()];
+        let err = Circuit::<u8>::from_instructions(&instructions).unwrap_err();
+        assert_eq!(err.to_string(), "`300` is not a 8-bit number");
+        assert!(Circuit::<u32>::from_instructions(&instructions).is_ok());
+    }
+
+    #[test]
+    fn dot_export() {
+        let dot = circuit::<u16>("x AND 3 -> y\nNOT y -> z").to_dot();
+        assert_eq!(
+            dot,
+            "digraph circuit {
+    rankdir=LR;
+    \"x\" [style=dashed];
+    \"y\" [label=\"y = x AND 3\"];
+    \"z\" [label=\"z = NOT y\"];
+    \"x\" -> \"y\";
+    \"y\" -> \"z\";
+}
+"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+pub mod circuit;
+
+use aoc_common::{ParseError, Solution, parse};
+use circuit::{Circuit, CircuitError};
+use std::fmt::{self, Display};
+
+/// The gate driving a wire, reading wires named by `W`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction<W = String> {
+    Direct(W),
+    Not(W),
+    And(W, W),
+    Or(W, W),
+    Xor(W, W),
+    Nand(W, W),
+    LShift(W, u32),
+    RShift(W, u32),
+    /// A right shift that keeps the sign of a two's complement number.
+    ArShift(W, u32),
+}
+
+impl<W> Instruction<W> {
+    /// The same gate reading `f(input)` for every input.
+    pub fn map<V>(self, mut f: impl FnMut(W) -> V) -> Instruction<V> {
+        match self {
+            Instruction::Direct(x) => Instruction::Direct(f(x)),
+            Instruction::Not(x) => Instruction::Not(f(x)),
+            Instruction::And(x, y) => Instruction::And(f(x), f(y)),
+            Instruction::Or(x, y) => Instruction::Or(f(x), f(y)),
+            Instruction::Xor(x, y) => Instruction::Xor(f(x), f(y)),
+            Instruction::Nand(x, y) => Instruction::Nand(f(x), f(y)),
+            Instruction::LShift(x, n) => Instruction::LShift(f(x), n),
+            Instruction::RShift(x, n) => Instruction::RShift(f(x), n),
+            Instruction::ArShift(x, n) => Instruction::ArShift(f(x), n),
+        }
+    }
+
+    pub fn inputs(&self) -> Vec<&W> {
+        match self {
+            Instruction::Direct(x)
+            | Instruction::Not(x)
+            | Instruction::LShift(x, _)
+            | Instruction::RShift(x, _)
+            | Instruction::ArShift(x, _) => vec![x],
+            Instruction::And(x, y)
+            | Instruction::Or(x, y)
+            | Instruction::Xor(x, y)
+            | Instruction::Nand(x, y) => vec![x, y],
+        }
+    }
+}
+
+impl<W: Display> Display for Instruction<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Direct(x) => write!(f, "{x}"),
+            Instruction::Not(x) => write!(f, "NOT {x}"),
+            Instruction::And(x, y) => write!(f, "{x} AND {y}"),
+            Instruction::Or(x, y) => write!(f, "{x} OR {y}"),
+            Instruction::Xor(x, y) => write!(f, "{x} XOR {y}"),
+            Instruction::Nand(x, y) => write!(f, "{x} NAND {y}"),
+            Instruction::LShift(x, n) => write!(f, "{x} LSHIFT {n}"),
+            Instruction::RShift(x, n) => write!(f, "{x} RSHIFT {n}"),
+            Instruction::ArShift(x, n) => write!(f, "{x} ARSHIFT {n}"),
+        }
+    }
+}
+
+/// Parses `x AND y -> z` and friends into the driven wire and its gate.
+fn parse_instruction(line: &str) -> Result<(String, Instruction), ParseError> {
+    let Some((source, target_wire)) = line.split_once(" -> ") else {
+        return Err(ParseError::at(line, line, "`<signal or gate> -> <wire>`"));
+    };
+    let target_wire = target_wire.trim();
+    if target_wire.is_empty() || target_wire.contains(char::is_whitespace) {
+        return Err(ParseError::at(line, target_wire, "a single wire name"));
+    }
+
+    let instruction = match source.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        [val] => Instruction::Direct(val.to_string()),
+
+        ["NOT", x] => Instruction::Not(x.to_string()),
+
+        [x, "AND", y] => Instruction::And(x.to_string(), y.to_string()),
+
+        [x, "OR", y] => Instruction::Or(x.to_string(), y.to_string()),
+
+        [x, "XOR", y] => Instruction::Xor(x.to_string(), y.to_string()),
+
+        [x, "NAND", y] => Instruction::Nand(x.to_string(), y.to_string()),
+
+        [x, "LSHIFT", y] => Instruction::LShift(x.to_string(), parse::number(line, y)?),
+
+        [x, "RSHIFT", y] => Instruction::RShift(x.to_string(), parse::number(line, y)?),
+
+        [x, "ARSHIFT", y] => Instruction::ArShift(x.to_string(), parse::number(line, y)?),
+
+        _ => {
+            return Err(ParseError::at(
+                line,
+                source,
+                "a signal, `NOT x`, or `x AND|OR|XOR|NAND|LSHIFT|RSHIFT|ARSHIFT y`",
+            ));
+        }
+    };
+    Ok((target_wire.to_string(), instruction))
+}
+
+// The wires `input` names as `name`: those its lines drive when `driven`,
+// the inputs of its gates otherwise.
+fn mentions<'a>(input: &'a str, name: &'a str, driven: bool) -> impl Iterator<Item = &'a str> {
+    (input.lines())
+        .filter_map(|line| line.split_once(" -> "))
+        .flat_map(move |(source, target)| match driven {
+            true => vec![target.trim()],
+            false => source.split_whitespace().collect(),
+        })
+        .filter(move |&word| word == name)
+}
+
+// Points a circuit that cannot be worked out at the line to blame.
+fn invalid(input: &str, err: CircuitError) -> ParseError {
+    let nowhere = &input[input.len()..];
+    match err {
+        CircuitError::Undriven(wire) => ParseError::at(
+            input,
+            mentions(input, &wire, false).next().unwrap_or(nowhere),
+            "a wire some line drives",
+        ),
+        CircuitError::Redriven(wire) => ParseError::at(
+            input,
+            mentions(input, &wire, true).nth(1).unwrap_or(nowhere),
+            "a wire no other line drives",
+        ),
+        CircuitError::Cycle(wires) => ParseError::at(
+            input,
+            mentions(input, &wires[0], true).next().unwrap_or(nowhere),
+            format!(
+                "a wire outside the loop {} <- {}",
+                wires.join(" <- "),
+                wires[0]
+            ),
+        ),
+        CircuitError::Literal { text, bits } => ParseError::at(
+            input,
+            mentions(input, &text, false).next().unwrap_or(nowhere),
+            format!("a {bits}-bit number"),
+        ),
+    }
+}
+
+// Wires up `input` and checks that both parts can work out wire `a` and
+// override wire `b`: every wire is driven once and no wires read each other
+// in a loop.
+fn circuit(input: &str) -> Result<Circuit, ParseError> {
+    let instructions = parse::lines(input, parse_instruction)?;
+    let circuit = Circuit::from_instructions(&instructions).map_err(|err| invalid(input, err))?;
+    let wires = circuit
+        .topological_order()
+        .map_err(|err| invalid(input, err))?;
+    if let Some(&wire) = circuit.undriven_wires().first() {
+        return Err(invalid(input, CircuitError::Undriven(wire.to_string())));
+    }
+    for needed in ["a", "b"] {
+        if !wires.contains(&needed) {
+            let nowhere = &input[input.len()..];
+            return Err(ParseError::at(
+                input,
+                nowhere,
+                format!("a line driving wire `{needed}`"),
+            ));
+        }
+    }
+    Ok(circuit)
+}
+
+fn wire_a(circuit: &mut Circuit) -> u16 {
+    circuit
+        .get_wire_value("a")
+        .expect("parse checks that wire `a` can be worked out")
+}
+
+fn solve_part1(circuit: &Circuit) -> u16 {
+    wire_a(&mut circuit.clone())
+}
+
+// Feeds the signal on `a` back into `b`; only what is downstream of `b` is
+// worked out again.
+fn solve_part2(circuit: &Circuit) -> u16 {
+    let mut circuit = circuit.clone();
+    let a = wire_a(&mut circuit);
+    circuit
+        .override_wire("b", a)
+        .expect("parse checks that wire `b` exists");
+    wire_a(&mut circuit)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+
+    type Input = Circuit;
+
+    fn parse(input: &str) -> Result<Circuit, ParseError> {
+        circuit(input)
+    }
+
+    fn part1(circuit: &Circuit) -> impl Display {
+        solve_part1(circuit)
+    }
+
+    fn part2(circuit: &Circuit) -> impl Display {
+        solve_part2(circuit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn example() -> Circuit {
+        let instructions = parse::lines(EXAMPLE, parse_instruction).unwrap();
+        Circuit::from_instructions(&instructions).unwrap()
+    }
+
+    #[test]
+    fn test_sample_circuit() {
+        let mut circuit = example();
+        let expected = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+        for (wire, value) in expected {
+            assert_eq!(circuit.get_wire_value(wire), Ok(value), "wire {wire}");
+        }
+    }
+
+    #[test]
+    fn test_override() {
+        let mut circuit = example();
+        circuit.override_wire("x", 1).unwrap();
+        assert_eq!(circuit.get_wire_value("d"), Ok(0));
+        assert_eq!(circuit.get_wire_value("e"), Ok(457));
+    }
+
+    #[test]
+    fn test_malformed_gates() {
+        let err = Day07::parse("123 -> x\nx NOR y -> z").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "x NOR y");
+        let err = Day07::parse("x LSHIFT two -> y").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 10, "a number")
+        );
+        assert!(Day07::parse("123 x").is_err());
+    }
+
+    #[test]
+    fn test_circuits_that_cannot_be_worked_out() {
+        let err = Day07::parse("1 -> b\nb -> a\n2 -> b").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 6));
+        assert_eq!(err.expected, "a wire no other line drives");
+        let err = Day07::parse("1 -> b\nc AND b -> a\nNOT a -> c").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 10, "c"));
+        assert_eq!(err.expected, "a wire outside the loop c <- a <- c");
+        let err = Day07::parse("1 -> b\nb OR y -> a").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "y"));
+        let err = Day07::parse("1 -> b\n70000 -> a").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a 16-bit number"));
+        let err = Day07::parse("1 -> b\n").unwrap_err();
+        assert_eq!(err.expected, "a line driving wire `a`");
+        assert_eq!(
+            Day07::part2(&Day07::parse("1 -> b\nb -> a").unwrap()).to_string(),
+            "1"
+        );
+    }
+}
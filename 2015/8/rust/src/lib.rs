@@ -1,24 +1,25 @@
-use std::{fs, i32};
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
 
 fn calculate_parsed_length(raw: &str) -> i32 {
     let mut length: i32 = 0;
     let mut chars = raw.trim_matches('"').chars().peekable();
     
     while let Some(c) = chars.next() {
-        if c == '\\' {
-            if let Some(&next) = chars.peek(){
+        if c == '\\'
+            && let Some(&next) = chars.peek(){
                 if next == '\\' || next == '"' {
                     length += 1;
                     chars.next();
                     continue;
                 } else if next == 'x'{
+                    chars.next();
                     if chars.next().is_some() && chars.next().is_some() {
                         length += 1;
                     }
                     continue;
                 }
             }
-        }
         length += 1;
     }
     length
@@ -35,50 +36,53 @@ fn calculate_encoded_length(raw: &str) -> i32 {
     length
 }
 
-fn calculate_raw_and_parsed(data: Vec<String>) -> (i32,i32) {
+fn calculate_raw_and_parsed(data: &[String]) -> (i32,i32) {
     let mut total_raw: i32 = 0;
     let mut total_parsed: i32 = 0;
 
     for raw_string in data {
         let  raw_length = raw_string.len() as i32;
-        let  parsed_length = calculate_parsed_length(&raw_string);
+        let  parsed_length = calculate_parsed_length(raw_string);
         total_parsed += parsed_length;
         total_raw += raw_length;
     }
     (total_raw, total_parsed)
 }
 
-fn calculate_raw_and_encoded(data: Vec<String>) -> (i32,i32) {
+fn calculate_raw_and_encoded(data: &[String]) -> (i32,i32) {
     let mut total_raw: i32 = 0;
     let mut total_encoded: i32 = 0;
 
     for raw_string in data {
         let raw_length = raw_string.len() as i32;
-        let  encoded_length = calculate_encoded_length(&raw_string);
+        let  encoded_length = calculate_encoded_length(raw_string);
         total_raw += raw_length;
         total_encoded += encoded_length;
     }
     (total_raw, total_encoded)
 }
 
-fn main() {
-    let data: Vec<String> = fs::read_to_string("../input.txt")
-    .expect("Error reading file")
-    .lines()
-    .map(String::from)
-    .collect();
-
-    let (raw, parsed) = calculate_raw_and_parsed(data.clone());
-    println!("Part 1");
-    println!("Total Raw length: {}", raw);
-    println!("Total Parsed length: {}", parsed);
-    println!("Difference: {}\n", raw - parsed);
-
-    let (raw_2, encoded) = calculate_raw_and_encoded(data);
-    println!("Part 2");
-    println!("Total Raw length: {}", raw_2);
-    println!("Total Parsed length: {}", encoded);
-    println!("Difference: {}", encoded - raw_2);
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(data: &Vec<String>) -> impl Display {
+        let (raw, parsed) = calculate_raw_and_parsed(data);
+        raw - parsed
+    }
+
+    fn part2(data: &Vec<String>) -> impl Display {
+        let (raw, encoded) = calculate_raw_and_encoded(data);
+        encoded - raw
+    }
 }
 
 // use std::fs;
@@ -125,3 +129,32 @@ fn main() {
 //     }
 //     count
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+    #[test]
+    fn test_parsed_lengths() {
+        let lengths: Vec<_> = EXAMPLE.lines().map(calculate_parsed_length).collect();
+        assert_eq!(lengths, [0, 3, 7, 1]);
+    }
+
+    #[test]
+    fn test_encoded_lengths() {
+        let lengths: Vec<_> = EXAMPLE.lines().map(calculate_encoded_length).collect();
+        assert_eq!(lengths, [6, 9, 16, 11]);
+    }
+
+    #[test]
+    fn test_totals() {
+        let data = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_raw_and_parsed(&data), (23, 11));
+        assert_eq!(calculate_raw_and_encoded(&data), (23, 42));
+    }
+}
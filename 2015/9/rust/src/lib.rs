@@ -1,9 +1,10 @@
-use std::collections::HashMap;
-use std::fs;
+use aoc_common::{ParseError, Solution, parse};
 use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Node {
+pub struct Node {
     connections: HashMap<String, i32>
 }
 
@@ -23,22 +24,28 @@ impl Node {
     }
 }
 
-fn parse_input(path: &str) -> HashMap<String, Node>{
-    let mut nodes = HashMap::new();
-    let data = fs::read_to_string(path).expect("Unable to read file");
-
-    for line in data.lines(){
+fn parse_route(line: &str) -> Result<(String, String, i32), ParseError> {
+    if parse::word(line, 1, "`to`")? != "to" || parse::word(line, 3, "`=`")? != "=" {
+        return Err(ParseError::at(line, line, "`A to B = distance`"));
+    }
+    let origin = parse::word(line, 0, "a city")?.to_string();
+    let destination = parse::word(line, 2, "a city")?.to_string();
+    let cost = parse::number(line, parse::word(line, 4, "a distance")?)?;
+    Ok((origin, destination, cost))
+}
 
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        let origin = parts[0].to_string();
-        let destination = parts[2].to_string();
-        let cost = parts[4].parse().expect("Could not parse into number");
+fn parse_input(data: &str) -> Result<HashMap<String, Node>, ParseError> {
+    let mut nodes = HashMap::new();
 
-        nodes.entry(origin.clone()).or_insert_with(Node::new).add_neighbor(destination.clone(), cost);
+    for (origin, destination, cost) in parse::lines(data, parse_route)? {
+        nodes
+            .entry(origin.clone())
+            .or_insert_with(Node::new)
+            .add_neighbor(destination.clone(), cost);
 
         nodes.entry(destination.clone()).or_insert_with(Node::new).add_neighbor(origin.clone(), cost);
     }
-    nodes
+    Ok(nodes)
 }
 
 fn find_shortest_path(nodes: &HashMap<String, Node>) -> (Vec<String>, i32) {
@@ -101,14 +108,48 @@ fn find_longest_path(nodes: &HashMap<String, Node>) -> (Vec<String>, i32) {
     (best_route, longest_distance)
 }
 
-fn main() {
-    let nodes = parse_input("../input.txt");
-    let (route, distance) = find_shortest_path(&nodes);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Input = HashMap<String, Node>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Node>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(nodes: &HashMap<String, Node>) -> impl Display {
+        find_shortest_path(nodes).1
+    }
+
+    fn part2(nodes: &HashMap<String, Node>) -> impl Display {
+        find_longest_path(nodes).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("================= Part 1 =================");
-    println!("The shortest route in the data is {} with a distance of {}", route.join(" -> "), distance);
+    const EXAMPLE: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
 
-    println!("================= Part 2 =================");
-    let (route, distance) = find_longest_path(&nodes);
-    println!("The longest route in the data is {} with a distance of {}", route.join(" -> "), distance);
+    #[test]
+    fn test_shortest_route() {
+        let (route, distance) = find_shortest_path(&parse_input(EXAMPLE).unwrap());
+        // London -> Dublin -> Belfast, in either direction.
+        assert_eq!(distance, 605);
+        assert_eq!(route[1], "Dublin");
+    }
+
+    #[test]
+    fn test_longest_route() {
+        let (route, distance) = find_longest_path(&parse_input(EXAMPLE).unwrap());
+        // Dublin -> London -> Belfast, in either direction.
+        assert_eq!(distance, 982);
+        assert_eq!(route[1], "London");
+    }
 }
@@ -0,0 +1,105 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub enum Turn {
+    Left,
+    Right,
+}
+
+fn parse_step(input: &str, step: &str) -> Result<(Turn, i32), ParseError> {
+    let turn = match step.chars().next() {
+        Some('R') => Turn::Right,
+        Some('L') => Turn::Left,
+        _ => return Err(ParseError::at(input, step, "a step like `R2` or `L3`")),
+    };
+    Ok((turn, parse::number(input, &step[1..])?))
+}
+
+fn calculate_distance_from_origin(instructions: &[(Turn, i32)], pt_2: bool) -> i32 {
+    //0 = north, 1 = east, 2 = south, 3 = west
+    let mut current_pos = (0, 0);
+    let total_directions = 4;
+    let mut curr_direction = 0;
+    let mut points_visited: HashMap<(i32, i32), i32> = HashMap::new();
+    points_visited.insert(current_pos, 1);
+
+    for &(ref turn, distance) in instructions {
+        match turn {
+            Turn::Right => curr_direction = (curr_direction + 1) % total_directions,
+            Turn::Left => curr_direction = (curr_direction + 3) % total_directions, // equivalent to -1 modulo 4
+        }
+
+        if pt_2 {
+            for _ in 0..distance {
+                match curr_direction {
+                    0 => current_pos.1 += 1,
+                    1 => current_pos.0 += 1,
+                    2 => current_pos.1 -= 1,
+                    3 => current_pos.0 -= 1,
+                    _ => {}
+                }
+                if points_visited.contains_key(&current_pos) {
+                    return current_pos.0.abs() + current_pos.1.abs();
+                }
+                points_visited.insert(current_pos, 1);
+            }
+        } else {
+            match curr_direction {
+                0 => current_pos.1 += distance,
+                1 => current_pos.0 += distance,
+                2 => current_pos.1 -= distance,
+                3 => current_pos.0 -= distance,
+                _ => {}
+            }
+        }
+    }
+    // Return Manhattan distance from origin
+    current_pos.0.abs() + current_pos.1.abs()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+
+    type Input = Vec<(Turn, i32)>;
+
+    fn parse(input: &str) -> Result<Vec<(Turn, i32)>, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| parse_step(input, step.trim()))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<(Turn, i32)>) -> impl Display {
+        calculate_distance_from_origin(instructions, false)
+    }
+
+    fn part2(instructions: &Vec<(Turn, i32)>) -> impl Display {
+        calculate_distance_from_origin(instructions, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(instructions: &str, pt_2: bool) -> i32 {
+        calculate_distance_from_origin(&Day01::parse(instructions).unwrap(), pt_2)
+    }
+
+    #[test]
+    fn test_final_distance() {
+        assert_eq!(distance("R2, L3", false), 5);
+        assert_eq!(distance("R2, R2, R2", false), 2);
+        assert_eq!(distance("R5, L5, R5, R3", false), 12);
+    }
+
+    #[test]
+    fn test_first_location_visited_twice() {
+        assert_eq!(distance("R8, R4, R4, R8", true), 4);
+    }
+}
@@ -1,6 +1,6 @@
-use std::{cell::RefCell, collections::HashMap, fs};
-
-use regex::Regex;
+use aoc_common::{ParseError, Solution, parse};
+use std::{cell::RefCell, collections::HashMap};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Destination {
@@ -8,39 +8,60 @@ enum Destination {
     Output(i32),
 }
 
-fn parse_destination(type_str: &str, id_str: &str) -> Destination {
-    let id = id_str.parse::<i32>().expect("Invalid destination ID");
+pub struct Instructions {
+    bot_instructions: HashMap<i32, (Destination, Destination)>,
+    initial_values: Vec<(i32, i32)>,
+}
+
+fn parse_destination(line: &str, index: usize) -> Result<Destination, ParseError> {
+    let type_str = parse::word(line, index, "`bot` or `output`")?;
+    let id = parse::number(line, parse::word(line, index + 1, "a destination id")?)?;
     match type_str {
-        "bot" => Destination::Bot(id),
-        "output" => Destination::Output(id),
-        _ => panic!("Invalid destination type"),
+        "bot" => Ok(Destination::Bot(id)),
+        "output" => Ok(Destination::Output(id)),
+        _ => Err(ParseError::at(line, type_str, "`bot` or `output`")),
     }
 }
 
-fn solve(input: &str, target_low: i32, target_high: i32) -> (i32, i32) {
+fn parse_instructions(input: &str) -> Result<Instructions, ParseError> {
     let mut bot_instructions: HashMap<i32, (Destination, Destination)> = HashMap::new();
     let mut initial_values: Vec<(i32, i32)> = Vec::new();
 
-    let value_re = Regex::new(r"value (\d+) goes to bot (\d+)").unwrap();
-    let gives_re =
-        Regex::new(r"bot (\d+) gives low to (bot|output) (\d+) and high to (bot|output) (\d+)")
-            .unwrap();
-
-    for line in input.lines() {
-        if let Some(caps) = value_re.captures(line) {
-            let value = caps[1].parse::<i32>().unwrap();
-            let bot_id = caps[2].parse::<i32>().unwrap();
-            initial_values.push((value, bot_id));
-        } else if let Some(caps) = gives_re.captures(line) {
-            let bot_id = caps[1].parse::<i32>().unwrap();
-            let low_dest = parse_destination(&caps[2], &caps[3]);
-            let high_dest = parse_destination(&caps[4], &caps[5]);
-            bot_instructions.insert(bot_id, (low_dest, high_dest));
-        } else if !line.trim().is_empty() {
-            eprintln!("Warning unparsed line: {}", line);
-        }
+    for (i, line) in input.lines().enumerate() {
+        let number = |index, expected| parse::number(line, parse::word(line, index, expected)?);
+        let parsed = match line.split_whitespace().next() {
+            Some("value") => number(1, "a chip value").and_then(|value| {
+                initial_values.push((value, number(5, "a bot id")?));
+                Ok(())
+            }),
+            Some("bot") => number(1, "a bot id").and_then(|bot_id| {
+                let low_dest = parse_destination(line, 5)?;
+                let high_dest = parse_destination(line, 10)?;
+                bot_instructions.insert(bot_id, (low_dest, high_dest));
+                Ok(())
+            }),
+            Some(_) => Err(ParseError::at(
+                line,
+                line,
+                "`value V goes to bot B` or `bot B gives ...`",
+            )),
+            None => Ok(()),
+        };
+        parsed.map_err(|e| e.below(i))?;
     }
 
+    Ok(Instructions {
+        bot_instructions,
+        initial_values,
+    })
+}
+
+fn solve(instructions: &Instructions, target_low: i32, target_high: i32) -> (i32, i32) {
+    let Instructions {
+        bot_instructions,
+        initial_values,
+    } = instructions;
+
     let bots: RefCell<HashMap<i32, Vec<i32>>> = RefCell::new(HashMap::new());
     let outputs: RefCell<HashMap<i32, Vec<i32>>> = RefCell::new(HashMap::new());
 
@@ -48,7 +69,7 @@ fn solve(input: &str, target_low: i32, target_high: i32) -> (i32, i32) {
         bots.borrow_mut().entry(bot_id).or_default();
     }
 
-    for (value, bot_id) in initial_values {
+    for &(value, bot_id) in initial_values {
         bots.borrow_mut().entry(bot_id).or_default().push(value);
     }
 
@@ -131,25 +152,46 @@ fn solve(input: &str, target_low: i32, target_high: i32) -> (i32, i32) {
     let part2_answer_product = val0 * val1 * val2;
 
     // Ensure Part 1 answer was found before returning
-    let final_part1_bot_id = part1_answer_bot_id.expect(&format!(
-        "Target comparison ({},{}) never happened!",
-        target_low, target_high
-    ));
+    let final_part1_bot_id = part1_answer_bot_id.unwrap_or_else(|| panic!("Target comparison ({},{}) never happened!",
+        target_low, target_high));
 
     (final_part1_bot_id, part2_answer_product)
 }
 
-fn main() {
-    let binding = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let input = binding.trim(); // Trim trailing whitespace
+pub struct Day10;
 
-    let sep = "=".repeat(20);
+impl Solution for Day10 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 10;
 
-    // Call solve once to get both parts
-    let (part1_bot, part2_product) = solve(input, 17, 61);
+    type Input = Instructions;
 
-    println!("{sep} Part 1 {sep}");
-    println!("The responsible bot is: {}", part1_bot);
-    println!("{sep} Part 2 {sep}");
-    println!("Product of outputs: {}", part2_product);
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        parse_instructions(input.trim())
+    }
+
+    fn part1(instructions: &Instructions) -> impl Display {
+        solve(instructions, 17, 61).0
+    }
+
+    fn part2(instructions: &Instructions) -> impl Display {
+        solve(instructions, 17, 61).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_bots() {
+        let instructions = "value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+        // Outputs 0, 1 and 2 end up with chips 5, 2 and 3.
+        assert_eq!(solve(&Day10::parse(instructions).unwrap(), 2, 5), (2, 30));
+    }
 }
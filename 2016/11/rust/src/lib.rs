@@ -1,8 +1,8 @@
+use aoc_common::{ParseError, Solution, search};
 use itertools::Itertools;
 use regex::Regex;
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::fs;
-use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 enum ItemType {
@@ -16,24 +16,10 @@ struct Item {
     kind: ItemType,
 }
 
-#[derive(Clone, Debug, Eq)]
-struct State {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct State {
     elevator_floor: usize,
     item_floors: Vec<(usize, usize)>,
-    steps: u32,
-}
-
-impl Hash for State {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.elevator_floor.hash(state);
-        self.item_floors.hash(state);
-    }
-}
-
-impl PartialEq for State {
-    fn eq(&self, other: &Self) -> bool {
-        self.elevator_floor == other.elevator_floor && self.item_floors == other.item_floors
-    }
 }
 
 impl State {
@@ -124,7 +110,6 @@ impl State {
                 let potential_next_state = State {
                     elevator_floor: next_floor,
                     item_floors: next_item_floors,
-                    steps: self.steps + 1,
                 };
 
                 if potential_next_state.is_valid() {
@@ -136,10 +121,12 @@ impl State {
     }
 }
 
-fn parse_input(input: &str) -> State {
+fn parse_input(input: &str) -> Result<State, ParseError> {
     let mut element_map: HashMap<String, usize> = HashMap::new();
     let mut element_count = 0;
     let mut initial_locations: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    let mut mentions: Vec<&str> = Vec::new();
+    let mut chips: HashMap<usize, &str> = HashMap::new();
 
     let gen_re = Regex::new(r"a (\w+) generator").unwrap();
     let chip_re = Regex::new(r"a (\w+)-compatible microchip").unwrap();
@@ -157,6 +144,7 @@ fn parse_input(input: &str) -> State {
                 current_idx
             });
             initial_locations[idx].0 = Some(floor_idx);
+            mentions.resize(element_count, cap.get(0).unwrap().as_str());
         }
         // Find microchips
         for cap in chip_re.captures_iter(line) {
@@ -170,73 +158,110 @@ fn parse_input(input: &str) -> State {
                 current_idx
             });
             initial_locations[idx].1 = Some(floor_idx);
+            mentions.resize(element_count, cap.get(0).unwrap().as_str());
+            chips.insert(idx, cap.get(0).unwrap().as_str());
         }
     }
 
     let mut item_floors: Vec<(usize, usize)> = initial_locations
         .iter()
-        .map(|(gen_opt, chip_opt)| {
-            (
-                gen_opt.expect("Generator location missing"),
-                chip_opt.expect("Microchip location missing"),
-            )
+        .zip(&mentions)
+        .map(|(locations, &mention)| match *locations {
+            (Some(generator), Some(chip)) => Ok((generator, chip)),
+            _ => Err(ParseError::at(
+                input,
+                mention,
+                "a generator and a microchip of each element",
+            )),
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
+
+    // A chip left with another element's generator and not its own is fried
+    // before the first move
+    let fried = item_floors.iter().position(|&(generator, chip)| {
+        generator != chip && item_floors.iter().any(|&(other, _)| other == chip)
+    });
+    if let Some(idx) = fried {
+        return Err(ParseError::at(
+            input,
+            chips[&idx],
+            "a microchip with its own generator or away from all generators",
+        ));
+    }
 
     item_floors.sort_unstable();
 
-    State {
+    Ok(State {
         elevator_floor: 0,
         item_floors,
-        steps: 0,
-    }
+    })
 }
 
-fn solve(initial_state: State) -> Option<u32> {
-    let mut queue: VecDeque<State> = VecDeque::new();
-    let mut visited: HashSet<State> = HashSet::new();
-
+// A state that already fries a chip leads nowhere
+fn solve(initial_state: State) -> Option<usize> {
     if !initial_state.is_valid() {
-        eprintln!("Initial state is invalid!");
         return None;
     }
 
-    queue.push_back(initial_state.clone());
-    visited.insert(initial_state);
+    search::bfs(initial_state, State::get_next_states, State::is_goal).map(|found| found.cost)
+}
+
+pub struct Day11;
 
-    while let Some(current_state) = queue.pop_front() {
-        if current_state.is_goal() {
-            return Some(current_state.steps);
-        }
+impl Solution for Day11 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 11;
 
-        for next_state in current_state.get_next_states() {
-            if visited.insert(next_state.clone()) {
-                queue.push_back(next_state);
-            }
-        }
+    type Input = State;
+
+    fn parse(input: &str) -> Result<State, ParseError> {
+        parse_input(input)
     }
 
-    None // No solution found
+    fn part1(initial_state: &State) -> impl Display {
+        solve(initial_state.clone()).expect("No solution found")
+    }
+
+    fn part2(initial_state: &State) -> impl Display {
+        let mut initial_state = initial_state.clone();
+        // Add Elerium (gen=0, chip=0) and Dilithium (gen=0, chip=0)
+        initial_state.item_floors.push((0, 0)); // Elerium G, M on floor 0
+        initial_state.item_floors.push((0, 0)); // Dilithium G, M on floor 0
+        initial_state.item_floors.sort_unstable();
+        solve(initial_state).expect("No solution found")
+    }
 }
 
-fn main() {
-    let input = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let initial_state_part1 = parse_input(&input);
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    match solve(initial_state_part1) {
-        Some(steps) => println!("Minimum steps = {}", steps),
-        None => println!("No solution found."),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+    #[test]
+    fn test_parse() {
+        let state = parse_input(EXAMPLE).unwrap();
+        assert_eq!(state.item_floors, [(1, 0), (2, 0)]);
+        assert!(state.is_valid());
+        assert!(!state.is_goal());
+    }
+
+    #[test]
+    fn test_fried_chip() {
+        let input =
+            "The first floor contains a hydrogen generator and a lithium-compatible microchip.
+The second floor contains a lithium generator and a hydrogen-compatible microchip.";
+        let err = parse_input(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 51));
+        assert_eq!(err.text, "a hydrogen-compatible microchip");
     }
 
-    let mut initial_state_part2 = parse_input(&input);
-    // Add Elerium (gen=0, chip=0) and Dilithium (gen=0, chip=0)
-    initial_state_part2.item_floors.push((0, 0)); // Elerium G, M on floor 0
-    initial_state_part2.item_floors.push((0, 0)); // Dilithium G, M on floor 0
-    initial_state_part2.item_floors.sort_unstable();
-    println!("{sep} Part 2 {sep}");
-    match solve(initial_state_part2) {
-        Some(steps) => println!("Minimum steps = {}", steps),
-        None => println!("No solution found."),
+    #[test]
+    fn test_fewest_steps() {
+        assert_eq!(solve(parse_input(EXAMPLE).unwrap()), Some(11));
     }
 }
@@ -0,0 +1,63 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::assembunny::{self, Instruction};
+use aoc_vm::{Machine, Word};
+use std::fmt::Display;
+
+// Register a once the program has run with register c set to `c`.
+fn solve(program: &[Instruction], c: Word) -> Word {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_register('c', c);
+    machine.run();
+    machine.register('a')
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 12;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        assembunny::parse(input, &["cpy", "inc", "dec", "jnz"])
+    }
+
+    fn part1(program: &Vec<Instruction>) -> impl Display {
+        solve(program, 0)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> impl Display {
+        solve(program, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_program() {
+        let program = "cpy 41 a
+inc a
+inc a
+dec a
+jnz a 2
+dec a";
+        assert_eq!(solve(&Day12::parse(program).unwrap(), 0), 42);
+    }
+
+    #[test]
+    fn test_malformed_program() {
+        let err = Day12::parse("cpy 41 a\ncpy 1").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a register, found nothing"
+        );
+        let err = Day12::parse("cpy 41 a\ninc e").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "e"));
+        let err = Day12::parse("jnz a 2 3").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 9, "3"));
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::grid::Pos;
+use aoc_common::{Grid, ParseError, Solution, parse, search};
+use std::fmt::Display;
+
+const START: Pos = (1, 1);
+const TARGET: Pos = (31, 39);
+
+fn is_wall(x: i32, y: i32, fav_number: i32) -> bool {
+    let base = (x * x + 3 * x + 2 * x * y + y + y * y) + fav_number;
+    let bin_rep = format!("{:b}", base);
+    let mut n_ones = 0;
+    for bit in bin_rep.chars() {
+        if bit == '1' {
+            n_ones += 1;
+        }
+    }
+    n_ones % 2 != 0
+}
+
+fn gen_maze(fav_number: i32, max_x: usize, max_y: usize) -> Grid<bool> {
+    Grid::from_fn(max_x + 1, max_y + 1, |(x, y)| {
+        is_wall(x as i32, y as i32, fav_number)
+    })
+}
+
+fn solve_maze(maze: &Grid<bool>, start: Pos, target: Pos) -> Option<usize> {
+    if maze.get(start) != Some(&false) {
+        return None; // Invalid start
+    }
+    let open = |&pos: &Pos| {
+        maze.neighbors4(pos)
+            .filter(|&next| !maze[next])
+            .map(|next| (next, 1))
+            .collect::<Vec<_>>()
+    };
+    let distance = |&(x, y): &Pos| x.abs_diff(target.0) + y.abs_diff(target.1);
+    search::astar(start, open, distance, |&pos| pos == target).map(|found| found.cost)
+}
+
+fn count_reachable_locations(maze: &Grid<bool>, start: Pos, max_steps: usize) -> usize {
+    let open = |&pos: &Pos| {
+        maze.neighbors4(pos)
+            .filter(|&next| !maze[next])
+            .collect::<Vec<_>>()
+    };
+    search::distances(start, open, max_steps).len()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 13;
+
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, ParseError> {
+        parse::number(input, input.trim())
+    }
+
+    fn part1(fav_number: &i32) -> impl Display {
+        let maze = gen_maze(*fav_number, TARGET.0 + 5, TARGET.1 + 5);
+        solve_maze(&maze, START, TARGET)
+            .expect("Failed to find a path to the target (target might be unreachable or blocked)")
+    }
+
+    fn part2(fav_number: &i32) -> impl Display {
+        let maze = gen_maze(*fav_number, TARGET.0 + 5, TARGET.1 + 5);
+        count_reachable_locations(&maze, START, 50)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_office() {
+        let maze = gen_maze(10, 9, 6);
+        let rendered = maze.render(|&wall| if wall { '#' } else { '.' });
+        assert_eq!(
+            rendered.lines().collect::<Vec<_>>(),
+            [
+                ".#.####.##",
+                "..#..#...#",
+                "#....##...",
+                "###.#.###.",
+                ".##..#..#.",
+                "..##....#.",
+                "#...##.###",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let maze = gen_maze(10, 15, 15);
+        assert_eq!(solve_maze(&maze, START, (7, 4)), Some(11));
+    }
+}
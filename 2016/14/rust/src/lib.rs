@@ -0,0 +1,124 @@
+use aoc_common::{ParseError, Solution};
+use aoc_hash::mining::{self, Digest};
+use std::fmt::Display;
+
+// How many hashes are worked out at a time, across every thread.
+const BLOCK: u64 = 4096;
+
+// The hashes of the salt and every index so far, stretched or not.
+struct Hashes<'a> {
+    salt: &'a str,
+    stretch: usize,
+    known: Vec<Digest>,
+}
+
+impl<'a> Hashes<'a> {
+    fn new(salt: &'a str, stretch: bool) -> Self {
+        Hashes {
+            salt,
+            stretch: if stretch { 2016 } else { 0 },
+            known: Vec::new(),
+        }
+    }
+
+    fn get_hash(&mut self, index: usize) -> Digest {
+        while index >= self.known.len() {
+            let start = self.known.len() as u64;
+            let block = mining::digests(self.salt, start..start + BLOCK, self.stretch);
+            self.known.extend(block);
+        }
+        self.known[index]
+    }
+}
+
+fn find_triplet(hash: &Digest) -> Option<u8> {
+    hash.nibbles()
+        .windows(3)
+        .find(|window| window[0] == window[1] && window[1] == window[2])
+        .map(|window| window[0])
+}
+
+fn contains_quituple(hash: &Digest, target: u8) -> bool {
+    hash.nibbles()
+        .windows(5)
+        .any(|window| window.iter().all(|&nibble| nibble == target))
+}
+
+fn find_64_index(salt: &str, stretch: bool) -> usize {
+    let mut keys_found = 0;
+    let mut index = 0;
+    let mut hashes = Hashes::new(salt, stretch);
+
+    loop {
+        let hash = hashes.get_hash(index);
+
+        if let Some(triplet) = find_triplet(&hash) {
+            for next_index in (index + 1)..=(index + 1000) {
+                let next_hash = hashes.get_hash(next_index);
+
+                if contains_quituple(&next_hash, triplet) {
+                    keys_found += 1;
+                    if keys_found == 64 {
+                        return index;
+                    }
+                    break;
+                }
+            }
+        }
+        index += 1;
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 14;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(salt: &String) -> impl Display {
+        find_64_index(salt, false)
+    }
+
+    fn part2(salt: &String) -> impl Display {
+        find_64_index(salt, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triplets_and_quintuples() {
+        let mut hashes = Hashes::new("abc", false);
+        assert_eq!(find_triplet(&hashes.get_hash(18)), Some(0x8));
+        assert_eq!(find_triplet(&hashes.get_hash(39)), Some(0xe));
+        assert!(contains_quituple(&hashes.get_hash(816), 0xe));
+    }
+
+    #[test]
+    fn test_stretched_hash() {
+        let mut hashes = Hashes::new("abc", true);
+        assert_eq!(
+            hashes.get_hash(0).to_string(),
+            "a107ff634856bb300138cac6568c0f24"
+        );
+    }
+
+    #[test]
+    fn test_64th_key() {
+        assert_eq!(find_64_index("abc", false), 22728);
+    }
+
+    #[test]
+    #[ignore = "stretches tens of thousands of hashes"]
+    fn test_64th_stretched_key() {
+        assert_eq!(find_64_index("abc", true), 22551);
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+#[derive(Debug, Clone)]
+pub struct Disc {
+    positions: i32,
+    initial: i32,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Disc>, ParseError> {
+    parse::lines(input, |l| {
+        let positions = parse::number(l, parse::word(l, 3, "a number of positions")?)?;
+        let initial = parse::word(l, 11, "a starting position")?.trim_end_matches('.');
+        let initial = parse::number(l, initial)?;
+        Ok(Disc { positions, initial })
+    })
+}
+
+fn check_time(discs: &[Disc], time: i32) -> bool {
+    discs.iter().enumerate().all(|(index, disc)| {
+        let time_at_disc = time + (index as i32) + 1;
+        let position_at_time = (disc.initial + time_at_disc) % disc.positions;
+        position_at_time == 0
+    })
+}
+
+fn solve(discs: &[Disc]) -> i32 {
+    (0..).find(|&time| check_time(discs, time)).unwrap()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Disc>;
+
+    fn parse(input: &str) -> Result<Vec<Disc>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(discs: &Vec<Disc>) -> impl Display {
+        solve(discs)
+    }
+
+    fn part2(discs: &Vec<Disc>) -> impl Display {
+        let mut discs = discs.clone();
+        discs.push(Disc {
+            positions: 11,
+            initial: 0,
+        });
+        solve(&discs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_discs() {
+        let discs = parse_input(
+            "Disc #1 has 5 positions; at time=0, it is at position 4.
+Disc #2 has 2 positions; at time=0, it is at position 1.",
+        )
+        .unwrap();
+        assert!(!check_time(&discs, 0));
+        assert_eq!(solve(&discs), 5);
+    }
+}
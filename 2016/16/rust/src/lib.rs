@@ -0,0 +1,102 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn gen_string(base_data: String) -> String {
+    let b: String = base_data.clone().chars().rev().collect();
+    let mut replaced_b = String::with_capacity(base_data.len());
+    for char in b.chars() {
+        if char == '1' {
+            replaced_b.push('0');
+        } else if char == '0' {
+            replaced_b.push('1');
+        } else {
+            panic!("Invalid character found");
+        }
+    }
+    format!("{base_data}0{replaced_b}")
+}
+
+fn fill_disk(current_data: String, space_to_fill: usize) -> String {
+    let mut new_data = gen_string(current_data.clone());
+    while new_data.len() <= space_to_fill {
+        new_data = gen_string(new_data);
+    }
+    new_data
+}
+
+fn gen_checksum(data: String) -> String {
+    let chars: Vec<char> = data.chars().collect();
+    let mut checksum = String::with_capacity(data.len() / 2);
+    for part in chars.chunks(2) {
+        if part[0] == part[1] {
+            checksum.push('1');
+        } else {
+            checksum.push('0');
+        }
+    }
+    checksum
+}
+
+fn solve(input: String, space_to_fill: usize) -> String {
+    let mut data = fill_disk(input, space_to_fill);
+    data.truncate(space_to_fill);
+    let mut checksum = gen_checksum(data);
+    while checksum.len().is_multiple_of(2) {
+        checksum = gen_checksum(checksum)
+    }
+    checksum
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 16;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let data = parse::only(
+            input,
+            input.trim(),
+            |c| c == '0' || c == '1',
+            "a binary digit",
+        )?;
+        Ok(data.to_string())
+    }
+
+    fn part1(initial_state: &String) -> impl Display {
+        solve(initial_state.clone(), 272)
+    }
+
+    fn part2(initial_state: &String) -> impl Display {
+        solve(initial_state.clone(), 35651584)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dragon_curve() {
+        assert_eq!(gen_string("1".to_string()), "100");
+        assert_eq!(gen_string("0".to_string()), "001");
+        assert_eq!(gen_string("11111".to_string()), "11111000000");
+        assert_eq!(
+            gen_string("111100001010".to_string()),
+            "1111000010100101011110000"
+        );
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(gen_checksum("110010110100".to_string()), "110101");
+        assert_eq!(gen_checksum("110101".to_string()), "100");
+    }
+
+    #[test]
+    fn test_fill_disk() {
+        assert_eq!(solve("10000".to_string(), 20), "01100");
+    }
+}
@@ -0,0 +1,130 @@
+use aoc_common::{ParseError, Solution, search};
+use aoc_hash::mining::Digest;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct State {
+    x: usize,
+    y: usize,
+    path: String,
+}
+
+// The digits b to f open a door
+fn is_open(nibble: u8) -> bool {
+    nibble >= 0xb
+}
+
+fn get_open_doors(passcode: &str, path: &str) -> [bool; 4] {
+    let hash = Digest::of(&[passcode.as_bytes(), path.as_bytes()]);
+
+    [
+        is_open(hash.nibble(0)), // Up
+        is_open(hash.nibble(1)), // Down
+        is_open(hash.nibble(2)), // Left
+        is_open(hash.nibble(3)), // Right
+    ]
+}
+
+fn in_vault(state: &State) -> bool {
+    state.x == 3 && state.y == 3
+}
+
+// Every room reachable in one step through an open door
+fn moves(passcode: &str, state: &State) -> Vec<State> {
+    let doors = get_open_doors(passcode, &state.path);
+    let steps = [
+        (state.y > 0, 'U', 0, -1),
+        (state.y < 3, 'D', 0, 1),
+        (state.x > 0, 'L', -1, 0),
+        (state.x < 3, 'R', 1, 0),
+    ];
+
+    doors
+        .into_iter()
+        .zip(steps)
+        .filter(|&(open, (inside, ..))| open && inside)
+        .map(|(_, (_, door, dx, dy))| State {
+            x: state.x.wrapping_add_signed(dx),
+            y: state.y.wrapping_add_signed(dy),
+            path: format!("{}{door}", state.path),
+        })
+        .collect()
+}
+
+fn start() -> State {
+    State {
+        x: 0,
+        y: 0,
+        path: String::new(),
+    }
+}
+
+fn shortest_path(passcode: &str) -> Option<String> {
+    let found = search::bfs(start(), |state| moves(passcode, state), in_vault)?;
+    Some(found.goal().path.clone())
+}
+
+fn longest_path(passcode: &str) -> usize {
+    let mut queue = VecDeque::from([start()]);
+    let mut longest = 0;
+
+    while let Some(state) = queue.pop_front() {
+        // The vault ends a path; it cannot be walked through
+        if in_vault(&state) {
+            longest = longest.max(state.path.len());
+            continue;
+        }
+        queue.extend(moves(passcode, &state));
+    }
+
+    longest
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 17;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(passcode: &String) -> impl Display {
+        shortest_path(passcode).expect("No path to the vault")
+    }
+
+    fn part2(passcode: &String) -> impl Display {
+        longest_path(passcode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locked_in() {
+        assert_eq!(shortest_path("hijkl"), None);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        assert_eq!(shortest_path("ihgpwlah").as_deref(), Some("DDRRRD"));
+        assert_eq!(shortest_path("kglvqrro").as_deref(), Some("DDUDRLRRUDRD"));
+        assert_eq!(
+            shortest_path("ulqzkmiv").as_deref(),
+            Some("DRURDRUDDLLDLUURRDULRLDUUDDDRR")
+        );
+    }
+
+    #[test]
+    fn test_longest_path() {
+        assert_eq!(longest_path("ihgpwlah"), 370);
+        assert_eq!(longest_path("kglvqrro"), 492);
+        assert_eq!(longest_path("ulqzkmiv"), 830);
+    }
+}
@@ -1,4 +1,5 @@
-use std::{char, fs};
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
 
 fn gen_row(prev_row: &[char]) -> Vec<char> {
     let width = prev_row.len();
@@ -36,14 +37,42 @@ fn gen_and_count_safe(input: &str, rows: usize) -> i32 {
     total_safe_tiles
 }
 
-fn main() {
-    let binding = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let input = binding.trim();
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    let part1 = gen_and_count_safe(input, 40);
-    println!("The total number of safe tiles for 40 rows is: {part1}");
-    let part2 = gen_and_count_safe(input, 400000);
-    println!("{sep} Part 2 {sep}");
-    println!("The total number of safe tiles for 400000 rows is: {part2}");
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 18;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let row = parse::only(input, input.trim(), |c| c == '.' || c == '^', "`.` or `^`")?;
+        Ok(row.to_string())
+    }
+
+    fn part1(first_row: &String) -> impl Display {
+        gen_and_count_safe(first_row, 40)
+    }
+
+    fn part2(first_row: &String) -> impl Display {
+        gen_and_count_safe(first_row, 400000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_room() {
+        let first: Vec<char> = "..^^.".chars().collect();
+        let second = gen_row(&first);
+        assert_eq!(second.iter().collect::<String>(), ".^^^^");
+        assert_eq!(gen_row(&second).iter().collect::<String>(), "^^..^");
+    }
+
+    #[test]
+    fn test_larger_room() {
+        assert_eq!(gen_and_count_safe(".^^.^.^^^^", 10), 38);
+    }
 }
@@ -0,0 +1,73 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+fn simulate(n: i32) -> i32 {
+    let mut queue = VecDeque::from_iter(1..=n);
+    while queue.len() > 1 {
+        if let Some(current) = queue.pop_front() {
+            let _ = queue.pop_front();
+            queue.push_back(current);
+        }
+    }
+    queue[0]
+}
+
+fn largest_power_of_3(n: i32) -> i32 {
+    let mut power: i32 = 1;
+    while let Some(next_power) = power.checked_mul(3) {
+        if next_power <= n {
+            power = next_power;
+        } else {
+            break;
+        }
+    }
+    power
+}
+
+fn simulate_pt2(n: i32) -> i32 {
+    let l = largest_power_of_3(n);
+    if n == l {
+        n
+    } else if n <= 2 * l {
+        n - l
+    } else {
+        2 * n - 3 * l
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 19;
+
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, ParseError> {
+        parse::number(input, input.trim())
+    }
+
+    fn part1(n_elves: &i32) -> impl Display {
+        simulate(*n_elves)
+    }
+
+    fn part2(n_elves: &i32) -> impl Display {
+        simulate_pt2(*n_elves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steal_from_the_left() {
+        assert_eq!(simulate(5), 3);
+    }
+
+    #[test]
+    fn test_steal_from_across() {
+        assert_eq!(simulate_pt2(5), 2);
+    }
+}
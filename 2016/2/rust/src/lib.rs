@@ -1,6 +1,16 @@
-use std::{collections::HashMap, fs, usize};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
-fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> String {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn find_code(instructions: &[Vec<Move>], previous_position: (usize, usize)) -> String {
     let numpad = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
     let r = numpad.len();
     let c = numpad[0].len();
@@ -8,9 +18,9 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
     let mut current_pos = previous_position;
 
     for instruction in instructions {
-        for mv in instruction.chars() {
+        for &mv in instruction {
             match mv {
-                'U' => {
+                Move::Up => {
                     if current_pos.0 == 0 {
                         //println!("Already at the top of numpad keeping position");
                         continue;
@@ -18,7 +28,7 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
                         current_pos.0 -= 1;
                     }
                 }
-                'D' => {
+                Move::Down => {
                     if current_pos.0 == r - 1 {
                         //println!("Already at the bottom of numpad keeping position");
                         continue;
@@ -26,7 +36,7 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
                         current_pos.0 += 1;
                     }
                 }
-                'L' => {
+                Move::Left => {
                     if current_pos.1 == 0 {
                         //println!("Already at the left most side of numpad keeping position");
                         continue;
@@ -34,7 +44,7 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
                         current_pos.1 -= 1;
                     }
                 }
-                'R' => {
+                Move::Right => {
                     if current_pos.1 == c - 1 {
                         //println!("Already at the right most side of numpad keeping position");
                         continue;
@@ -42,10 +52,6 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
                         current_pos.1 += 1;
                     }
                 }
-                _ => {
-                    println!("Invalid movement: {mv}");
-                    break;
-                }
             }
         }
         code.push_str(&numpad[current_pos.0][current_pos.1].to_string());
@@ -53,7 +59,7 @@ fn find_code(instructions: Vec<&str>, previous_position: (usize, usize)) -> Stri
     code
 }
 
-fn find_code_2(instructions: Vec<&str>, previous_position: (usize, usize)) -> String {
+fn find_code_2(instructions: &[Vec<Move>], previous_position: (usize, usize)) -> String {
     let numpad: HashMap<(usize, usize), String> = {
         let mut map = HashMap::new();
         map.insert((0, 2), "1".to_string());
@@ -76,9 +82,9 @@ fn find_code_2(instructions: Vec<&str>, previous_position: (usize, usize)) -> St
     let mut current_pos = previous_position;
 
     for instruction in instructions {
-        for mv in instruction.chars() {
+        for &mv in instruction {
             let next_pos = match mv {
-                'U' => {
+                Move::Up => {
                     if current_pos.0 > 0 {
                         //println!("Already at the top of numpad keeping position");
                         (current_pos.0 - 1, current_pos.1)
@@ -86,19 +92,15 @@ fn find_code_2(instructions: Vec<&str>, previous_position: (usize, usize)) -> St
                         current_pos
                     }
                 }
-                'D' => (current_pos.0 + 1, current_pos.1),
-                'L' => {
+                Move::Down => (current_pos.0 + 1, current_pos.1),
+                Move::Left => {
                     if current_pos.1 > 0 {
                         (current_pos.0, current_pos.1 - 1)
                     } else {
                         current_pos
                     }
                 }
-                'R' => (current_pos.0, current_pos.1 + 1),
-                _ => {
-                    println!("Invalid movement: {mv}");
-                    break;
-                }
+                Move::Right => (current_pos.0, current_pos.1 + 1),
             };
             if numpad.contains_key(&next_pos) {
                 current_pos = next_pos;
@@ -109,14 +111,57 @@ fn find_code_2(instructions: Vec<&str>, previous_position: (usize, usize)) -> St
     code
 }
 
-fn main() {
-    let binding = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let data: Vec<&str> = binding.trim().lines().collect();
-    let sep = "=".repeat(20);
-    let code = find_code(data.clone(), (1, 1));
-    println!("{sep} Part 1 {sep}");
-    println!("The code to the bathroom is :{code}");
-    let code_2 = find_code_2(data, (2, 0));
-    println!("{sep} Part 2 {sep}");
-    println!("The code to the bathroom is :{code_2}");
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<Move>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Move>>, ParseError> {
+        parse::lines(input.trim(), |line| {
+            (line.char_indices())
+                .map(|(i, c)| match c {
+                    'U' => Ok(Move::Up),
+                    'D' => Ok(Move::Down),
+                    'L' => Ok(Move::Left),
+                    'R' => Ok(Move::Right),
+                    _ => Err(ParseError::at(
+                        line,
+                        &line[i..i + c.len_utf8()],
+                        "a move `U`, `D`, `L` or `R`",
+                    )),
+                })
+                .collect()
+        })
+    }
+
+    fn part1(instructions: &Vec<Vec<Move>>) -> impl Display {
+        find_code(instructions, (1, 1))
+    }
+
+    fn part2(instructions: &Vec<Vec<Move>>) -> impl Display {
+        find_code_2(instructions, (2, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ULL
+RRDDD
+LURDL
+UUUUD";
+
+    #[test]
+    fn test_square_keypad() {
+        assert_eq!(find_code(&Day02::parse(EXAMPLE).unwrap(), (1, 1)), "1985");
+    }
+
+    #[test]
+    fn test_diamond_keypad() {
+        assert_eq!(find_code_2(&Day02::parse(EXAMPLE).unwrap(), (2, 0)), "5DB3");
+    }
 }
@@ -0,0 +1,96 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::cmp::max;
+use std::fmt::Display;
+
+fn min_valid_ip(mut blocked_ranges: Vec<(usize, usize)>) -> usize {
+    // Possible ips range from 0 to the max 32 bit integers
+    blocked_ranges.sort();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in blocked_ranges {
+        if merged.is_empty() || start > merged[merged.len() - 1].1 + 1 {
+            merged.push((start, end));
+        } else {
+            let last_index = merged.len() - 1;
+            merged[last_index].1 = max(merged[last_index].1, end);
+        }
+    }
+    let mut current_ip: usize = 0;
+    for (start, end) in merged {
+        if current_ip < start {
+            return current_ip;
+        } else {
+            current_ip = max(current_ip, end + 1);
+        }
+    }
+    current_ip
+}
+
+fn all_valid_ips(mut blocked_ranges: Vec<(usize, usize)>, max_ip: usize) -> usize {
+    // Possible ips range from 0 to the max 32 bit integers
+    blocked_ranges.sort();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in blocked_ranges {
+        if merged.is_empty() || start > merged[merged.len() - 1].1 + 1 {
+            merged.push((start, end));
+        } else {
+            let last_index = merged.len() - 1;
+            merged[last_index].1 = max(merged[last_index].1, end);
+        }
+    }
+    let mut current_ip: usize = 0;
+    let mut allowed_ips = 0;
+    for (start, end) in merged {
+        if current_ip < start {
+            allowed_ips += start - current_ip;
+        }
+        current_ip = max(current_ip, end + 1);
+    }
+    allowed_ips + (max_ip + 1).saturating_sub(current_ip)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 20;
+
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        parse::lines(input, |line| {
+            let (start, end) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(line, line, "a range `start-end`"))?;
+            Ok((
+                parse::number(line, start.trim())?,
+                parse::number(line, end.trim())?,
+            ))
+        })
+    }
+
+    fn part1(excluded_ips: &Vec<(usize, usize)>) -> impl Display {
+        min_valid_ip(excluded_ips.clone())
+    }
+
+    fn part2(excluded_ips: &Vec<(usize, usize)>) -> impl Display {
+        all_valid_ips(excluded_ips.clone(), u32::MAX as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blacklist() {
+        let blocked = Day20::parse("5-8\n0-2\n4-7").unwrap();
+        assert_eq!(min_valid_ip(blocked.clone()), 3);
+        // With addresses 0 through 9, only 3 and 9 are allowed.
+        assert_eq!(all_valid_ips(blocked, 9), 2);
+    }
+
+    #[test]
+    fn test_overlapping_and_adjacent_ranges() {
+        assert_eq!(all_valid_ips(vec![(0, 3), (2, 5), (6, 6), (9, 10)], 12), 4);
+    }
+}
@@ -1,6 +1,7 @@
-use std::fs;
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
 
-enum Instruction {
+pub enum Instruction {
     SwapPos(usize, usize),
     SwapLetter(char, char),
     RotateLeft(usize),
@@ -10,66 +11,31 @@ enum Instruction {
     Move(usize, usize),
 }
 
-fn parse_instruction(instruction: &str) -> Instruction {
-    let parts: Vec<_> = instruction.split_whitespace().collect();
-    let inst_type = format!("{} {}", parts[0], parts[1]);
-    match inst_type.as_str() {
-        "swap position" => {
-            let x = parts[2]
-                .parse::<usize>()
-                .expect("Failed to unwrap x on swap position");
-            let y = parts[5]
-                .parse::<usize>()
-                .expect("Failed to unwrap y on swap position");
-            Instruction::SwapPos(x, y)
-        }
-        "swap letter" => {
-            let x = parts[2].chars().next().unwrap();
-            let y = parts[5].chars().next().unwrap();
-            Instruction::SwapLetter(x, y)
-        }
-        "rotate left" => {
-            let x = parts[2]
-                .parse::<usize>()
-                .expect("Failed to unwrap x on rotate left");
-            Instruction::RotateLeft(x)
-        }
-        "rotate right" => {
-            let x = parts[2]
-                .parse::<usize>()
-                .expect("Failed to unwrap x on rotate right");
-            Instruction::RotateRight(x)
-        }
-        "rotate based" => {
-            let x = parts[6]
-                .chars()
-                .next()
-                .expect("Failed to unwrap x on rotate based");
-            Instruction::RotateBased(x)
-        }
-        "reverse positions" => {
-            let x = parts[2]
-                .parse::<usize>()
-                .expect("Failed to unwrap x on reverse positions");
-            let y = parts[4]
-                .parse::<usize>()
-                .expect("Failed to unwrap y on reverse positions");
-            Instruction::Reverse(x, y)
-        }
-        "move position" => {
-            let x = parts[2]
-                .parse::<usize>()
-                .expect("Failed to unwrap x on move position");
-            let y = parts[5]
-                .parse::<usize>()
-                .expect("Failed to unwrap y on move position");
-            Instruction::Move(x, y)
-        }
-        _ => {
-            println!("Invalid Instruction: {}", inst_type);
-            panic!("BOOOOMM")
-        }
-    }
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let position = |index| parse::number::<usize>(line, parse::word(line, index, "a position")?);
+    let letter = |index| {
+        let word = parse::word(line, index, "a letter")?;
+        let mut chars = word.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ParseError::at(line, word, "a letter")),
+        }
+    };
+    let verb = parse::word(line, 0, "an operation")?;
+    let object = parse::word(line, 1, "an operation")?;
+    Ok(match (verb, object) {
+        ("swap", "position") => Instruction::SwapPos(position(2)?, position(5)?),
+        ("swap", "letter") => Instruction::SwapLetter(letter(2)?, letter(5)?),
+        ("rotate", "left") => Instruction::RotateLeft(position(2)?),
+        ("rotate", "right") => Instruction::RotateRight(position(2)?),
+        ("rotate", "based") => Instruction::RotateBased(letter(6)?),
+        ("reverse", "positions") => Instruction::Reverse(position(2)?, position(4)?),
+        ("move", "position") => Instruction::Move(position(2)?, position(5)?),
+        ("swap" | "rotate" | "reverse" | "move", _) => {
+            return Err(ParseError::at(line, object, format!("a kind of {verb}")));
+        }
+        _ => return Err(ParseError::at(line, verb, "swap, rotate, reverse or move")),
+    })
 }
 
 fn apply_instruction(s: &str, instr: &Instruction) -> String {
@@ -168,36 +134,72 @@ fn reverse_instruction(s: &str, instr: &Instruction) -> String {
     }
 }
 
-fn main() {
-    let input: Vec<String> = fs::read_to_string("../input.txt")
-        .expect("Error reading input")
-        .lines()
-        .map(|line| line.to_string())
-        .collect();
+pub struct Day21;
 
-    let instructions: Vec<Instruction> = input.iter().map(|line| parse_instruction(line)).collect();
+impl Solution for Day21 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 21;
 
-    let base_pass = "abcdefgh";
-    let to_unscramble = "fbgdceah";
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
+    type Input = Vec<Instruction>;
 
-    let part1 = instructions
-        .iter()
-        .fold(base_pass.to_string(), |acc, instr| {
-            apply_instruction(&acc, instr)
-        });
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse::lines(input, parse_instruction)
+    }
 
-    println!("The scrambled password is: {part1}");
+    fn part1(instructions: &Vec<Instruction>) -> impl Display {
+        instructions
+            .iter()
+            .fold("abcdefgh".to_string(), |acc, instr| {
+                apply_instruction(&acc, instr)
+            })
+    }
 
-    println!("\n{sep} Part 2 {sep}");
+    fn part2(instructions: &Vec<Instruction>) -> impl Display {
+        instructions
+            .iter()
+            .rev()
+            .fold("fbgdceah".to_string(), |acc, instr| {
+                reverse_instruction(&acc, instr)
+            })
+    }
+}
 
-    let part2 = instructions
-        .iter()
-        .rev()
-        .fold(to_unscramble.to_string(), |acc, instr| {
-            reverse_instruction(&acc, instr)
-        });
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("The unscrambled password is: {part2}");
+    const EXAMPLE: &str = "swap position 4 with position 0
+swap letter d with letter b
+reverse positions 0 through 4
+rotate left 1 step
+move position 1 to position 4
+move position 3 to position 0
+rotate based on position of letter b
+rotate based on position of letter d";
+
+    #[test]
+    fn test_scramble() {
+        let steps: Vec<String> = Day21::parse(EXAMPLE)
+            .unwrap()
+            .iter()
+            .scan("abcde".to_string(), |password, instr| {
+                *password = apply_instruction(password, instr);
+                Some(password.clone())
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                "ebcda", "edcba", "abcde", "bcdea", "bdeac", "abdec", "ecabd", "decab"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unscramble_undoes_each_step() {
+        for instr in Day21::parse(EXAMPLE).unwrap() {
+            let scrambled = apply_instruction("abcdefgh", &instr);
+            assert_eq!(reverse_instruction(&scrambled, &instr), "abcdefgh");
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use aoc_common::grid::Pos;
+use aoc_common::{Grid, ParseError, Solution, parse, search};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Node {
+    x: usize,
+    y: usize,
+    used: u32,
+    avail: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+    Goal,
+    Data,
+}
+
+fn parse_node(line: &str) -> Result<Node, ParseError> {
+    let coordinates = parse::word(line, 0, "a node name")?;
+    let (x, y) = coordinates
+        .rsplit_once("-x")
+        .and_then(|(_, xy)| xy.split_once("-y"))
+        .ok_or_else(|| ParseError::at(line, coordinates, "a node `/dev/grid/node-xX-yY`"))?;
+    let size = |index, expected| {
+        parse::number(
+            line,
+            parse::word(line, index, expected)?.trim_end_matches('T'),
+        )
+    };
+
+    Ok(Node {
+        x: parse::number(line, x)?,
+        y: parse::number(line, y)?,
+        used: size(2, "a used size")?,
+        avail: size(3, "an available size")?,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Node>, ParseError> {
+    // The first two lines are the `df` command and its header.
+    let body = input.splitn(3, '\n').nth(2).unwrap_or("");
+    parse::lines(body, parse_node).map_err(|e| e.below(2))
+}
+
+fn count_viable_pairs(nodes: &[Node]) -> u32 {
+    let mut count: u32 = 0;
+    let mut avails: Vec<&Node> = nodes.iter().collect();
+    avails.sort_unstable_by_key(|n| n.avail);
+    for a in nodes.iter().filter(|n| n.used > 0) {
+        let idx = avails.partition_point(|b| b.avail < a.used);
+        for b in &avails[idx..] {
+            if a.x == b.x && a.y == b.y {
+                continue;
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+fn classify_node(n: &Node, goal_pos: Pos) -> Tile {
+    if n.used == 0 {
+        Tile::Empty
+    } else if (n.x, n.y) == goal_pos {
+        Tile::Goal
+    } else if n.used > 100 {
+        Tile::Wall
+    } else {
+        Tile::Data
+    }
+}
+
+fn build_grid(nodes: &[Node]) -> (Grid<Tile>, Pos, Pos) {
+    let max_x = nodes.iter().map(|n| n.x).max().unwrap();
+    let max_y = nodes.iter().map(|n| n.y).max().unwrap();
+    let goal_pos = (max_x, 0);
+    let mut empty_pos = (0, 0);
+    let mut grid = Grid::new(max_x + 1, max_y + 1, Tile::Data);
+
+    for node in nodes {
+        let tile = classify_node(node, goal_pos);
+        if tile == Tile::Empty {
+            empty_pos = (node.x, node.y);
+        }
+        grid[(node.x, node.y)] = tile;
+    }
+    (grid, empty_pos, goal_pos)
+}
+
+fn shortest_move(grid: &Grid<Tile>, start: Pos, goal: Pos) -> Option<usize> {
+    let open = |&pos: &Pos| {
+        grid.neighbors4(pos)
+            .filter(|&next| grid[next] != Tile::Wall)
+            .collect::<Vec<_>>()
+    };
+    search::bfs(start, open, |&pos| pos == goal).map(|found| found.cost)
+}
+
+fn solve_pt2(nodes: &[Node]) -> i32 {
+    let (grid, empty, goal) = build_grid(nodes);
+
+    let target = (goal.0 - 1, goal.1); // move empty left of goal
+    let mut total_steps =
+        shortest_move(&grid, empty, target).expect("No path to goal-adjacent") as i32;
+    total_steps += 5 * (goal.0 as i32 - 1) + 1;
+    total_steps
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 22;
+
+    type Input = Vec<Node>;
+
+    fn parse(input: &str) -> Result<Vec<Node>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(nodes: &Vec<Node>) -> impl Display {
+        count_viable_pairs(nodes)
+    }
+
+    fn part2(nodes: &Vec<Node>) -> impl Display {
+        solve_pt2(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "root@ebhq-gridcenter# df -h
+Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%";
+
+    #[test]
+    fn test_viable_pairs() {
+        // Every node but the 28T one fits into the empty node.
+        assert_eq!(count_viable_pairs(&Day22::parse(EXAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    fn test_move_goal_data() {
+        assert_eq!(solve_pt2(&Day22::parse(EXAMPLE).unwrap()), 7);
+    }
+}
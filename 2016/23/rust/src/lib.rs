@@ -0,0 +1,73 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::assembunny::{self, Instruction};
+use aoc_vm::{Machine, Word};
+use std::fmt::Display;
+
+// Register a once the program has run with register a set to `eggs`. The
+// add and multiply loops are shortcut, which is what makes 12 eggs feasible.
+fn solve(program: &[Instruction], eggs: Word) -> Word {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_register('a', eggs);
+    assembunny::run_fast(&mut machine);
+    machine.register('a')
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 23;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        assembunny::parse(input, &["cpy", "inc", "dec", "jnz", "tgl"])
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> impl Display {
+        solve(instructions, 7)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> impl Display {
+        solve(instructions, 12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let program = Day23::parse("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a").unwrap();
+        assert_eq!(solve(&program, 0), 3);
+    }
+
+    // The puzzle's program has this shape, with other constants: it works out
+    // a! through a multiply loop, then toggles its tail into a + 3 * 4.
+    const FACTORIAL: &str = "cpy a b\ndec b\ncpy a d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2
+dec d\njnz d -5\ndec b\ncpy b c\ncpy c d\ndec d\ninc c\njnz d -2\ntgl c\ncpy -16 c\njnz 1 c
+cpy 3 c\njnz 4 d\ninc a\ninc d\njnz d -2\ninc c\njnz c -5";
+
+    #[test]
+    fn test_loops_after_toggling() {
+        let program = Day23::parse(FACTORIAL).unwrap();
+        let mut machine = Machine::new(program.clone());
+        machine.set_register('a', 7);
+        machine.run();
+        assert_eq!(machine.register('a'), 5040 + 12);
+        assert_eq!(solve(&program, 7), 5040 + 12);
+        assert_eq!(solve(&program, 12), 479_001_600 + 12);
+    }
+
+    #[test]
+    fn test_malformed_program() {
+        let err = Day23::parse("cpy 2 a\ntgl e").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "e"));
+        let err = Day23::parse("cpy 2 a\nmul a b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected one of cpy, inc, dec, jnz, tgl, found `mul`"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::grid::Pos;
+use aoc_common::{Grid, ParseError, Solution, search};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+struct Point {
+    letter: u8,
+    pos: Pos,
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let allowed = |c: char| c == '.' || c == '#' || c.is_ascii_digit();
+    Grid::parse(
+        input,
+        |c| allowed(c).then_some(c),
+        "`.`, `#` or a location digit",
+    )
+}
+
+fn find_points(grid: &Grid<char>) -> Vec<Point> {
+    grid.iter()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(pos, &c)| Point {
+            letter: (c as u8) - b'0',
+            pos,
+        })
+        .collect()
+}
+
+fn distances_from(start: Pos, grid: &Grid<char>) -> HashMap<Pos, usize> {
+    let open = |&pos: &Pos| {
+        grid.neighbors4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect::<Vec<_>>()
+    };
+    search::distances(start, open, usize::MAX)
+}
+
+fn solve(grid: &Grid<char>, return_to_start: bool) -> usize {
+    let points = find_points(grid);
+    let n = points.len();
+    let mut dist = vec![vec![0; n]; n];
+    for (i, from) in points.iter().enumerate() {
+        let dists = distances_from(from.pos, grid);
+        for (j, to) in points.iter().enumerate() {
+            if i != j {
+                dist[i][j] = dists[&to.pos];
+            }
+        }
+    }
+    let start_idx = points.iter().position(|p| p.letter == 0).unwrap();
+    let other_indices: Vec<usize> = (0..n).filter(|&i| i != start_idx).collect();
+
+    let mut min_total_dist = usize::MAX;
+
+    for perm in other_indices.iter().permutations(other_indices.len()) {
+        let mut total = 0;
+        let mut prev = start_idx;
+
+        for &next in perm {
+            total += dist[prev][next];
+            prev = next;
+        }
+        if return_to_start {
+            total += dist[prev][start_idx];
+        }
+        min_total_dist = min_total_dist.min(total);
+    }
+    min_total_dist
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 24;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> impl Display {
+        solve(grid, false)
+    }
+
+    fn part2(grid: &Grid<char>) -> impl Display {
+        solve(grid, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########";
+
+    #[test]
+    fn test_visit_every_point() {
+        assert_eq!(solve(&Day24::parse(EXAMPLE).unwrap(), false), 14);
+    }
+
+    #[test]
+    fn test_return_to_start() {
+        // 0 -> 4 -> 3 -> 2 -> 1 -> 0
+        assert_eq!(solve(&Day24::parse(EXAMPLE).unwrap(), true), 20);
+    }
+}
@@ -0,0 +1,175 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::assembunny::{self, Instruction};
+use aoc_vm::{Machine, Step, Word};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+// How the program's output turned out for some starting a.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Signal {
+    // At an `out`, the machine was back in the state it was in at an earlier
+    // one, about to send the same value. Everything since then repeats, so
+    // the output alternates 0, 1, 0, 1 forever.
+    Clock,
+    // Output `index` (from 0) was `value` where the clock needs `index % 2`.
+    Diverged { index: usize, value: Word },
+    // The program ended after `outputs` values.
+    Halted { outputs: usize },
+    // `steps` steps went by without an output.
+    Silent { steps: u64 },
+    // After `steps` steps the output was still a clock signal, `outputs`
+    // values long, but the machine had not come back to an earlier state.
+    Unsettled { outputs: usize, steps: u64 },
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Signal::Clock => write!(f, "a clock signal"),
+            Signal::Diverged { index, value } => write!(
+                f,
+                "output {index} is {value} where the clock needs {}",
+                index % 2
+            ),
+            Signal::Halted { outputs } => write!(f, "halted after {outputs} outputs"),
+            Signal::Silent { steps } => write!(f, "no output in {steps} steps"),
+            Signal::Unsettled { outputs, steps } => write!(
+                f,
+                "still a clock after {outputs} outputs in {steps} steps, but not yet repeating"
+            ),
+        }
+    }
+}
+
+// Runs the program with register a set to `a` until its output is proven to
+// be a clock signal or is not one, recording the state at every `out`, or
+// until `max_steps` steps have gone by.
+fn check_clock(instructions: &[Instruction], a: Word, max_steps: u64) -> Signal {
+    let mut machine = Machine::new(instructions.to_vec());
+    machine.set_register('a', a);
+    let mut seen = HashSet::new();
+    let mut index = 0;
+    let (mut quiet, mut steps) = (0, 0);
+    loop {
+        if let Some(Instruction::Out(x)) = machine.current() {
+            let (value, parity) = (machine.value(x), index as Word % 2);
+            if value != parity {
+                return Signal::Diverged { index, value };
+            }
+            if !seen.insert((machine.pc, machine.registers, parity)) {
+                return Signal::Clock;
+            }
+            index += 1;
+            quiet = 0;
+        } else if quiet == max_steps {
+            return Signal::Silent { steps: quiet };
+        }
+        if steps == max_steps {
+            return Signal::Unsettled {
+                outputs: index,
+                steps,
+            };
+        }
+        match assembunny::step_fast(&mut machine) {
+            Step::Halted | Step::Blocked => return Signal::Halted { outputs: index },
+            Step::Ran | Step::Output(_) => {
+                quiet += 1;
+                steps += 1;
+            }
+        }
+    }
+}
+
+fn find_min_a(instructions: &[Instruction], max_steps: u64) -> Result<Word, String> {
+    for a in 0.. {
+        match check_clock(instructions, a, max_steps) {
+            Signal::Clock => return Ok(a),
+            stuck @ (Signal::Silent { .. } | Signal::Unsettled { .. }) => {
+                return Err(format!("with a = {a}: {stuck}"));
+            }
+            Signal::Diverged { .. } | Signal::Halted { .. } => {}
+        }
+    }
+    unreachable!()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 25;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        assembunny::parse(input, &["cpy", "inc", "dec", "jnz", "out"])
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> impl Display {
+        find_min_a(instructions, aoc_vm::max_steps()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part2(_instructions: &Vec<Instruction>) -> impl Display {
+        // There is no second puzzle on the last day.
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(text: &str) -> Vec<Instruction> {
+        Day25::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_check_clock() {
+        // Thirty alternating outputs are not enough: the 31st breaks the clock.
+        let countdown = program("cpy 15 b\nout 0\nout 1\ndec b\njnz b -3\nout 5");
+        assert_eq!(
+            check_clock(&countdown, 0, 1000),
+            Signal::Diverged {
+                index: 30,
+                value: 5
+            }
+        );
+        assert_eq!(
+            check_clock(&program("out a\ninc a\nout a"), 0, 1000),
+            Signal::Halted { outputs: 2 }
+        );
+        let silent = program("out a\ninc a\njnz 1 0");
+        assert_eq!(
+            check_clock(&silent, 0, 1000),
+            Signal::Silent { steps: 1000 }
+        );
+        assert_eq!(
+            find_min_a(&silent, 1000),
+            Err("with a = 0: no output in 1000 steps".to_string())
+        );
+        // A clock that counts up in b never comes back to the same state.
+        let counting = program("out 0\nout 1\ninc b\njnz 1 -3");
+        assert_eq!(
+            check_clock(&counting, 0, 1000),
+            Signal::Unsettled {
+                outputs: 501,
+                steps: 1000
+            }
+        );
+        assert!(find_min_a(&counting, 1000).is_err());
+    }
+
+    #[test]
+    fn test_find_min_a() {
+        // The puzzle has no example; this program emits a - 3 and a - 2 in
+        // turn, so it only produces the clock signal when a starts at 3.
+        let program =
+            Day25::parse("dec a\ndec a\ndec a\nout a\ninc a\nout a\ndec a\njnz 1 -4").unwrap();
+        assert_eq!(find_min_a(&program, 1000), Ok(3));
+        assert_eq!(check_clock(&program, 3, 1000), Signal::Clock);
+        assert_eq!(
+            check_clock(&program, 4, 1000).to_string(),
+            "output 0 is 1 where the clock needs 0"
+        );
+    }
+}
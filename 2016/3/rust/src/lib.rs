@@ -0,0 +1,89 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn parse_sides(line: &str) -> Result<[i32; 3], ParseError> {
+    let side = |index| parse::number(line, parse::word(line, index, "three side lengths")?);
+    Ok([side(0)?, side(1)?, side(2)?])
+}
+
+fn check_valid_triangle(triangles: &[[i32; 3]]) -> i32 {
+    let mut valid_count = 0;
+
+    for &[a, b, c] in triangles {
+        if a + b > c && a + c > b && b + c > a {
+            valid_count += 1;
+        }
+    }
+    valid_count
+}
+
+fn check_valid_triangle_2(triangles: &[[i32; 3]]) -> i32 {
+    let mut valid_count = 0;
+    let mut column_a: Vec<i32> = Vec::new();
+    let mut column_b: Vec<i32> = Vec::new();
+    let mut column_c: Vec<i32> = Vec::new();
+
+    for &[a, b, c] in triangles {
+        column_a.push(a);
+        column_b.push(b);
+        column_c.push(c);
+    }
+    let all_columns = [column_a, column_b, column_c];
+
+    for col in all_columns.iter() {
+        for chunk in col.chunks(3) {
+            let a = chunk[0];
+            let b = chunk[1];
+            let c = chunk[2];
+
+            if a + b > c && a + c > b && b + c > a {
+                valid_count += 1;
+            }
+        }
+    }
+    valid_count
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 3;
+
+    type Input = Vec<[i32; 3]>;
+
+    fn parse(input: &str) -> Result<Vec<[i32; 3]>, ParseError> {
+        parse::lines(input, parse_sides)
+    }
+
+    fn part1(triangles: &Vec<[i32; 3]>) -> impl Display {
+        check_valid_triangle(triangles)
+    }
+
+    fn part2(triangles: &Vec<[i32; 3]>) -> impl Display {
+        check_valid_triangle_2(triangles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "101 301 501
+102 302 502
+103 303 503
+201 401 601
+202 402 602
+203 403 603";
+
+    #[test]
+    fn test_rows() {
+        assert_eq!(check_valid_triangle(&Day03::parse("5 10 25").unwrap()), 0);
+        assert_eq!(check_valid_triangle(&Day03::parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(check_valid_triangle_2(&Day03::parse(EXAMPLE).unwrap()), 6);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+fn calculate_checksum(name: &str) -> String {
+    let mut letter_counts: HashMap<char, i32> = HashMap::new();
+
+    for ch in name.chars() {
+        if ch.is_alphabetic() {
+            *letter_counts.entry(ch).or_insert(0) += 1;
+        }
+    }
+    let mut letter_vec: Vec<(char, i32)> = letter_counts.into_iter().collect();
+    letter_vec.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    letter_vec.iter().map(|(ch, _)| *ch).take(5).collect()
+}
+
+pub struct Room {
+    name: String,
+    sector_id: i32,
+    checksum: String,
+}
+
+fn parse_room(line: &str) -> Result<Room, ParseError> {
+    let expected = "a room `name-123[check]`";
+    let (name, id_checksum) = line
+        .rsplit_once('-')
+        .ok_or_else(|| ParseError::at(line, line, expected))?;
+    let (id_str, checksum) = id_checksum
+        .split_once('[')
+        .ok_or_else(|| ParseError::at(line, id_checksum, expected))?;
+    let checksum = checksum
+        .strip_suffix(']')
+        .ok_or_else(|| ParseError::at(line, checksum, "a checksum closed by `]`"))?;
+    Ok(Room {
+        name: name.to_string(),
+        sector_id: parse::number(line, id_str)?,
+        checksum: checksum.to_string(),
+    })
+}
+
+fn check_valid_room(rooms: &[Room]) -> i32 {
+    let mut valid_id_sum = 0;
+
+    for room in rooms {
+        let calculated_checksum = calculate_checksum(&room.name);
+
+        if calculated_checksum == room.checksum {
+            valid_id_sum += room.sector_id;
+        }
+    }
+    valid_id_sum
+}
+
+fn decrypt_name(name: &str, sector_id: i32) -> String {
+    name.chars()
+        .map(|ch| {
+            if ch == '-' {
+                ' '
+            } else if ch.is_alphabetic() {
+                let shift = (ch as u8 - b'a' + (sector_id % 26) as u8) % 26;
+                (b'a' + shift) as char
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
+fn find_north_pole_room(rooms: &[Room]) -> Option<i32> {
+    for room in rooms {
+        let decrypted_name = decrypt_name(&room.name, room.sector_id);
+
+        if decrypted_name.contains("northpole") {
+            return Some(room.sector_id);
+        }
+    }
+    None
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Room>;
+
+    fn parse(input: &str) -> Result<Vec<Room>, ParseError> {
+        parse::lines(input, parse_room)
+    }
+
+    fn part1(rooms: &Vec<Room>) -> impl Display {
+        check_valid_room(rooms)
+    }
+
+    fn part2(rooms: &Vec<Room>) -> impl Display {
+        find_north_pole_room(rooms).expect("North Pole room not found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_real_rooms() {
+        let rooms = Day04::parse(
+            "aaaaa-bbb-z-y-x-123[abxyz]
+a-b-c-d-e-f-g-h-987[abcde]
+not-a-real-room-404[oarel]
+totally-real-room-200[decoy]",
+        )
+        .unwrap();
+        assert_eq!(check_valid_room(&rooms), 123 + 987 + 404);
+    }
+
+    #[test]
+    fn test_decrypt() {
+        assert_eq!(
+            decrypt_name("qzmt-zixmtkozy-ivhz", 343),
+            "very encrypted name"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_common::{ParseError, Solution};
+use aoc_hash::mining::{self, Digest};
+use std::fmt::Display;
+
+// The hashes of the door id and an index that start with five zeros, in
+// index order.
+fn interesting(door_id: &str) -> impl Iterator<Item = Digest> + '_ {
+    mining::search(door_id, 0, |digest| {
+        digest.starts_with_zeros(5).then_some(*digest)
+    })
+    .map(|(_, digest)| digest)
+}
+
+fn hex_digit(nibble: u8) -> char {
+    char::from_digit(nibble.into(), 16).unwrap()
+}
+
+fn generate_password_part1(door_id: &str) -> String {
+    interesting(door_id)
+        .map(|digest| hex_digit(digest.nibble(5)))
+        .take(8)
+        .collect()
+}
+
+fn generate_password_part2(door_id: &str) -> String {
+    let mut password = ['_'; 8];
+    let mut found_positions = 0;
+    let mut hashes = interesting(door_id);
+
+    while found_positions < 8 {
+        let digest = hashes.next().expect("the search never ends");
+        let pos = digest.nibble(5) as usize;
+        if pos < 8 && password[pos] == '_' {
+            password[pos] = hex_digit(digest.nibble(6));
+            found_positions += 1;
+        }
+    }
+
+    password.iter().collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 5;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(door_id: &String) -> impl Display {
+        generate_password_part1(door_id)
+    }
+
+    fn part2(door_id: &String) -> impl Display {
+        generate_password_part2(door_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interesting_hashes() {
+        let hashes: Vec<String> = interesting("abc")
+            .take(4)
+            .map(|digest| digest.to_string())
+            .collect();
+        assert!(hashes[0].starts_with("000001"));
+        assert!(hashes[1].starts_with("000008f82"));
+        assert!(hashes[2].starts_with("00000f"));
+        assert!(hashes[3].starts_with("000004e"));
+    }
+
+    #[test]
+    #[ignore = "hashes tens of millions of candidates"]
+    fn test_passwords() {
+        assert_eq!(generate_password_part1("abc"), "18f47a30");
+        assert_eq!(generate_password_part2("abc"), "05ace8e3");
+    }
+}
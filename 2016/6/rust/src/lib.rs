@@ -0,0 +1,102 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+fn find_message(messages: &[String], max: bool) -> String {
+    let num_cols = messages[0].len();
+    let mut columns: Vec<HashMap<char, i32>> = vec![HashMap::new(); num_cols];
+
+    for line in messages {
+        for (i, ch) in line.chars().enumerate() {
+            *columns[i].entry(ch).or_insert(0) += 1;
+        }
+    }
+    if max {
+        columns
+            .into_iter()
+            .filter_map(|col| {
+                col.into_iter()
+                    .max_by_key(|&(_ch, count)| count)
+                    .map(|(ch, _)| ch)
+            })
+            .collect()
+    } else {
+        columns
+            .into_iter()
+            .filter_map(|col| {
+                col.into_iter()
+                    .min_by_key(|&(_ch, count)| count)
+                    .map(|(ch, _)| ch)
+            })
+            .collect()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 6;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        let width = input.lines().next().map_or(0, str::len);
+        parse::lines(input, |line| {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    line,
+                    line,
+                    format!("a message of {width} characters"),
+                ));
+            }
+            Ok(line.to_string())
+        })
+    }
+
+    fn part1(messages: &Vec<String>) -> impl Display {
+        find_message(messages, true)
+    }
+
+    fn part2(messages: &Vec<String>) -> impl Display {
+        find_message(messages, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "eedadn
+drvtee
+eandsr
+raavrd
+atevrs
+tsrnev
+sdttsa
+rasrtv
+nssdts
+ntnada
+svetve
+tesnvt
+vntsnd
+vrdear
+dvrsen
+enarar";
+
+    #[test]
+    fn test_most_common() {
+        assert_eq!(
+            find_message(&Day06::parse(EXAMPLE).unwrap(), true),
+            "easter"
+        );
+    }
+
+    #[test]
+    fn test_least_common() {
+        assert_eq!(
+            find_message(&Day06::parse(EXAMPLE).unwrap(), false),
+            "advent"
+        );
+    }
+}
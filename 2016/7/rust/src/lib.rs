@@ -1,4 +1,5 @@
-use std::{char, fs};
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
 
 fn has_abba(ip: &str) -> bool {
     ip.as_bytes()
@@ -73,14 +74,44 @@ fn support_ssl(ip: &str) -> bool {
     false
 }
 
-fn main() {
-    let binding = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let data: Vec<&str> = binding.lines().collect();
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    let part1 = data.iter().filter(|line| supports_tls(line)).count();
-    println!("The total count of valid ips is: {}", part1);
-    println!("{sep} Part 2 {sep}");
-    let part2 = data.iter().filter(|line| support_ssl(line)).count();
-    println!("The total count of SSL-supporting ips is: {}", part2);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 7;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(ips: &Vec<String>) -> impl Display {
+        ips.iter().filter(|line| supports_tls(line)).count()
+    }
+
+    fn part2(ips: &Vec<String>) -> impl Display {
+        ips.iter().filter(|line| support_ssl(line)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tls() {
+        assert!(supports_tls("abba[mnop]qrst"));
+        assert!(!supports_tls("abcd[bddb]xyyx"));
+        assert!(!supports_tls("aaaa[qwer]tyui"));
+        assert!(supports_tls("ioxxoj[asdfgh]zxcvbn"));
+    }
+
+    #[test]
+    fn test_ssl() {
+        assert!(support_ssl("aba[bab]xyz"));
+        assert!(!support_ssl("xyx[xyx]xyx"));
+        assert!(support_ssl("aaa[kek]eke"));
+        assert!(support_ssl("zazbz[bzb]cdb"));
+    }
 }
@@ -0,0 +1,151 @@
+use aoc_common::{Grid, ParseError, Solution, ocr, parse};
+use std::fmt::Display;
+
+const WIDTH: usize = 50;
+const HEIGHT: usize = 6;
+
+fn count_lit_pixels(grid: &Grid<bool>) -> usize {
+    grid.count(|&lit| lit)
+}
+
+enum Operation {
+    Rect(usize, usize),
+    RotateRow(usize, usize),
+    RotateColumn(usize, usize),
+}
+
+fn parse_operation(line: &str) -> Result<Operation, ParseError> {
+    if let Some(dims) = line.strip_prefix("rect ") {
+        let (a, b) = dims
+            .split_once('x')
+            .ok_or_else(|| ParseError::at(line, dims, "a size `AxB`"))?;
+        return Ok(Operation::Rect(
+            parse::number(line, a)?,
+            parse::number(line, b)?,
+        ));
+    }
+    let axis = parse::word(line, 1, "`row` or `column`")?;
+    let target = parse::word(line, 2, "a row `y=A` or column `x=A`")?;
+    let (index, size) = match (axis, target.split_once('=')) {
+        ("row", Some(("y", index))) => (index, HEIGHT),
+        ("column", Some(("x", index))) => (index, WIDTH),
+        _ => return Err(ParseError::at(line, target, "a row `y=A` or column `x=A`")),
+    };
+    let index = match parse::number(line, index)? {
+        n if n < size => n,
+        _ => {
+            return Err(ParseError::at(
+                line,
+                index,
+                format!("an index below {size}"),
+            ));
+        }
+    };
+    let by = parse::number(line, parse::word(line, 4, "a rotation amount")?)?;
+    match parse::word(line, 0, "an operation")? {
+        "rotate" if axis == "row" => Ok(Operation::RotateRow(index, by)),
+        "rotate" => Ok(Operation::RotateColumn(index, by)),
+        other => Err(ParseError::at(line, other, "`rect` or `rotate`")),
+    }
+}
+
+fn update_grid(operation: &Operation, grid: &mut Grid<bool>) {
+    match *operation {
+        Operation::Rect(a, b) => {
+            for y in 0..b.min(HEIGHT) {
+                for x in 0..a.min(WIDTH) {
+                    grid[(x, y)] = true;
+                }
+            }
+        }
+        Operation::RotateRow(row, by) => grid.rotate_row(row, by),
+        Operation::RotateColumn(col, by) => grid.rotate_column(col, by),
+    }
+}
+
+// The screen after `input`'s operations.
+fn screen(input: &str) -> Result<Grid<bool>, ParseError> {
+    let mut grid = Grid::new(WIDTH, HEIGHT, false);
+    for operation in parse::lines(input.trim(), parse_operation)? {
+        update_grid(&operation, &mut grid);
+    }
+    Ok(grid)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 8;
+
+    type Input = Grid<bool>;
+
+    // Part 2 reads the screen, so screens that do not show letters are
+    // refused here.
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        let grid = screen(input)?;
+        if let Err(err) = ocr::read(&grid) {
+            let nowhere = &input[input.len()..];
+            let expected = format!("operations that draw letters ({err})");
+            return Err(ParseError::at(input, nowhere, expected));
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Grid<bool>) -> impl Display {
+        count_lit_pixels(grid)
+    }
+
+    fn part2(grid: &Grid<bool>) -> impl Display {
+        ocr::read(grid).expect("parse checks that the screen shows letters")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_operations() {
+        // The puzzle runs these on a 7x3 screen; on the real 50x6 one the last
+        // column rotation no longer wraps around.
+        let grid = screen(
+            "rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1",
+        )
+        .unwrap();
+        assert_eq!(count_lit_pixels(&grid), 6);
+        let rows: Vec<String> = grid
+            .render(|&lit| if lit { '#' } else { '.' })
+            .lines()
+            .map(|row| row[..7].to_string())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                "....#.#", "#.#....", ".#.....", ".#.....", ".......", "......."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_letters() {
+        let grid = Day08::parse(
+            "rect 4x1
+rotate column x=0 by 5
+rotate column x=1 by 5
+rotate column x=2 by 5
+rotate column x=3 by 5
+rect 1x6",
+        )
+        .unwrap();
+        assert_eq!(Day08::part2(&grid).to_string(), "L");
+        let err = Day08::parse("rect 3x2").err().unwrap();
+        assert!(
+            err.expected
+                .starts_with("operations that draw letters (letter 1 is not")
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use std::error::Error;
-use std::fs;
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
 use std::str;
 
 // --- Part 1 ---
@@ -104,23 +104,51 @@ fn solve_part2(data: &[u8]) -> Result<u64, &'static str> {
     calculate_decompressed_length_recursive(data, 0, data.len())
 }
 
-// --- Main execution ---
-fn main() -> Result<(), Box<dyn Error>> {
-    let input_path = "../input.txt";
-    let input_data = fs::read_to_string(input_path)?;
-    let trimmed_data = input_data.trim(); // Remove leading/trailing whitespace
-    let bytes = trimmed_data.as_bytes();
-    let sep = "=".repeat(20);
-
-    match solve_part1(bytes) {
-        Ok(length) => println!("{sep} Part 1 {sep}\nDecompressed length = {}", length),
-        Err(e) => eprintln!("Part 1 Error: {}", e),
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 9;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
     }
 
-    match solve_part2(bytes) {
-        Ok(length) => println!("{sep} Part 2{sep}\nDecompressed length = {}", length),
-        Err(e) => eprintln!("Part 2 Error: {}", e),
+    fn part1(data: &String) -> impl Display {
+        solve_part1(data.as_bytes()).expect("Invalid compressed data")
     }
 
-    Ok(())
+    fn part2(data: &String) -> impl Display {
+        solve_part2(data.as_bytes()).expect("Invalid compressed data")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompressed_length() {
+        let length = |data: &str| solve_part1(data.as_bytes()).unwrap();
+        assert_eq!(length("ADVENT"), 6);
+        assert_eq!(length("A(1x5)BC"), 7);
+        assert_eq!(length("(3x3)XYZ"), 9);
+        assert_eq!(length("A(2x2)BCD(2x2)EFG"), 11);
+        assert_eq!(length("(6x1)(1x3)A"), 6);
+        assert_eq!(length("X(8x2)(3x3)ABCY"), 18);
+    }
+
+    #[test]
+    fn test_recursive_length() {
+        let length = |data: &str| solve_part2(data.as_bytes()).unwrap();
+        assert_eq!(length("(3x3)XYZ"), 9);
+        assert_eq!(length("X(8x2)(3x3)ABCY"), 20);
+        assert_eq!(length("(27x12)(20x12)(13x14)(7x10)(1x12)A"), 241920);
+        assert_eq!(
+            length("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN"),
+            445
+        );
+    }
 }
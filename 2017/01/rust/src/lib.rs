@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
 
 /*
 fn solve_pt1(captha: String) -> i32 {
@@ -49,13 +50,50 @@ fn solve(captha: &str, offset: usize) -> i32 {
     })
 }
 
-fn main() {
-    let input = fs::read_to_string("../input.txt").expect("Error reading input file");
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    let part1 = solve(&input, 1);
-    println!("The solution for the captha is: {part1}");
-    println!("{sep} Part 2 {sep}");
-    let part2 = solve(&input, input.trim().len() / 2);
-    println!("The solution for the captha is: {part2}");
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let captcha = parse::only(input, input.trim(), |c| c.is_ascii_digit(), "a digit")?;
+        Ok(captcha.to_string())
+    }
+
+    fn part1(captcha: &String) -> impl Display {
+        solve(captcha, 1)
+    }
+
+    fn part2(captcha: &String) -> impl Display {
+        solve(captcha, captcha.len() / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_digit() {
+        assert_eq!(solve("1122", 1), 3);
+        assert_eq!(solve("1111", 1), 4);
+        assert_eq!(solve("1234", 1), 0);
+        assert_eq!(solve("91212129", 1), 9);
+    }
+
+    #[test]
+    fn test_halfway_around() {
+        for (captcha, sum) in [
+            ("1212", 6),
+            ("1221", 0),
+            ("123425", 4),
+            ("123123", 12),
+            ("12131415", 4),
+        ] {
+            assert_eq!(solve(captcha, captcha.len() / 2), sum, "{captcha}");
+        }
+    }
 }
@@ -0,0 +1,76 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn parse_input(data: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::lines(data, |l| {
+        l.split_whitespace()
+            .map(|s| parse::number(l, s))
+            .collect::<Result<Vec<i32>, _>>()
+    })
+}
+
+fn calculate_check_sum(sheet: &[Vec<i32>]) -> i32 {
+    let mut checksum = 0;
+    for line in sheet {
+        let max = line.iter().max().unwrap();
+        let min = line.iter().min().unwrap();
+        checksum += max - min;
+    }
+    checksum
+}
+
+fn checksum_divide(sheet: &[Vec<i32>]) -> i32 {
+    let mut checksum = 0;
+    for row in sheet {
+        for (i, &a) in row.iter().enumerate() {
+            for &b in &row[i + 1..] {
+                if a % b == 0 {
+                    checksum += a / b;
+                    break;
+                } else if b % a == 0 {
+                    checksum += b / a;
+                    break;
+                }
+            }
+        }
+    }
+    checksum
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(sheet: &Vec<Vec<i32>>) -> impl Display {
+        calculate_check_sum(sheet)
+    }
+
+    fn part2(sheet: &Vec<Vec<i32>>) -> impl Display {
+        checksum_divide(sheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        let sheet = Day02::parse("5 1 9 5\n7 5 3\n2 4 6 8").unwrap();
+        assert_eq!(calculate_check_sum(&sheet), 18);
+    }
+
+    #[test]
+    fn test_evenly_divisible() {
+        let sheet = Day02::parse("5 9 2 8\n9 4 7 3\n3 8 6 5").unwrap();
+        assert_eq!(checksum_divide(&sheet), 9);
+    }
+}
@@ -1,4 +1,6 @@
-use std::{collections::HashMap, fs};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Point {
     x: i32,
@@ -99,17 +101,45 @@ fn part_2(n: i32) -> i32 {
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("../input.txt")
-        .expect("Error reading input file")
-        .trim()
-        .parse::<i32>()
-        .unwrap();
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    let part1 = part_1(input);
-    println!("The distance to the origin is: {part1}");
-    println!("{sep} Part 2 {sep}");
-    let part2 = part_2(input);
-    println!("The first value higher than the input is: {part2}");
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 3;
+
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, ParseError> {
+        parse::number(input, input.trim())
+    }
+
+    fn part1(square: &i32) -> impl Display {
+        part_1(*square)
+    }
+
+    fn part2(square: &i32) -> impl Display {
+        part_2(*square)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spiral_distance() {
+        assert_eq!(part_1(1), 0);
+        assert_eq!(part_1(12), 3);
+        assert_eq!(part_1(23), 2);
+        assert_eq!(part_1(1024), 31);
+    }
+
+    #[test]
+    fn test_first_larger_sum() {
+        // The stress test writes 1, 1, 2, 4, 5, 10, 11, 23, 25, 26, 54, ...
+        assert_eq!(part_2(1), 2);
+        assert_eq!(part_2(5), 10);
+        assert_eq!(part_2(25), 26);
+        assert_eq!(part_2(747), 806);
+    }
 }
@@ -1,8 +1,9 @@
-use std::{collections::HashSet, fs};
+use aoc_common::{ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
 
-fn parse_input(path: &str) -> Vec<Vec<String>> {
-    fs::read_to_string(path)
-        .expect("Error reading input file")
+fn parse_input(input: &str) -> Vec<Vec<String>> {
+    input
         .lines()
         .map(|l| {
             l.split_whitespace()
@@ -12,7 +13,7 @@ fn parse_input(path: &str) -> Vec<Vec<String>> {
         .collect()
 }
 
-fn is_unique(phrase: Vec<String>) -> bool {
+fn is_unique(phrase: &[String]) -> bool {
     let mut seen = HashSet::new();
     for word in phrase {
         if !seen.insert(word) {
@@ -44,7 +45,7 @@ fn is_anagram(phrase: Vec<String>) -> bool {
 }
 */
 
-fn is_anagram(phrase: Vec<String>) -> bool {
+fn is_anagram(phrase: &[String]) -> bool {
     let mut seen = HashSet::new();
     for word in phrase {
         let mut chars: Vec<char> = word.to_lowercase().chars().collect();
@@ -57,9 +58,8 @@ fn is_anagram(phrase: Vec<String>) -> bool {
     true
 }
 
-fn solve_part1(path: &str) -> i32 {
+fn solve_part1(list: &[Vec<String>]) -> i32 {
     let mut count = 0;
-    let list = parse_input(path);
     for phrase in list {
         if is_unique(phrase) {
             count += 1;
@@ -80,20 +80,57 @@ fn solve_part2(path: &str) -> i32 {
 }
 */
 
-fn solve_part2(path: &str) -> usize {
-    parse_input(path)
-        .into_iter()
-        .filter(|phrase| is_unique(phrase.clone()) && is_anagram(phrase.clone()))
+fn solve_part2(list: &[Vec<String>]) -> usize {
+    list.iter()
+        .filter(|phrase| is_unique(phrase) && is_anagram(phrase))
         .count()
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    println!("{sep} Part 1 {sep}");
-    let part1 = solve_part1(path);
-    println!("The total of avaliable passphrases is: {part1}");
-    println!("{sep} Part 2 {sep}");
-    let part2 = solve_part2(path);
-    println!("The total of avaliable passphrases is: {part2}");
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(passphrases: &Vec<Vec<String>>) -> impl Display {
+        solve_part1(passphrases)
+    }
+
+    fn part2(passphrases: &Vec<Vec<String>>) -> impl Display {
+        solve_part2(passphrases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_duplicate_words() {
+        let list = Day04::parse("aa bb cc dd ee\naa bb cc dd aa\naa bb cc dd aaa").unwrap();
+        let valid: Vec<bool> = list.iter().map(|p| is_unique(p)).collect();
+        assert_eq!(valid, [true, false, true]);
+        assert_eq!(solve_part1(&list), 2);
+    }
+
+    #[test]
+    fn test_no_anagrams() {
+        let list = Day04::parse(
+            "abcde fghij
+abcde xyz ecdab
+a ab abc abd abf abj
+iiii oiii ooii oooi oooo
+oiii ioii iioi iiio",
+        )
+        .unwrap();
+        let valid: Vec<bool> = list.iter().map(|p| is_anagram(p)).collect();
+        assert_eq!(valid, [true, false, true, true, false]);
+        assert_eq!(solve_part2(&list), 3);
+    }
 }
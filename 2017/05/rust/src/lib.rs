@@ -0,0 +1,82 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse::lines(input, |l| parse::number(l, l.trim()))
+}
+
+fn solve_part1(jumps: &[i32]) -> usize {
+    let mut count: usize = 1;
+    let mut instructions = jumps.to_vec();
+    let len = instructions.len();
+    let mut i: i32 = 0;
+    loop {
+        let offset = instructions[i as usize];
+        if (i + offset) >= len as i32 {
+            return count;
+        } else {
+            count += 1;
+            instructions[i as usize] += 1;
+            i += offset;
+        }
+    }
+}
+
+fn solve_part2(jumps: &[i32]) -> usize {
+    let mut count: usize = 1;
+    let mut instructions = jumps.to_vec();
+    let len = instructions.len();
+    let mut i: i32 = 0;
+    loop {
+        let offset = instructions[i as usize];
+        if (i + offset) >= len as i32 {
+            return count;
+        } else {
+            count += 1;
+            if offset < 3 {
+                instructions[i as usize] += 1;
+            } else {
+                instructions[i as usize] -= 1;
+            }
+            i += offset;
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 5;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(jumps: &Vec<i32>) -> impl Display {
+        solve_part1(jumps)
+    }
+
+    fn part2(jumps: &Vec<i32>) -> impl Display {
+        solve_part2(jumps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0\n3\n0\n1\n-3";
+
+    #[test]
+    fn test_jumps() {
+        assert_eq!(solve_part1(&Day05::parse(EXAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_stranger_jumps() {
+        assert_eq!(solve_part2(&Day05::parse(EXAMPLE).unwrap()), 10);
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|n| parse::number(input, n))
+        .collect()
+}
+
+fn redistribute(banks: &mut [usize]) {
+    if banks.is_empty() {
+        return;
+    }
+
+    let (mut idx, &blocks) = banks
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(&a.0)))
+        .unwrap();
+
+    banks[idx] = 0;
+    let len = banks.len();
+
+    for _ in 0..blocks {
+        idx = (idx + 1) % len;
+        banks[idx] += 1;
+    }
+}
+
+/// Returns (cycles_until_repeat, size_of_loop).
+fn solve(initial: &[usize]) -> (usize, usize) {
+    let mut banks = initial.to_vec();
+    let mut step = 0;
+    let mut seen: HashMap<Vec<usize>, usize> = HashMap::new();
+
+    while let std::collections::hash_map::Entry::Vacant(entry) = seen.entry(banks.clone()) {
+        entry.insert(step);
+        redistribute(&mut banks);
+        step += 1;
+    }
+
+    let first_seen_at = seen[&banks];
+    (step, step - first_seen_at)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 6;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(banks: &Vec<usize>) -> impl Display {
+        solve(banks).0
+    }
+
+    fn part2(banks: &Vec<usize>) -> impl Display {
+        solve(banks).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redistribute() {
+        let mut banks = vec![0, 2, 7, 0];
+        let mut states = Vec::new();
+        for _ in 0..5 {
+            redistribute(&mut banks);
+            states.push(banks.clone());
+        }
+        assert_eq!(
+            states,
+            [
+                [2, 4, 1, 2],
+                [3, 1, 2, 3],
+                [0, 2, 3, 4],
+                [1, 3, 4, 1],
+                [2, 4, 1, 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cycles() {
+        assert_eq!(solve(&Day06::parse("0\t2\t7\t0").unwrap()), (5, 4));
+    }
+}
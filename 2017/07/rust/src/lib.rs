@@ -0,0 +1,164 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Node {
+    name: String,
+    weight: usize,
+    leafs: Option<Vec<String>>,
+}
+
+fn parse_node(line: &str) -> Result<Node, ParseError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let name = parse::word(line, 0, "a program name")?.to_string();
+    let weight = parse::word(line, 1, "a weight `(N)`")?;
+    let weight: usize = parse::number(line, weight.trim_matches(|c| c == '(' || c == ')'))?;
+
+    let leafs = if parts.len() > 2 {
+        if parts[2] != "->" {
+            return Err(ParseError::at(line, parts[2], "`->`"));
+        }
+        Some(
+            parts[3..]
+                .iter()
+                .map(|s| s.trim_end_matches(',').to_string())
+                .collect(),
+        )
+    } else {
+        None
+    };
+
+    Ok(Node {
+        name,
+        weight,
+        leafs,
+    })
+}
+
+fn parse_input(data: &str) -> Result<Vec<Node>, ParseError> {
+    parse::lines(data, parse_node)
+}
+
+fn build_map(nodes: Vec<Node>) -> HashMap<String, Node> {
+    let mut map = HashMap::new();
+    for node in nodes {
+        map.insert(node.name.clone(), node);
+    }
+    map
+}
+
+fn bottom_node(map: &HashMap<String, Node>) -> &str {
+    let leaf_set: HashSet<&String> = map
+        .values()
+        .filter_map(|n| n.leafs.as_ref())
+        .flatten()
+        .collect();
+    map.keys()
+        .find(|name| !leaf_set.contains(name))
+        .expect("Tower has no bottom node")
+}
+
+/// Returns the total weight of the tower rooted at `name`, recording in
+/// `correction` the weight the unbalanced program should have.
+fn total_weight(name: &str, map: &HashMap<String, Node>, correction: &mut Option<isize>) -> usize {
+    let node = map.get(name).expect("Node not found");
+    let mut total: usize = node.weight;
+
+    if let Some(leafs) = node.leafs.as_ref() {
+        let mut child_weights = Vec::new();
+        for child in leafs {
+            let w = total_weight(child, map, correction);
+            child_weights.push((child.clone(), w));
+            total += w;
+        }
+        let mut weight_counts = HashMap::new();
+        for (_, w) in &child_weights {
+            *weight_counts.entry(w).or_insert(0) += 1;
+        }
+        if weight_counts.len() > 1 && correction.is_none() {
+            let bad_w = weight_counts
+                .iter()
+                .filter(|&(_, &count)| count == 1)
+                .map(|(&weight, _)| weight)
+                .next()
+                .unwrap();
+            let good_w = weight_counts
+                .iter()
+                .filter(|&(_, &count)| count > 1)
+                .map(|(&weight, _)| weight)
+                .next()
+                .unwrap();
+            let bad_child_name = child_weights
+                .iter()
+                .find(|(_, w)| *w == *bad_w)
+                .map(|(name, _)| name)
+                .unwrap();
+
+            let bad_node = map.get(bad_child_name).unwrap();
+
+            let corrected = bad_node.weight as isize + (*good_w as isize - *bad_w as isize);
+            *correction = Some(corrected);
+        }
+    }
+    total
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, Node>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Node>, ParseError> {
+        parse_input(input).map(build_map)
+    }
+
+    fn part1(map: &HashMap<String, Node>) -> impl Display {
+        bottom_node(map).to_string()
+    }
+
+    fn part2(map: &HashMap<String, Node>) -> impl Display {
+        let mut correction = None;
+        total_weight(bottom_node(map), map, &mut correction);
+        correction.expect("Tower is already balanced")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+    #[test]
+    fn test_bottom_program() {
+        assert_eq!(bottom_node(&Day07::parse(EXAMPLE).unwrap()), "tknk");
+    }
+
+    #[test]
+    fn test_balance() {
+        let map = Day07::parse(EXAMPLE).unwrap();
+        let mut correction = None;
+        assert_eq!(total_weight("padx", &map, &mut correction), 243);
+        assert_eq!(correction, None);
+        assert_eq!(
+            total_weight("tknk", &map, &mut correction),
+            41 + 251 + 243 + 243
+        );
+        assert_eq!(correction, Some(60));
+    }
+}
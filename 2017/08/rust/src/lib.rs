@@ -1,32 +1,34 @@
-use std::{collections::HashMap, fs};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
 
 // Function to interpret instructions and find register values
-fn interpret(path: &str) -> (i32, i32) {
+fn interpret(data: &str) -> Result<(i32, i32), ParseError> {
     // Store register values (name -> value)
     let mut registers: HashMap<&str, i32> = HashMap::new();
     // Track the highest value ever held by any register during execution
     let mut max_value_ever = i32::MIN; // Start with the smallest possible i32 value
 
-    // Read the input file
-    let data = fs::read_to_string(path).expect("Error reading input file.");
-
     // Process each line (instruction)
-    for line in data.lines() {
+    for (i, line) in data.lines().enumerate() {
+        let error = |text, expected| ParseError::at(line, text, expected).below(i);
         // Split the line into parts
         let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() != 7 {
-            eprintln!("Skipping malformed line: {}", line); // Handle potential bad input lines
-            continue;
+        if parts.len() != 7 || parts[3] != "if" {
+            return Err(error(line, "`reg inc|dec N if reg OP N`"));
         }
 
         // Extract instruction components
         let target_reg = parts[0]; // Register to modify
         let op = parts[1]; // Operation ("inc" or "dec")
-        let op_val: i32 = parts[2].parse().expect("failed to parse operation value");
+        let op_val: i32 = parse::number(line, parts[2]).map_err(|e| e.below(i))?;
         // parts[3] is "if" - we skip it
         let cond_reg = parts[4]; // Register to check in condition
         let comparator = parts[5]; // Comparison operator (>, <, ==, etc.)
-        let cond_val: i32 = parts[6].parse().expect("failed to parse condition value");
+        let cond_val: i32 = parse::number(line, parts[6]).map_err(|e| e.below(i))?;
+        if !matches!(op, "inc" | "dec") {
+            return Err(error(op, "`inc` or `dec`"));
+        }
 
         // Ensure both registers exist in our map, defaulting to 0 if new
         let cond_reg_val = *registers.entry(cond_reg).or_insert(0);
@@ -40,7 +42,12 @@ fn interpret(path: &str) -> (i32, i32) {
             "==" => cond_reg_val == cond_val,
             ">=" => cond_reg_val >= cond_val,
             "<=" => cond_reg_val <= cond_val,
-            _ => panic!("Invalid comparator: {comparator} on line: {line}"),
+            _ => {
+                return Err(error(
+                    comparator,
+                    "a comparison `>`, `<`, `!=`, `==`, `>=` or `<=`",
+                ));
+            }
         };
 
         // If the condition is met, perform the operation
@@ -51,8 +58,7 @@ fn interpret(path: &str) -> (i32, i32) {
             // Perform "inc" or "dec"
             match op {
                 "inc" => *target_val += op_val,
-                "dec" => *target_val -= op_val,
-                _ => panic!("Invalid operation: {op} on line: {line}"),
+                _ => *target_val -= op_val,
             }
 
             // Update the highest value ever seen (for Part 2)
@@ -67,22 +73,41 @@ fn interpret(path: &str) -> (i32, i32) {
     let final_max_value = *registers.values().max().unwrap_or(&0);
 
     // Return both the final max value and the highest value ever held
-    (final_max_value, max_value_ever)
+    Ok((final_max_value, max_value_ever))
 }
 
-fn main() {
-    let path = "../input.txt"; // Adjust path if needed
-    let sep = "=".repeat(20);
-
-    // Run the interpretation
-    let (part1_result, part2_result) = interpret(path);
-
-    // Print results
-    println!("{sep} Part 1 {sep}");
-    println!("Largest value in any register at the end: {}", part1_result);
-    println!("{sep} Part 2 {sep}");
-    println!(
-        "Highest value held in any register during execution: {}",
-        part2_result
-    );
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 8;
+
+    type Input = (i32, i32);
+
+    fn parse(input: &str) -> Result<(i32, i32), ParseError> {
+        // Run the interpretation
+        interpret(input)
+    }
+
+    fn part1(input: &(i32, i32)) -> impl Display {
+        input.0
+    }
+
+    fn part2(input: &(i32, i32)) -> impl Display {
+        input.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registers() {
+        let program = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+        assert_eq!(interpret(program).unwrap(), (1, 10));
+    }
 }
@@ -0,0 +1,100 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+fn solve(input: &str) -> (usize, usize) {
+    let mut chars = input.chars();
+
+    let mut inside_garbage = false;
+    let mut depth = 0;
+    let mut score = 0;
+    let mut garbage_count = 0;
+
+    while let Some(c) = chars.next() {
+        if inside_garbage {
+            match c {
+                '!' => {
+                    chars.next();
+                }
+                '>' => {
+                    inside_garbage = false;
+                }
+                _ => {
+                    garbage_count += 1;
+                }
+            }
+        } else {
+            match c {
+                '{' => {
+                    depth += 1;
+                }
+                '}' => {
+                    score += depth;
+                    depth -= 1;
+                }
+                '<' => {
+                    inside_garbage = true;
+                }
+                ',' => {}
+                _ => {}
+            }
+        }
+    }
+    (score, garbage_count)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 9;
+
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<(usize, usize), ParseError> {
+        Ok(solve(input))
+    }
+
+    fn part1(input: &(usize, usize)) -> impl Display {
+        input.0
+    }
+
+    fn part2(input: &(usize, usize)) -> impl Display {
+        input.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        for (stream, score) in [
+            ("{}", 1),
+            ("{{{}}}", 6),
+            ("{{},{}}", 5),
+            ("{{{},{},{{}}}}", 16),
+            ("{<a>,<a>,<a>,<a>}", 1),
+            ("{{<ab>},{<ab>},{<ab>},{<ab>}}", 9),
+            ("{{<!!>},{<!!>},{<!!>},{<!!>}}", 9),
+            ("{{<a!>},{<a!>},{<a!>},{<ab>}}", 3),
+        ] {
+            assert_eq!(solve(stream).0, score, "{stream}");
+        }
+    }
+
+    #[test]
+    fn test_garbage() {
+        for (stream, count) in [
+            ("<>", 0),
+            ("<random characters>", 17),
+            ("<<<<>", 3),
+            ("<{!>}>", 2),
+            ("<!!>", 0),
+            ("<!!!>>", 0),
+            ("<{o\"i!a,<{i<a>", 10),
+        ] {
+            assert_eq!(solve(stream).1, count, "{stream}");
+        }
+    }
+}
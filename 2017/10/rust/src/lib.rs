@@ -0,0 +1,67 @@
+use aoc_common::{ParseError, Solution, parse};
+use aoc_hash::knot::{self, KnotHasher};
+use std::fmt::Display;
+
+// The input read both ways: part 1 takes the lengths as numbers, part 2 as
+// bytes.
+pub struct Lengths {
+    numbers: Vec<usize>,
+    bytes: Vec<u8>,
+}
+
+fn knot_round(lengths: &[usize], size: usize) -> usize {
+    let mut hasher = KnotHasher::new().size(size).rounds(1).salt(&[]);
+    hasher.update_lengths(lengths);
+    let hash = hasher.finalize();
+    usize::from(hash.sparse()[0]) * usize::from(hash.sparse()[1])
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 10;
+
+    type Input = Lengths;
+
+    fn parse(input: &str) -> Result<Lengths, ParseError> {
+        let text = input.trim();
+        let numbers = text
+            .split(',')
+            .map(|n| parse::number(input, n.trim()))
+            .collect::<Result<_, _>>()?;
+        Ok(Lengths {
+            numbers,
+            bytes: text.as_bytes().to_vec(),
+        })
+    }
+
+    fn part1(lengths: &Lengths) -> impl Display {
+        knot_round(&lengths.numbers, 256)
+    }
+
+    fn part2(lengths: &Lengths) -> impl Display {
+        knot::hash(&lengths.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_round() {
+        // The example list is 0..5 and ends up as 3 4 2 1 0.
+        let lengths = Day10::parse("3, 4, 1, 5").unwrap();
+        assert_eq!(knot_round(&lengths.numbers, 5), 12);
+    }
+
+    #[test]
+    fn test_knot_hash() {
+        let lengths = Day10::parse("1,2,3\n").unwrap();
+        assert_eq!(
+            Day10::part2(&lengths).to_string(),
+            "3efbe78a8d82f29979031a4aa0b16a9d"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+#[derive(Debug, Copy, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    fn distance_to(&self, other: &Point) -> i32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = -dx - dy;
+        dx.abs().max(dy.abs()).max(dz.abs())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    N,
+    NE,
+    NW,
+    S,
+    SE,
+    SW,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|s| match s.trim() {
+            "n" => Ok(Direction::N),
+            "ne" => Ok(Direction::NE),
+            "nw" => Ok(Direction::NW),
+            "s" => Ok(Direction::S),
+            "se" => Ok(Direction::SE),
+            "sw" => Ok(Direction::SW),
+            dir => Err(ParseError::at(
+                input,
+                dir,
+                "a direction n, ne, nw, s, se or sw",
+            )),
+        })
+        .collect()
+}
+
+fn solve(map: &[Direction]) -> (i32, i32) {
+    let mut current = Point::new(0, 0);
+    let origin = Point::new(0, 0);
+    let mut max_distance = 0;
+
+    for dir in map {
+        match dir {
+            Direction::N => current.y -= 1,
+            Direction::NE => {
+                current.x += 1;
+                current.y -= 1
+            }
+            Direction::NW => current.x -= 1,
+            Direction::S => current.y += 1,
+            Direction::SE => current.x += 1,
+            Direction::SW => {
+                current.x -= 1;
+                current.y += 1
+            }
+        }
+
+        let dist = origin.distance_to(&current);
+        max_distance = max_distance.max(dist);
+    }
+
+    (origin.distance_to(&current), max_distance)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 11;
+
+    type Input = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(path: &Vec<Direction>) -> impl Display {
+        solve(path).0
+    }
+
+    fn part2(path: &Vec<Direction>) -> impl Display {
+        solve(path).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_distance() {
+        for (path, steps) in [
+            ("ne,ne,ne", 3),
+            ("ne,ne,sw,sw", 0),
+            ("ne,ne,s,s", 2),
+            ("se,sw,se,sw,sw", 3),
+        ] {
+            assert_eq!(solve(&Day11::parse(path).unwrap()).0, steps, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_furthest() {
+        assert_eq!(solve(&Day11::parse("ne,ne,sw,sw").unwrap()), (0, 2));
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+fn parse_input(data: &str) -> Result<HashMap<usize, Vec<usize>>, ParseError> {
+    let pipes = parse::lines(data, |line| {
+        let (id, connected) = line
+            .split_once(" <-> ")
+            .ok_or_else(|| ParseError::at(line, line, "pipes `A <-> B, C`"))?;
+        let connected = connected
+            .split(", ")
+            .map(|id| parse::number(line, id.trim()))
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok((parse::number(line, id.trim())?, connected))
+    })?;
+    Ok(pipes.into_iter().collect())
+}
+
+fn reachable_form(start: usize, graph: &HashMap<usize, Vec<usize>>) -> HashSet<usize> {
+    let mut visited = HashSet::new();
+    dfs(start, graph, &mut visited);
+    visited
+}
+
+fn dfs(node: usize, graph: &HashMap<usize, Vec<usize>>, visited: &mut HashSet<usize>) {
+    if visited.contains(&node) {
+        return;
+    }
+    visited.insert(node);
+
+    if let Some(neighbors) = graph.get(&node) {
+        for &neighbor in neighbors {
+            dfs(neighbor, graph, visited);
+        }
+    }
+}
+
+fn find_groups(graph: &HashMap<usize, Vec<usize>>) -> usize {
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut group_count: usize = 0;
+    for node in graph.keys() {
+        if !visited.contains(node) {
+            let group = reachable_form(*node, graph);
+            visited.extend(&group);
+            group_count += 1;
+        }
+    }
+    group_count
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 12;
+
+    type Input = HashMap<usize, Vec<usize>>;
+
+    fn parse(input: &str) -> Result<HashMap<usize, Vec<usize>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(pipes: &HashMap<usize, Vec<usize>>) -> impl Display {
+        reachable_form(0, pipes).len()
+    }
+
+    fn part2(pipes: &HashMap<usize, Vec<usize>>) -> impl Display {
+        find_groups(pipes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+    #[test]
+    fn test_group_of_zero() {
+        let group = reachable_form(0, &Day12::parse(EXAMPLE).unwrap());
+        assert_eq!(group, HashSet::from([0, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_groups() {
+        assert_eq!(find_groups(&Day12::parse(EXAMPLE).unwrap()), 2);
+    }
+}
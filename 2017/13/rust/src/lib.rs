@@ -0,0 +1,106 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Firewall {
+    depth: usize,
+    scan_pos: usize,
+}
+
+fn parse_input(input: &str) -> Result<HashMap<usize, Firewall>, ParseError> {
+    let mut layers: HashMap<usize, Firewall> = HashMap::new();
+    let scanners = parse::lines(input, |line| {
+        let (layer, depth) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(line, line, "a layer `depth: range`"))?;
+        Ok((
+            parse::number(line, layer.trim())?,
+            parse::number(line, depth)?,
+        ))
+    })?;
+    for (layer, depth) in scanners {
+        let fw = Firewall { depth, scan_pos: 0 };
+        layers.insert(layer, fw);
+    }
+    // add empty layers
+    let max_layers = layers.keys().max().copied().unwrap_or(0);
+    for l in 0..(max_layers + 1) {
+        layers.entry(l).or_insert(Firewall {
+            depth: 0,
+            scan_pos: 0,
+        });
+    }
+    Ok(layers)
+}
+
+fn compute_severity(layers: &HashMap<usize, Firewall>) -> usize {
+    layers
+        .iter()
+        .filter(|(_layer, fw)| fw.depth > 0)
+        .filter(|(layer, fw)| *layer % (2 * (fw.depth - 1)) == 0)
+        .map(|(layer, fw)| layer * fw.depth)
+        .sum()
+}
+
+fn is_safe(delay: usize, layers: &HashMap<usize, Firewall>) -> bool {
+    for (layer, fw) in layers {
+        if fw.depth == 0 {
+            continue;
+        }
+
+        let period = 2 * (fw.depth - 1);
+        if (layer + delay).is_multiple_of(period) {
+            return false;
+        }
+    }
+    true
+}
+
+fn solve_pt2(layers: &HashMap<usize, Firewall>) -> usize {
+    let mut delay: usize = 0;
+    while !is_safe(delay, layers) {
+        delay += 1;
+    }
+    delay
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 13;
+
+    type Input = HashMap<usize, Firewall>;
+
+    fn parse(input: &str) -> Result<HashMap<usize, Firewall>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(layers: &HashMap<usize, Firewall>) -> impl Display {
+        compute_severity(layers)
+    }
+
+    fn part2(layers: &HashMap<usize, Firewall>) -> impl Display {
+        solve_pt2(layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0: 3\n1: 2\n4: 4\n6: 4";
+
+    #[test]
+    fn test_severity() {
+        assert_eq!(compute_severity(&Day13::parse(EXAMPLE).unwrap()), 24);
+    }
+
+    #[test]
+    fn test_delay() {
+        let layers = Day13::parse(EXAMPLE).unwrap();
+        assert!(!is_safe(0, &layers));
+        assert_eq!(solve_pt2(&layers), 10);
+    }
+}
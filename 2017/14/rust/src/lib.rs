@@ -0,0 +1,90 @@
+use aoc_common::{ParseError, Solution};
+use aoc_hash::knot;
+use std::fmt::Display;
+
+// The squares of a row of the grid, 1 where used, from the bits of the knot
+// hash of the key and the row number.
+fn row(key: &str, r: usize) -> Vec<u8> {
+    let hash = knot::hash(format!("{key}-{r}").as_bytes());
+    hash.dense()
+        .into_iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1))
+        .collect()
+}
+
+fn dfs(grid: &mut Vec<Vec<u8>>, r: usize, c: usize) {
+    if r >= grid.len() || c >= grid[0].len() || grid[r][c] != 1 {
+        return;
+    }
+
+    grid[r][c] = 2; // Mark as visited 
+
+    if r > 0 {
+        dfs(grid, r - 1, c);
+    } // Up
+    dfs(grid, r + 1, c); // Down
+    if c > 0 {
+        dfs(grid, r, c - 1);
+    } // Left
+    dfs(grid, r, c + 1); // Right 
+}
+
+fn solve(input_key: &str) -> (usize, usize) {
+    let mut grid: Vec<Vec<u8>> = Vec::with_capacity(128);
+    let mut used_count = 0;
+
+    for r in 0..128 {
+        let grid_row = row(input_key, r);
+        used_count += grid_row.iter().filter(|&&square| square == 1).count();
+        grid.push(grid_row);
+    }
+
+    let mut region_count = 0;
+    for r in 0..128 {
+        for c in 0..128 {
+            if grid[r][c] == 1 {
+                region_count += 1;
+                dfs(&mut grid, r, c); // Explore and mark the entire region
+            }
+        }
+    }
+
+    (used_count, region_count)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 14;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(key: &String) -> impl Display {
+        solve(key).0
+    }
+
+    fn part2(key: &String) -> impl Display {
+        solve(key).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_row() {
+        // The top-left corner of the example grid starts with ##.#.#..
+        assert_eq!(row("flqrgnkx", 0)[..8], [1, 1, 0, 1, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_used_squares_and_regions() {
+        assert_eq!(solve("flqrgnkx"), (8108, 1242));
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+const FACTOR_A: u64 = 16807;
+const FACTOR_B: u64 = 48271;
+const DIVISOR: u64 = 2147483647;
+const PAIRS_P1: usize = 40_000_000;
+const PAIRS_P2: usize = 5_000_000;
+const MULTIPLE_A: u64 = 4;
+const MULTIPLE_B: u64 = 8;
+
+fn parse_starts(input: &str) -> Result<(u64, u64), ParseError> {
+    let starts: Vec<u64> = parse::lines(input.trim(), |line| {
+        parse::number(line, parse::word(line, 4, "a starting value")?)
+    })?;
+    match starts[..] {
+        [a, b] => Ok((a, b)),
+        _ => Err(ParseError::at(
+            input,
+            input.trim_end(),
+            "two generators, A and B",
+        )),
+    }
+}
+
+struct Generator {
+    value: u64,
+    factor: u64,
+    multiple_check: Option<u64>,
+}
+
+impl Generator {
+    fn new(start_value: u64, factor: u64, multiple_check: Option<u64>) -> Self {
+        Generator {
+            value: start_value,
+            factor,
+            multiple_check,
+        }
+    }
+
+    fn next_value(&mut self) -> u64 {
+        loop {
+            self.value = (self.value * self.factor) % DIVISOR;
+            if let Some(multiple) = self.multiple_check {
+                if self.value.is_multiple_of(multiple) {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+        self.value
+    }
+}
+
+fn solve_part1(start_a: u64, start_b: u64) -> usize {
+    let mut gen_a = Generator::new(start_a, FACTOR_A, None);
+    let mut gen_b = Generator::new(start_b, FACTOR_B, None);
+    let mut matches = 0;
+
+    for _ in 0..PAIRS_P1 {
+        let val_a = gen_a.next_value();
+        let val_b = gen_b.next_value();
+
+        if (val_a & 0xFFFF) == (val_b & 0xFFFF) {
+            matches += 1;
+        }
+    }
+    matches
+}
+
+fn solve_part2(start_a: u64, start_b: u64) -> usize {
+    let mut gen_a = Generator::new(start_a, FACTOR_A, Some(MULTIPLE_A));
+    let mut gen_b = Generator::new(start_b, FACTOR_B, Some(MULTIPLE_B));
+    let mut matches = 0;
+
+    for _ in 0..PAIRS_P2 {
+        let val_a = gen_a.next_value();
+        let val_b = gen_b.next_value();
+
+        if (val_a & 0xFFFF) == (val_b & 0xFFFF) {
+            matches += 1;
+        }
+    }
+    matches
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 15;
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        parse_starts(input)
+    }
+
+    fn part1(starts: &(u64, u64)) -> impl Display {
+        solve_part1(starts.0, starts.1)
+    }
+
+    fn part2(starts: &(u64, u64)) -> impl Display {
+        solve_part2(starts.0, starts.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Generator A starts with 65\nGenerator B starts with 8921";
+
+    #[test]
+    fn test_generators() {
+        let mut gen_a = Generator::new(65, FACTOR_A, None);
+        let mut gen_b = Generator::new(8921, FACTOR_B, None);
+        let a: Vec<u64> = (0..5).map(|_| gen_a.next_value()).collect();
+        let b: Vec<u64> = (0..5).map(|_| gen_b.next_value()).collect();
+        assert_eq!(a, [1092455, 1181022009, 245556042, 1744312007, 1352636452]);
+        assert_eq!(b, [430625591, 1233683848, 1431495498, 137874439, 285222916]);
+    }
+
+    #[test]
+    fn test_judge() {
+        let (a, b) = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(solve_part1(a, b), 588);
+    }
+
+    #[test]
+    fn test_picky_judge() {
+        let (a, b) = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(solve_part2(a, b), 309);
+    }
+}
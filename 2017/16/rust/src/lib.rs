@@ -0,0 +1,112 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+pub enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(char, char),
+}
+
+fn parse_move(input: &str, mv: &str) -> Result<Move, ParseError> {
+    let pair = |expected| {
+        mv[1..]
+            .split_once('/')
+            .ok_or_else(|| ParseError::at(input, mv, expected))
+    };
+    let program = |name: &str| {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_lowercase() => Ok(c),
+            _ => Err(ParseError::at(input, name, "a program `a` to `p`")),
+        }
+    };
+    match mv.chars().next() {
+        Some('s') => Ok(Move::Spin(parse::number(input, &mv[1..])?)),
+        Some('x') => {
+            let (a, b) = pair("an exchange `xA/B`")?;
+            Ok(Move::Exchange(
+                parse::number(input, a.trim())?,
+                parse::number(input, b.trim())?,
+            ))
+        }
+        Some('p') => {
+            let (a, b) = pair("a partner swap `pA/B`")?;
+            Ok(Move::Partner(program(a)?, program(b)?))
+        }
+        _ => Err(ParseError::at(
+            input,
+            mv,
+            "a spin, exchange or partner move",
+        )),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    input
+        .trim()
+        .split(",")
+        .map(|mv| parse_move(input, mv))
+        .collect()
+}
+
+fn simulate_dance(moves: &[Move], size: u8, loops: u64) -> (String, String) {
+    let mut programs: Vec<char> = (b'a'..b'a' + size).map(char::from).collect();
+    let mut seen: Vec<String> = Vec::new();
+    loop {
+        for mv in moves {
+            match *mv {
+                Move::Spin(n) => programs.rotate_right(n),
+                Move::Exchange(a, b) => programs.swap(a, b),
+                Move::Partner(a, b) => {
+                    let p_a = programs.iter().position(|&c| c == a).unwrap();
+                    let p_b = programs.iter().position(|&c| c == b).unwrap();
+                    programs.swap(p_a, p_b);
+                }
+            }
+        }
+        let out = programs.iter().collect::<String>();
+        if seen.contains(&out) {
+            break;
+        }
+        seen.push(out);
+    }
+    let seen_len = seen.len();
+    let idx: usize = (loops as usize - 1) % seen_len;
+    let out_2 = seen[idx].clone();
+    (seen[0].clone(), out_2)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 16;
+
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(moves: &Vec<Move>) -> impl Display {
+        simulate_dance(moves, 16, 1_000_000_000).0
+    }
+
+    fn part2(moves: &Vec<Move>) -> impl Display {
+        simulate_dance(moves, 16, 1_000_000_000).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dance() {
+        let moves = Day16::parse("s1,x3/4,pe/b").unwrap();
+        assert_eq!(
+            simulate_dance(&moves, 5, 2),
+            ("baedc".to_string(), "ceadb".to_string())
+        );
+    }
+}
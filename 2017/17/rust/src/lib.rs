@@ -0,0 +1,68 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+fn spin_lock(steps: usize, cycles: usize, after_val: usize) -> usize {
+    let mut buffer = vec![0];
+    let mut curr_idx = 0;
+
+    for val in 1..=cycles {
+        curr_idx = (curr_idx + steps) % buffer.len() + 1;
+        buffer.insert(curr_idx, val);
+    }
+
+    let pos = buffer.iter().position(|&x| x == after_val).unwrap();
+    buffer[(pos + 1) % buffer.len()]
+}
+
+fn spin_lock_2(steps: usize, cycles: usize) -> usize {
+    let mut after_0 = 0;
+    let mut curr_idx = 0;
+
+    for val in 1..=cycles {
+        curr_idx = (curr_idx + steps) % val + 1;
+        if curr_idx == 1 {
+            after_0 = val;
+        }
+    }
+
+    after_0
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 17;
+
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, ParseError> {
+        parse::number(input, input.trim())
+    }
+
+    fn part1(steps: &usize) -> impl Display {
+        spin_lock(*steps, 2017, 2017)
+    }
+
+    fn part2(steps: &usize) -> impl Display {
+        spin_lock_2(*steps, 50_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spin_lock() {
+        // After nine insertions the buffer is 0 (9) 5 7 2 4 3 8 6 1.
+        assert_eq!(spin_lock(3, 9, 3), 8);
+        assert_eq!(spin_lock(3, 2017, 2017), 638);
+    }
+
+    #[test]
+    fn test_after_zero() {
+        assert_eq!(spin_lock_2(3, 9), 9);
+        assert_eq!(spin_lock_2(3, 2017), spin_lock(3, 2017, 0));
+    }
+}
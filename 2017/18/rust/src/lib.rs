@@ -0,0 +1,118 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::duet::{self, Instruction};
+use aoc_vm::network::{Network, Report, Topology};
+use aoc_vm::{Machine, Word};
+use std::fmt::Display;
+
+// Read the first way, `snd` plays a sound and `rcv` recovers the last one
+// played unless its register is zero. The answer is the first sound
+// recovered.
+fn solve(instructions: &[Instruction]) -> Word {
+    let mut machine = Machine::new(instructions.to_vec());
+    while let Some(instruction) = machine.current() {
+        match instruction {
+            Instruction::Rcv(x) if machine.registers[x] != 0 => {
+                return machine.output.back().copied().unwrap_or(0);
+            }
+            Instruction::Rcv(_) => machine.pc += 1,
+            _ => {
+                machine.step();
+            }
+        }
+    }
+    0
+}
+
+/// Runs `count` copies of the program, each with its id in register `p`,
+/// connected by `topology`, until none can go on or they have carried out
+/// `max_steps` instructions between them.
+pub fn simulate(
+    instructions: &[Instruction],
+    count: usize,
+    topology: Topology,
+    max_steps: u64,
+) -> Result<Report<Instruction>, String> {
+    let programs = (0..count)
+        .map(|id| {
+            let mut program = Machine::new(instructions.to_vec());
+            program.set_register('p', id as Word);
+            program
+        })
+        .collect();
+    Ok(Network::new(programs, topology)?.run(max_steps))
+}
+
+// Runs programs 0 and 1 against each other until neither can go on.
+fn simulate_duet(instructions: &[Instruction]) -> Report<Instruction> {
+    simulate(instructions, 2, Topology::Pairwise, aoc_vm::max_steps())
+        .expect("two programs make a pair")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 18;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        duet::parse(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> impl Display {
+        solve(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> impl Display {
+        let report = simulate_duet(instructions);
+        if report.out_of_steps {
+            panic!("{report}");
+        }
+        report.sent[1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovered_frequency() {
+        let program = Day18::parse(
+            "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2",
+        )
+        .unwrap();
+        assert_eq!(solve(&program), 4);
+    }
+
+    #[test]
+    fn test_duet() {
+        let program = Day18::parse("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d").unwrap();
+        let report = simulate_duet(&program);
+        assert_eq!(report.sent, [3, 3]);
+        assert_eq!(report.received, [3, 3]);
+        let blocked: Vec<(usize, Word)> =
+            report.blocked.iter().map(|b| (b.machine, b.pc)).collect();
+        assert_eq!(blocked, [(0, 6), (1, 6)]);
+
+        let report = simulate(&program, 3, Topology::Ring, 100).unwrap();
+        assert_eq!(report.sent, [3, 3, 3]);
+        assert!(!report.out_of_steps);
+        assert!(simulate(&program, 3, Topology::Pairwise, 100).is_err());
+        assert!(
+            simulate(&program, 2, Topology::Pairwise, 5)
+                .unwrap()
+                .out_of_steps
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_common::grid::{ORTHOGONAL, Pos};
+use aoc_common::{Grid, ParseError, Solution};
+use std::fmt::Display;
+
+fn read_diagram(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_padded(
+        input,
+        ' ',
+        |c| (" |-+".contains(c) || c.is_ascii_uppercase()).then_some(c),
+        "a path piece or a letter",
+    )
+}
+
+fn find_start(diagram: &Grid<char>) -> Pos {
+    let col = diagram
+        .row(0)
+        .iter()
+        .position(|&c| c == '|')
+        .expect("Start not found");
+    (col, 0)
+}
+
+fn traverse_diagram(diagram: &Grid<char>) -> (String, usize) {
+    let mut pos = Some(find_start(diagram));
+    let mut dir = (0, 1); // down
+    let mut letters = String::new();
+    let mut steps = 0;
+
+    while let Some(here) = pos {
+        let c = diagram[here];
+        if c == ' ' {
+            break;
+        }
+
+        if c.is_ascii_alphabetic() {
+            letters.push(c);
+        } else if c == '+' {
+            dir = find_new_direction(diagram, here, dir);
+        }
+
+        pos = diagram.step(here, dir);
+        steps += 1;
+    }
+
+    (letters, steps)
+}
+
+fn find_new_direction(diagram: &Grid<char>, pos: Pos, (dx, dy): (isize, isize)) -> (isize, isize) {
+    ORTHOGONAL
+        .into_iter()
+        .filter(|&d| d != (-dx, -dy))
+        .find(|&d| {
+            diagram
+                .step(pos, d)
+                .is_some_and(|next| diagram[next] != ' ')
+        })
+        .expect("No valid direction found at junction")
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 19;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        read_diagram(input)
+    }
+
+    fn part1(diagram: &Grid<char>) -> impl Display {
+        traverse_diagram(diagram).0
+    }
+
+    fn part2(diagram: &Grid<char>) -> impl Display {
+        traverse_diagram(diagram).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follow_the_path() {
+        let diagram = [
+            "     |          ",
+            "     |  +--+    ",
+            "     A  |  C    ",
+            " F---|----E|--+ ",
+            "     |  |  |  D ",
+            "     +B-+  +--+ ",
+        ]
+        .join("\n");
+        assert_eq!(
+            traverse_diagram(&Day19::parse(&diagram).unwrap()),
+            ("ABCDEF".to_string(), 38)
+        );
+    }
+}
@@ -1,40 +1,41 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
-struct Particle {
+pub struct Particle {
     position: (i32, i32, i32),
     velocity: (i32, i32, i32),
     acceleration: (i32, i32, i32),
 }
 
-fn parse_vector(components: &str) -> (i32, i32, i32) {
-    let start = components.find("<").unwrap() + 1;
-    let end = components.find(">").unwrap();
-    let coords: Vec<i32> = components[start..end]
+fn parse_vector(line: &str, name: char) -> Result<(i32, i32, i32), ParseError> {
+    let expected = format!("a vector `{name}=<X,Y,Z>`");
+    let components = line
+        .split_once(&format!("{name}=<"))
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(components, _)| components)
+        .ok_or_else(|| ParseError::at(line, line, expected.as_str()))?;
+    let coords = components
         .split(",")
-        .map(|n| n.parse::<i32>().unwrap())
-        .collect();
-    (coords[0], coords[1], coords[2])
+        .map(|n| parse::number(line, n.trim()))
+        .collect::<Result<Vec<i32>, _>>()?;
+    match coords[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(ParseError::at(line, components, expected)),
+    }
 }
 
-fn parse_input(path: &str) -> Vec<Particle> {
-    let binding = fs::read_to_string(path).expect("Error reading input file");
-    let mut particles: Vec<Particle> = Vec::new();
-    for l in binding.lines() {
-        let parts: Vec<&str> = l.split(", ").collect();
-        let pos = parse_vector(parts[0]);
-        let vel = parse_vector(parts[1]);
-        let acc = parse_vector(parts[2]);
-        particles.push(Particle {
-            position: pos,
-            velocity: vel,
-            acceleration: acc,
-        });
-    }
-    particles
+fn parse_particle(line: &str) -> Result<Particle, ParseError> {
+    Ok(Particle {
+        position: parse_vector(line, 'p')?,
+        velocity: parse_vector(line, 'v')?,
+        acceleration: parse_vector(line, 'a')?,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Particle>, ParseError> {
+    parse::lines(input, parse_particle)
 }
 
 fn abs_norm(p: &Particle) -> (i32, i32, i32) {
@@ -51,7 +52,7 @@ fn closest_to_origin(particles: &[Particle]) -> usize {
         vec_part.push((i, pos, vel, acc));
     }
     // sort handling ties acc > vel > pos
-    vec_part.sort_unstable_by(|a, b| (a.3, a.2, a.1).cmp(&(b.3, b.2, b.1)));
+    vec_part.sort_unstable_by_key(|a| (a.3, a.2, a.1));
     // index of the original particle closest to the origin
     vec_part[0].0
 }
@@ -109,15 +110,50 @@ fn simulate(particles: &mut Vec<Particle>) -> usize {
     particles.len()
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let particles = parse_input(path);
-    println!("{sep} Part 1 {sep}");
-    let pt1 = closest_to_origin(&particles);
-    println!("The paticle closest to the origin will be particle: {pt1}");
-    println!("{sep} Part 2 {sep}");
-    let mut part2 = particles.clone();
-    let pt2 = simulate(&mut part2);
-    println!("The number of remaining particles after stable: {pt2}");
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 20;
+
+    type Input = Vec<Particle>;
+
+    fn parse(input: &str) -> Result<Vec<Particle>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(particles: &Vec<Particle>) -> impl Display {
+        closest_to_origin(particles)
+    }
+
+    fn part2(particles: &Vec<Particle>) -> impl Display {
+        simulate(&mut particles.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_in_the_long_run() {
+        let particles = Day20::parse(
+            "p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
+p=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>",
+        )
+        .unwrap();
+        assert_eq!(closest_to_origin(&particles), 0);
+    }
+
+    #[test]
+    fn test_collisions() {
+        let mut particles = Day20::parse(
+            "p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>",
+        )
+        .unwrap();
+        assert_eq!(simulate(&mut particles), 1);
+    }
 }
@@ -0,0 +1,122 @@
+use aoc_common::{Grid, ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type Rules = HashMap<Grid<bool>, Grid<bool>>;
+
+const START: &str = ".#./..#/###";
+
+fn variations(grid: &Grid<bool>) -> Vec<Grid<bool>> {
+    let mut variants = Vec::new();
+    let mut current = grid.clone();
+    for _ in 0..4 {
+        variants.push(current.flipped());
+        let next = current.rotated();
+        variants.push(current);
+        current = next;
+    }
+    variants
+}
+
+/// Reads a square pattern such as `.#/#.`, which must be a slice of `line`.
+fn parse_pattern(line: &str, pattern: &str) -> Result<Grid<bool>, ParseError> {
+    let rows: Vec<&str> = pattern.split('/').collect();
+    for row in &rows {
+        parse::only(line, row, |c| c == '.' || c == '#', "`.` or `#`")?;
+        if row.len() != rows.len() {
+            return Err(ParseError::at(
+                line,
+                row,
+                format!("a row of {} pixels", rows.len()),
+            ));
+        }
+    }
+    Ok(Grid::from_fn(rows.len(), rows.len(), |(x, y)| {
+        rows[y].as_bytes()[x] == b'#'
+    }))
+}
+
+fn enhance(grid: &Grid<bool>, rules: &Rules) -> Grid<bool> {
+    let size = grid.width();
+    let block_size = if size.is_multiple_of(2) { 2 } else { 3 };
+    let new_block_size = block_size + 1;
+    let blocks_per_side = size / block_size;
+    let new_size = blocks_per_side * new_block_size;
+    let mut new_grid = Grid::new(new_size, new_size, false);
+
+    for i in 0..blocks_per_side {
+        for j in 0..blocks_per_side {
+            let block = grid.subgrid((j * block_size, i * block_size), block_size, block_size);
+            let output = rules.get(&block).expect("No matching pattern found");
+            new_grid.paste((j * new_block_size, i * new_block_size), output);
+        }
+    }
+    new_grid
+}
+
+fn load_rules(content: &str) -> Result<Rules, ParseError> {
+    let mut rules = HashMap::new();
+    let pairs = parse::lines(content, |line| {
+        let (input, output) = line
+            .split_once(" => ")
+            .ok_or_else(|| ParseError::at(line, line, "a rule `pattern => pattern`"))?;
+        Ok((parse_pattern(line, input)?, parse_pattern(line, output)?))
+    })?;
+    for (input_grid, output_grid) in pairs {
+        for variant in variations(&input_grid) {
+            rules.insert(variant, output_grid.clone());
+        }
+    }
+    Ok(rules)
+}
+
+fn solve(rules: &Rules, iterations: usize) -> usize {
+    let mut grid = parse_pattern(START, START).expect("the start pattern is valid");
+    for _ in 0..iterations {
+        grid = enhance(&grid, rules);
+    }
+    grid.count(|&on| on)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 21;
+
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Rules, ParseError> {
+        load_rules(input)
+    }
+
+    fn part1(rules: &Rules) -> impl Display {
+        solve(rules, 5)
+    }
+
+    fn part2(rules: &Rules) -> impl Display {
+        solve(rules, 18)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variations_cover_the_rule() {
+        let rules = load_rules(".#./..#/### => #..#/..../..../#..#").unwrap();
+        for pattern in [".#./..#/###", ".#./#../###", "#../#.#/##.", "###/..#/.#."] {
+            let grid = parse_pattern(pattern, pattern).unwrap();
+            assert!(rules.contains_key(&grid), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_enhance() {
+        let rules =
+            Day21::parse("../.# => ##./#../...\n.#./..#/### => #..#/..../..../#..#").unwrap();
+        assert_eq!(solve(&rules, 1), 4);
+        assert_eq!(solve(&rules, 2), 12);
+    }
+}
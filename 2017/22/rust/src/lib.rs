@@ -1,8 +1,9 @@
+use aoc_common::{ParseError, Solution, parse};
 use std::collections::HashMap;
-use std::fs;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-enum NodeState {
+pub enum NodeState {
     Clean,
     Weakened,
     Infected,
@@ -46,10 +47,12 @@ impl Direction {
     }
 }
 
-fn parse_input(path: &str) -> HashMap<(i32, i32), NodeState> {
-    let input = fs::read_to_string(path).expect("Error reading input file");
+fn parse_input(input: &str) -> Result<HashMap<(i32, i32), NodeState>, ParseError> {
     let mut grid = HashMap::new();
     let lines: Vec<_> = input.lines().collect();
+    for line in &lines {
+        parse::only(input, line, |c| c == '#' || c == '.', "`#` or `.`")?;
+    }
     let offset = (lines.len() / 2) as i32;
 
     for (y, line) in lines.iter().enumerate() {
@@ -59,7 +62,7 @@ fn parse_input(path: &str) -> HashMap<(i32, i32), NodeState> {
             }
         }
     }
-    grid
+    Ok(grid)
 }
 
 fn simulate(mut grid: HashMap<(i32, i32), NodeState>, bursts: usize, part2: bool) -> usize {
@@ -117,15 +120,45 @@ fn simulate(mut grid: HashMap<(i32, i32), NodeState>, bursts: usize, part2: bool
     infections
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let grid = parse_input(path);
-
-    println!("{sep} Part 1 {sep}");
-    let part1_result = simulate(grid.clone(), 10_000, false);
-    println!("Infections after 10,000 bursts : {}", part1_result);
-    println!("{sep} Part 2 {sep}");
-    let part2_result = simulate(grid, 10_000_000, true);
-    println!("Infections after 10,000,000 bursts : {}", part2_result);
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 22;
+
+    type Input = HashMap<(i32, i32), NodeState>;
+
+    fn parse(input: &str) -> Result<HashMap<(i32, i32), NodeState>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &HashMap<(i32, i32), NodeState>) -> impl Display {
+        simulate(grid.clone(), 10_000, false)
+    }
+
+    fn part2(grid: &HashMap<(i32, i32), NodeState>) -> impl Display {
+        simulate(grid.clone(), 10_000_000, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "..#\n#..\n...";
+
+    #[test]
+    fn test_bursts() {
+        let grid = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(simulate(grid.clone(), 7, false), 5);
+        assert_eq!(simulate(grid.clone(), 70, false), 41);
+        assert_eq!(simulate(grid, 10_000, false), 5587);
+    }
+
+    #[test]
+    fn test_evolved_bursts() {
+        let grid = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(simulate(grid.clone(), 100, true), 26);
+        assert_eq!(simulate(grid, 10_000_000, true), 2511944);
+    }
 }
@@ -0,0 +1,237 @@
+use aoc_common::{ParseError, Solution};
+use aoc_vm::coprocessor::{self, Instruction};
+use aoc_vm::{Machine, Operand, Reg, Step, Word};
+use std::fmt::Display;
+
+fn simulate(instructions: &[Instruction]) -> u64 {
+    let mut machine = Machine::new(instructions.to_vec());
+    machine.run();
+    machine.count("mul")
+}
+
+fn is_not_prime(n: usize) -> bool {
+    if n < 2 {
+        return true;
+    }
+    for i in 2..=((n as f64).sqrt() as usize) {
+        if n.is_multiple_of(i) {
+            return true;
+        }
+    }
+    false
+}
+
+// What the program does once its setup is done: for every b from b to c in
+// steps of `{step}`, it tries every product d * e to tell whether b is
+// composite, and counts the composites in h. Only the step and the setup
+// differ between inputs.
+const COMPOSITE_LOOP: &str = "set f 1
+set d 2
+set e 2
+set g d
+mul g e
+sub g b
+jnz g 2
+set f 0
+sub e -1
+set g e
+sub g b
+jnz g -8
+sub d -1
+set g d
+sub g b
+jnz g -13
+jnz f 2
+sub h -1
+set g b
+sub g c
+jnz g 2
+jnz 1 3
+sub b -{step}
+jnz 1 -23";
+
+// Why a program does not count composites the way the puzzle's does: the
+// line (from 0) to blame, and what it should have been.
+#[derive(Debug, PartialEq, Eq)]
+struct Unexpected {
+    line: usize,
+    expected: String,
+}
+
+fn unexpected(line: usize, expected: impl Into<String>) -> Unexpected {
+    Unexpected {
+        line,
+        expected: expected.into(),
+    }
+}
+
+// The b, c and step of the composite-counting loop, found by checking that
+// the program ends with that loop and running its setup with a = 1 for at
+// most `max_steps` steps.
+fn composite_range(
+    instructions: &[Instruction],
+    max_steps: u64,
+) -> Result<(usize, usize, usize), Unexpected> {
+    let pattern: Vec<&str> = COMPOSITE_LOOP.lines().collect();
+    let start = (instructions.len().checked_sub(pattern.len())).ok_or_else(|| {
+        unexpected(
+            instructions.len(),
+            "the composite-counting loop at the end of the program",
+        )
+    })?;
+    let step = match instructions[instructions.len() - 2] {
+        Instruction::Sub(b, Operand::Value(step)) if b == Reg::new('b') && step < 0 => -step,
+        _ => {
+            return Err(unexpected(
+                instructions.len() - 2,
+                "the step added to b, as in `sub b -17`",
+            ));
+        }
+    };
+    let expected = coprocessor::parse(&COMPOSITE_LOOP.replace("{step}", &step.to_string()))
+        .expect("the composite-counting loop is a valid program");
+    for (i, line) in pattern.iter().enumerate() {
+        if instructions[start + i] != expected[i] {
+            let line = line.replace("{step}", &step.to_string());
+            return Err(unexpected(
+                start + i,
+                format!("`{line}` of the composite-counting loop"),
+            ));
+        }
+    }
+
+    let mut machine = Machine::new(instructions[..start].to_vec());
+    machine.set_register('a', 1);
+    let mut setup = Step::Ran;
+    while setup == Step::Ran && machine.executed() < max_steps {
+        setup = machine.step();
+    }
+    if setup == Step::Ran {
+        return Err(unexpected(
+            start,
+            format!("the setup to get here within {max_steps} steps"),
+        ));
+    }
+    if setup != Step::Halted || machine.pc != start as Word {
+        return Err(unexpected(start, "the setup to lead here"));
+    }
+    let (b, c) = (machine.register('b'), machine.register('c'));
+    if b < 2 || c < b || (c - b) % step != 0 {
+        return Err(unexpected(
+            start,
+            format!("b = {b} to reach c = {c} in steps of {step} from here"),
+        ));
+    }
+    Ok((b as usize, c as usize, step as usize))
+}
+
+fn count_composites(instructions: &[Instruction], max_steps: u64) -> Result<usize, Unexpected> {
+    let (start, end, step) = composite_range(instructions, max_steps)?;
+    Ok((start..=end)
+        .step_by(step)
+        .filter(|&n| is_not_prime(n))
+        .count())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 23;
+
+    type Input = Vec<Instruction>;
+
+    // Part 2 only works for programs that count composites like the
+    // puzzle's, so anything else is refused here.
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let instructions = coprocessor::parse(input)?;
+        if let Err(err) = composite_range(&instructions, aoc_vm::max_steps()) {
+            let line = input.lines().nth(err.line);
+            let text = line.unwrap_or(&input[input.len()..]);
+            return Err(ParseError::at(input, text, err.expected));
+        }
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> impl Display {
+        simulate(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> impl Display {
+        count_composites(instructions, aoc_vm::max_steps())
+            .expect("parse checks that the program counts composites")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_mul() {
+        // The puzzle has no example; this loop multiplies three times.
+        let program = coprocessor::parse("set b 3\nmul a 2\nsub b 1\njnz b -2").unwrap();
+        assert_eq!(simulate(&program), 3);
+    }
+
+    // A setup like the puzzle's, over 20, 37 and 54.
+    fn program(setup: &str) -> Vec<Instruction> {
+        let text = format!("{setup}\n{}", COMPOSITE_LOOP.replace("{step}", "17"));
+        coprocessor::parse(&text).unwrap()
+    }
+
+    const SETUP: &str =
+        "set b 5\nset c b\njnz a 2\njnz 1 5\nmul b 2\nsub b -10\nset c b\nsub c -34";
+
+    #[test]
+    fn test_composite_range() {
+        let instructions = program(SETUP);
+        assert_eq!(composite_range(&instructions, 1000), Ok((20, 54, 17)));
+        assert_eq!(count_composites(&instructions, 1000), Ok(2));
+
+        let mut machine = Machine::new(instructions);
+        machine.set_register('a', 1);
+        machine.run();
+        assert_eq!(machine.register('h'), 2);
+    }
+
+    #[test]
+    fn test_other_programs_are_refused() {
+        let mut instructions = program(SETUP);
+        instructions[12] = coprocessor::parse("mul g d").unwrap()[0];
+        assert_eq!(
+            composite_range(&instructions, 1000),
+            Err(unexpected(12, "`mul g e` of the composite-counting loop"))
+        );
+        let instructions = program(&SETUP.replace("-34", "-30"));
+        assert_eq!(
+            composite_range(&instructions, 1000),
+            Err(unexpected(
+                8,
+                "b = 20 to reach c = 50 in steps of 17 from here"
+            ))
+        );
+        let instructions = program(&format!("{SETUP}\njnz 1 0"));
+        assert_eq!(
+            composite_range(&instructions, 1000),
+            Err(unexpected(9, "the setup to get here within 1000 steps"))
+        );
+
+        let err = Day23::parse("set b 3\nmul a 2\nsub b 1\njnz b -2").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (4, ""));
+        assert_eq!(
+            err.expected,
+            "the composite-counting loop at the end of the program"
+        );
+        let text = format!("{SETUP}\n{}", COMPOSITE_LOOP.replace("{step}", "17"));
+        assert!(Day23::parse(&text).is_ok());
+        let err = Day23::parse(&text.replace("mul g e", "mul g d")).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (13, "mul g d"));
+    }
+
+    #[test]
+    fn test_composites() {
+        let composites: Vec<usize> = (0..20).filter(|&n| is_not_prime(n)).collect();
+        assert_eq!(composites, [0, 1, 4, 6, 8, 9, 10, 12, 14, 15, 16, 18]);
+    }
+}
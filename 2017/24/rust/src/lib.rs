@@ -1,8 +1,9 @@
+use aoc_common::{ParseError, Solution, parse};
 use std::collections::HashSet;
-use std::fs;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-struct Component(usize, usize);
+pub struct Component(usize, usize);
 
 impl Component {
     fn matches(&self, port: usize) -> bool {
@@ -18,15 +19,32 @@ impl Component {
     }
 }
 
-fn parse_components(path: &str) -> Vec<Component> {
-    fs::read_to_string(path)
-        .expect("Error reading input file")
-        .lines()
-        .map(|line| {
-            let parts: Vec<usize> = line.split('/').map(|x| x.parse().unwrap()).collect();
-            Component(parts[0], parts[1])
-        })
-        .collect()
+fn parse_components(input: &str) -> Result<Vec<Component>, ParseError> {
+    parse::lines(input, |line| {
+        let (a, b) = line
+            .split_once('/')
+            .ok_or_else(|| ParseError::at(line, line, "a component `A/B`"))?;
+        Ok(Component(parse::number(line, a)?, parse::number(line, b)?))
+    })
+}
+
+/// Returns the strength of the strongest bridge, and the strength of the
+/// longest one (ties broken by strength).
+fn strongest_bridges(components: &[Component]) -> (usize, usize) {
+    let mut used = HashSet::new();
+    let mut max_strength = 0;
+    let mut max_longest = (0, 0);
+
+    build_bridges(
+        components,
+        &mut used,
+        0,
+        0,
+        0,
+        &mut max_strength,
+        &mut max_longest,
+    );
+    (max_strength, max_longest.1)
 }
 
 fn build_bridges(
@@ -65,25 +83,35 @@ fn build_bridges(
     }
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let components = parse_components(path);
-    let mut used = HashSet::new();
-    let mut max_strength = 0;
-    let mut max_longest = (0, 0);
+pub struct Day24;
 
-    build_bridges(
-        &components,
-        &mut used,
-        0,
-        0,
-        0,
-        &mut max_strength,
-        &mut max_longest,
-    );
-    println!("{sep} Part 1 {sep}");
-    println!("Part 1: Strongest bridge has strength {}", max_strength);
-    println!("{sep} Part 2 {sep}");
-    println!("Part 2: Longest bridge has strength {}", max_longest.1);
+impl Solution for Day24 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 24;
+
+    type Input = Vec<Component>;
+
+    fn parse(input: &str) -> Result<Vec<Component>, ParseError> {
+        parse_components(input)
+    }
+
+    fn part1(components: &Vec<Component>) -> impl Display {
+        strongest_bridges(components).0
+    }
+
+    fn part2(components: &Vec<Component>) -> impl Display {
+        strongest_bridges(components).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridges() {
+        let components = Day24::parse("0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10").unwrap();
+        // 0/1--10/1--9/10 is the strongest, 0/2--2/2--2/3--3/5 the longest.
+        assert_eq!(strongest_bridges(&components), (31, 19));
+    }
 }
@@ -0,0 +1,204 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy)]
+pub enum MoveDir {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Action {
+    write: u8,
+    move_dir: MoveDir,
+    next_state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    actions: Vec<Action>,
+}
+
+/// The part of `line` between `prefix` and `suffix`. `line` is `None` past
+/// the end of `input`.
+fn field<'a>(
+    input: &'a str,
+    line: Option<&'a str>,
+    prefix: &str,
+    suffix: &str,
+) -> Result<&'a str, ParseError> {
+    let expected = || format!("`{prefix}...{suffix}`");
+    let line = line
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], expected()))?
+        .trim();
+    line.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .ok_or_else(|| ParseError::at(input, line, expected()))
+}
+
+fn parse_blueprint(input: &str) -> Result<(String, usize, HashMap<String, State>), ParseError> {
+    let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+
+    let start_state = field(input, lines.next(), "Begin in state ", ".")?;
+    let steps = field(
+        input,
+        lines.next(),
+        "Perform a diagnostic checksum after ",
+        " steps.",
+    )?;
+    let steps = parse::number(input, steps)?;
+
+    let mut states = HashMap::new();
+    let mut references = vec![start_state];
+    while let Some(header) = lines.next() {
+        let state_name = field(input, Some(header), "In state ", ":")?;
+
+        let mut actions = Vec::new();
+        for value in ["0", "1"] {
+            let current = field(input, lines.next(), "If the current value is ", ":")?;
+            if current != value {
+                return Err(ParseError::at(
+                    input,
+                    current,
+                    format!("the current value {value}"),
+                ));
+            }
+            let write = match field(input, lines.next(), "- Write the value ", ".")? {
+                "0" => 0,
+                "1" => 1,
+                other => return Err(ParseError::at(input, other, "the value 0 or 1")),
+            };
+            let move_dir = match field(input, lines.next(), "- Move one slot to the ", ".")? {
+                "left" => MoveDir::Left,
+                "right" => MoveDir::Right,
+                other => return Err(ParseError::at(input, other, "`left` or `right`")),
+            };
+            let next_state = field(input, lines.next(), "- Continue with state ", ".")?;
+            references.push(next_state);
+            actions.push(Action {
+                write,
+                move_dir,
+                next_state: next_state.to_string(),
+            });
+        }
+
+        states.insert(state_name.to_string(), State { actions });
+    }
+
+    if let Some(missing) = references
+        .into_iter()
+        .find(|name| !states.contains_key(*name))
+    {
+        return Err(ParseError::at(
+            input,
+            missing,
+            "a state described by the blueprint",
+        ));
+    }
+
+    Ok((start_state.to_string(), steps, states))
+}
+
+fn simulate_turing_machine(
+    start_state: &str,
+    steps: usize,
+    states: &HashMap<String, State>,
+) -> usize {
+    let mut tape = HashSet::new();
+    let mut cursor: isize = 0;
+    let mut current_state = start_state.to_string();
+
+    for _ in 0..steps {
+        let current_val = tape.contains(&cursor) as usize;
+        // The parser checked that every state a blueprint refers to is described.
+        let state = &states[&current_state];
+        let action = &state.actions[current_val];
+
+        if action.write == 1 {
+            tape.insert(cursor);
+        } else {
+            tape.remove(&cursor);
+        }
+
+        match action.move_dir {
+            MoveDir::Left => cursor -= 1,
+            MoveDir::Right => cursor += 1,
+        }
+
+        current_state = action.next_state.clone();
+    }
+
+    tape.len()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 25;
+
+    type Input = (String, usize, HashMap<String, State>);
+
+    fn parse(input: &str) -> Result<(String, usize, HashMap<String, State>), ParseError> {
+        parse_blueprint(input)
+    }
+
+    fn part1(blueprint: &(String, usize, HashMap<String, State>)) -> impl Display {
+        let (start_state, steps, states) = blueprint;
+        simulate_turing_machine(start_state, *steps, states)
+    }
+
+    fn part2(_blueprint: &(String, usize, HashMap<String, State>)) -> impl Display {
+        // There is no second puzzle on the last day.
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+    #[test]
+    fn test_checksum() {
+        let (start_state, steps, states) = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!((start_state.as_str(), steps), ("A", 6));
+        assert_eq!(simulate_turing_machine(&start_state, steps, &states), 3);
+    }
+
+    #[test]
+    fn test_malformed_blueprint() {
+        let err = Day25::parse(&EXAMPLE.replace("to the left", "to the top")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (11, 28, "top"));
+        let err = Day25::parse(&EXAMPLE.replace("state A.", "state C.")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 16: expected a state described by the blueprint, found `C`"
+        );
+        let err = Day25::parse(&EXAMPLE.replace("after 6 steps", "after six steps")).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "six"));
+    }
+}
@@ -1,9 +1,9 @@
+use aoc_common::{ParseError, Solution};
 use std::collections::HashMap;
-use std::fs;
+use std::fmt::Display;
 
-fn parse_ids(path: &str) -> Vec<String> {
-    fs::read_to_string(path)
-        .expect("Error reading input file")
+fn parse_ids(input: &str) -> Vec<String> {
+    input
         .lines()
         .map(|line| line.to_string())
         .collect()
@@ -52,16 +52,40 @@ fn find_prototype_boxes(ids: &[String]) -> String {
     String::new()
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let ids = parse_ids(path);
+pub struct Day02;
 
-    let part1_result = calculate_checksum(&ids);
-    let part2_result = find_prototype_boxes(&ids);
+impl Solution for Day02 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 2;
 
-    println!("{sep} Part 1 {sep}");
-    println!("Checksum is {}", part1_result);
-    println!("{sep} Part 2 {sep}");
-    println!("Common letters are {}", part2_result);
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(parse_ids(input))
+    }
+
+    fn part1(ids: &Vec<String>) -> impl Display {
+        calculate_checksum(ids)
+    }
+
+    fn part2(ids: &Vec<String>) -> impl Display {
+        find_prototype_boxes(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        let ids = Day02::parse("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab").unwrap();
+        assert_eq!(calculate_checksum(&ids), 12);
+    }
+
+    #[test]
+    fn test_prototype_boxes() {
+        let ids = Day02::parse("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz").unwrap();
+        assert_eq!(find_prototype_boxes(&ids), "fgij");
+    }
 }
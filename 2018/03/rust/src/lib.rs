@@ -0,0 +1,118 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Claim {
+    id: usize,
+    left: usize,
+    top: usize,
+    width: usize,
+    height: usize,
+}
+
+fn parse_claim(line: &str) -> Result<Claim, ParseError> {
+    let id = parse::word(line, 0, "a claim id `#N`")?;
+    let id = id
+        .strip_prefix('#')
+        .ok_or_else(|| ParseError::at(line, id, "a claim id `#N`"))?;
+    let coords = parse::word(line, 2, "a position `X,Y:`")?;
+    let (left, top) = coords
+        .strip_suffix(':')
+        .and_then(|coords| coords.split_once(','))
+        .ok_or_else(|| ParseError::at(line, coords, "a position `X,Y:`"))?;
+    let dims = parse::word(line, 3, "a size `WxH`")?;
+    let (width, height) = dims
+        .split_once('x')
+        .ok_or_else(|| ParseError::at(line, dims, "a size `WxH`"))?;
+    Ok(Claim {
+        id: parse::number(line, id)?,
+        left: parse::number(line, left)?,
+        top: parse::number(line, top)?,
+        width: parse::number(line, width)?,
+        height: parse::number(line, height)?,
+    })
+}
+
+fn parse_claims(input: &str) -> Result<Vec<Claim>, ParseError> {
+    parse::lines(input, parse_claim)
+}
+
+type Fabric = HashMap<(usize, usize), usize>;
+type ClaimMap = HashMap<usize, HashSet<(usize, usize)>>;
+
+fn mark_fabric(claims: &[Claim]) -> (Fabric, ClaimMap) {
+    let mut fabric = HashMap::new();
+    let mut claim_map = HashMap::new();
+
+    for claim in claims {
+        let mut positions = HashSet::new();
+        for i in claim.left..claim.left + claim.width {
+            for j in claim.top..claim.top + claim.height {
+                *fabric.entry((i, j)).or_insert(0) += 1;
+                positions.insert((i, j));
+            }
+        }
+        claim_map.insert(claim.id, positions);
+    }
+
+    (fabric, claim_map)
+}
+
+fn count_overlaps(fabric: &HashMap<(usize, usize), usize>) -> usize {
+    fabric.values().filter(|&&count| count > 1).count()
+}
+
+fn find_non_overlapping(
+    claim_map: &HashMap<usize, HashSet<(usize, usize)>>,
+    fabric: &HashMap<(usize, usize), usize>,
+) -> usize {
+    for (id, positions) in claim_map {
+        if positions.iter().all(|pos| fabric[pos] == 1) {
+            return *id;
+        }
+    }
+    0
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Claim>;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+        parse_claims(input)
+    }
+
+    fn part1(claims: &Vec<Claim>) -> impl Display {
+        let (fabric, _) = mark_fabric(claims);
+        count_overlaps(&fabric)
+    }
+
+    fn part2(claims: &Vec<Claim>) -> impl Display {
+        let (fabric, claim_map) = mark_fabric(claims);
+        find_non_overlapping(&claim_map, &fabric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2";
+
+    #[test]
+    fn test_overlaps() {
+        let (fabric, _) = mark_fabric(&Day03::parse(EXAMPLE).unwrap());
+        assert_eq!(count_overlaps(&fabric), 4);
+    }
+
+    #[test]
+    fn test_intact_claim() {
+        let (fabric, claim_map) = mark_fabric(&Day03::parse(EXAMPLE).unwrap());
+        assert_eq!(find_non_overlapping(&claim_map, &fabric), 3);
+    }
+}
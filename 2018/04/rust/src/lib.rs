@@ -0,0 +1,245 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// A record of the log, in the order the records happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The guard with this id begins their shift.
+    Shift(usize),
+    /// The guard on shift falls asleep at this minute past midnight.
+    Sleep(u8),
+    /// The guard on shift wakes up at this minute past midnight.
+    Wake(u8),
+}
+
+// The timestamp, kept to sort the records by, the event and its text.
+fn parse_record(line: &str) -> Result<(&str, Event, &str), ParseError> {
+    let (ts, event) = line
+        .strip_prefix('[')
+        .and_then(|line| line.split_once("] "))
+        .ok_or_else(|| ParseError::at(line, line, "a record `[YYYY-MM-DD hh:mm] event`"))?;
+    let minute = match ts.get(14..) {
+        Some(minute) if ts.len() == 16 => parse::number::<u8>(line, minute)?,
+        _ => return Err(ParseError::at(line, ts, "a timestamp `YYYY-MM-DD hh:mm`")),
+    };
+    if minute >= 60 {
+        return Err(ParseError::at(line, &ts[14..], "a minute from 00 to 59"));
+    }
+    let parsed = match event {
+        "falls asleep" => Event::Sleep(minute),
+        "wakes up" => Event::Wake(minute),
+        _ => {
+            let id = parse::word(line, 3, "a guard `#N`")?;
+            match id.strip_prefix('#') {
+                Some(id) if event.starts_with("Guard ") => Event::Shift(parse::number(line, id)?),
+                _ => {
+                    let expected = "`Guard #N begins shift`, `falls asleep` or `wakes up`";
+                    return Err(ParseError::at(line, event, expected));
+                }
+            }
+        }
+    };
+    Ok((ts, parsed, event))
+}
+
+// Sorts the records and checks that every nap is taken by a guard on shift
+// and ends, later in the hour, before the next nap or shift.
+fn parse_logs(input: &str) -> Result<Vec<Event>, ParseError> {
+    let mut records = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_record(line).map_err(|e| e.below(i)))
+        .collect::<Result<Vec<_>, _>>()?;
+    records.sort_by_key(|&(ts, _, _)| ts);
+
+    let mut on_shift = false;
+    let mut asleep: Option<(u8, &str)> = None;
+    for &(_, event, text) in &records {
+        match (event, asleep) {
+            (Event::Shift(_) | Event::Sleep(_), Some((_, nap))) => {
+                return Err(ParseError::at(
+                    input,
+                    nap,
+                    "a nap that ends with `wakes up`",
+                ));
+            }
+            (Event::Shift(_), None) => on_shift = true,
+            (Event::Sleep(_), None) if !on_shift => {
+                return Err(ParseError::at(
+                    input,
+                    text,
+                    "a guard on shift to fall asleep",
+                ));
+            }
+            (Event::Sleep(minute), None) => asleep = Some((minute, text)),
+            (Event::Wake(_), None) => {
+                return Err(ParseError::at(
+                    input,
+                    text,
+                    "`falls asleep` before `wakes up`",
+                ));
+            }
+            (Event::Wake(minute), Some((fell, nap))) if minute <= fell => {
+                let expected = "a guard to wake up later in the hour than they fell asleep";
+                return Err(ParseError::at(input, nap, expected));
+            }
+            (Event::Wake(_), Some(_)) => asleep = None,
+        }
+    }
+    if let Some((_, nap)) = asleep {
+        return Err(ParseError::at(
+            input,
+            nap,
+            "a nap that ends with `wakes up`",
+        ));
+    }
+    if !records
+        .iter()
+        .any(|&(_, event, _)| matches!(event, Event::Sleep(_)))
+    {
+        let end = &input[input.len()..];
+        return Err(ParseError::at(input, end, "a guard falling asleep"));
+    }
+    Ok(records.into_iter().map(|(_, event, _)| event).collect())
+}
+
+fn analyze_guard_sleeps(logs: &[Event]) -> (usize, usize, usize) {
+    let mut guard = 0;
+    let mut asleep_min = 0;
+    let mut sleep_map: HashMap<usize, [usize; 60]> = HashMap::new();
+
+    for &event in logs {
+        match event {
+            Event::Shift(id) => guard = id,
+            Event::Sleep(minute) => asleep_min = usize::from(minute),
+            Event::Wake(minute) => {
+                let entry = sleep_map.entry(guard).or_insert([0; 60]);
+                for count in &mut entry[asleep_min..usize::from(minute)] {
+                    *count += 1;
+                }
+            }
+        }
+    }
+
+    // The parser made sure somebody slept
+    let part1_guard = sleep_map
+        .iter()
+        .max_by_key(|&(_, mins)| mins.iter().sum::<usize>())
+        .map(|(id, _)| *id)
+        .expect("a guard slept");
+
+    let part1_minute = sleep_map[&part1_guard]
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, &v)| v)
+        .map(|(i, _)| i)
+        .expect("an hour has minutes");
+
+    let (part2_guard, part2_minute, _) = sleep_map
+        .iter()
+        .flat_map(|(&id, minutes)| minutes.iter().enumerate().map(move |(i, &v)| (id, i, v)))
+        .max_by_key(|&(_, _, v)| v)
+        .expect("a guard slept");
+
+    (
+        part1_guard * part1_minute,
+        part2_guard * part2_minute,
+        part2_guard,
+    )
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Event>;
+
+    fn parse(input: &str) -> Result<Vec<Event>, ParseError> {
+        parse_logs(input)
+    }
+
+    fn part1(logs: &Vec<Event>) -> impl Display {
+        analyze_guard_sleeps(logs).0
+    }
+
+    fn part2(logs: &Vec<Event>) -> impl Display {
+        analyze_guard_sleeps(logs).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shuffled, since the records are not in chronological order either.
+    const EXAMPLE: &str = "[1518-11-01 00:25] wakes up
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn test_sleepiest_guard() {
+        assert_eq!(
+            analyze_guard_sleeps(&Day04::parse(EXAMPLE).unwrap()),
+            (240, 4455, 99)
+        );
+    }
+
+    fn error(log: &str) -> (usize, usize, String) {
+        let err = Day04::parse(log).unwrap_err();
+        (err.line, err.column, err.expected)
+    }
+
+    #[test]
+    fn test_minutes_past_the_hour() {
+        let log = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:99] wakes up";
+        assert_eq!(error(log), (3, 16, "a minute from 00 to 59".to_string()));
+    }
+
+    #[test]
+    fn test_unpaired_naps() {
+        let reversed = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:30] falls asleep
+[1518-11-02 00:10] wakes up";
+        let expected = "a guard to wake up later in the hour than they fell asleep";
+        assert_eq!(error(reversed), (2, 20, expected.to_string()));
+
+        let sleepless = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:30] wakes up";
+        let expected = "`falls asleep` before `wakes up`";
+        assert_eq!(error(sleepless), (2, 20, expected.to_string()));
+
+        let unended = "[1518-11-01 00:05] falls asleep
+[1518-11-01 00:00] Guard #10 begins shift";
+        let expected = "a nap that ends with `wakes up`";
+        assert_eq!(error(unended), (1, 20, expected.to_string()));
+
+        let nobody = "[1518-11-01 00:05] falls asleep";
+        let expected = "a guard on shift to fall asleep";
+        assert_eq!(error(nobody), (1, 20, expected.to_string()));
+    }
+
+    #[test]
+    fn test_nobody_sleeps() {
+        let log = "[1518-11-01 00:00] Guard #10 begins shift";
+        assert_eq!(error(log), (1, 42, "a guard falling asleep".to_string()));
+    }
+}
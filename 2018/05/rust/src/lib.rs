@@ -0,0 +1,68 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+fn react_polymer(polymer: &str) -> String {
+    let mut stack: Vec<char> = Vec::new();
+    for ch in polymer.chars() {
+        if let Some(&last) = stack.last()
+            && last != ch && last.eq_ignore_ascii_case(&ch) {
+                stack.pop();
+                continue;
+            }
+        stack.push(ch);
+    }
+    stack.iter().collect()
+}
+
+fn shortest_reacted_polymer(polymer: &str) -> usize {
+    (b'a'..=b'z')
+        .map(|unit| {
+            let filtered: String = polymer
+                .chars()
+                .filter(|&ch| ch.to_ascii_lowercase() as u8 != unit)
+                .collect();
+            react_polymer(&filtered).len()
+        })
+        .min()
+        .unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 5;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(polymer: &String) -> impl Display {
+        react_polymer(polymer).len()
+    }
+
+    fn part2(polymer: &String) -> impl Display {
+        shortest_reacted_polymer(polymer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_react() {
+        assert_eq!(react_polymer("aA"), "");
+        assert_eq!(react_polymer("abBA"), "");
+        assert_eq!(react_polymer("abAB"), "abAB");
+        assert_eq!(react_polymer("aabAAB"), "aabAAB");
+        assert_eq!(react_polymer("dabAcCaCBAcCcaDA"), "dabCBAcaDA");
+    }
+
+    #[test]
+    fn test_remove_one_unit() {
+        assert_eq!(shortest_reacted_polymer("dabAcCaCBAcCcaDA"), 4);
+    }
+}
@@ -1,15 +1,17 @@
+use aoc_common::{ParseError, Solution, parse};
 use std::collections::{HashMap, HashSet};
-use std::fs;
-
-fn parse_coordinates(path: &str) -> Vec<(i32, i32)> {
-    fs::read_to_string(path)
-        .expect("Error reading input file")
-        .lines()
-        .map(|line| {
-            let parts: Vec<i32> = line.split(',').map(|s| s.trim().parse().unwrap()).collect();
-            (parts[0], parts[1])
-        })
-        .collect()
+use std::fmt::Display;
+
+fn parse_coordinates(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    parse::lines(input, |line| {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(line, line, "a coordinate `X, Y`"))?;
+        Ok((
+            parse::number(line, x.trim())?,
+            parse::number(line, y.trim())?,
+        ))
+    })
 }
 
 fn manhattan(p1: (i32, i32), p2: (i32, i32)) -> i32 {
@@ -63,15 +65,34 @@ fn compute_areas(coords: &[(i32, i32)], distance_limit: i32) -> (usize, usize) {
     (largest_finite_area, safe_region)
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let coords = parse_coordinates(path);
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 6;
+
+    type Input = Vec<(i32, i32)>;
 
-    let (part1_result, part2_result) = compute_areas(&coords, 10000);
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        parse_coordinates(input)
+    }
+
+    fn part1(coords: &Vec<(i32, i32)>) -> impl Display {
+        compute_areas(coords, 10000).0
+    }
 
-    println!("{sep} Part 1 {sep}");
-    println!("Largest finite area = {}", part1_result);
-    println!("{sep} Part 2 {sep}");
-    println!("Size of safe region = {}", part2_result);
+    fn part2(coords: &Vec<(i32, i32)>) -> impl Display {
+        compute_areas(coords, 10000).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_areas() {
+        let coords = Day06::parse("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9").unwrap();
+        assert_eq!(compute_areas(&coords, 32), (17, 16));
+    }
 }
@@ -1,16 +1,26 @@
+use aoc_common::{ParseError, Solution, parse};
 use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::fs;
+use std::fmt::Display;
 
-fn parse_dependencies(path: &str) -> HashMap<char, HashSet<char>> {
+fn parse_step(line: &str, index: usize) -> Result<char, ParseError> {
+    let word = parse::word(line, index, "a step")?;
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(step), None) if step.is_ascii_uppercase() => Ok(step),
+        _ => Err(ParseError::at(line, word, "a step `A` to `Z`")),
+    }
+}
+
+fn parse_dependencies(input: &str) -> Result<HashMap<char, HashSet<char>>, ParseError> {
     let mut deps: HashMap<char, HashSet<char>> = HashMap::new();
-    let input = fs::read_to_string(path).expect("Error reading input file");
-    for line in input.lines() {
-        let from = line.chars().nth(5).unwrap();
-        let to = line.chars().nth(36).unwrap();
+    let edges = parse::lines(input, |line| {
+        Ok((parse_step(line, 1)?, parse_step(line, 7)?))
+    })?;
+    for (from, to) in edges {
         deps.entry(to).or_default().insert(from);
         deps.entry(from).or_default();
     }
-    deps
+    Ok(deps)
 }
 
 fn determine_order(mut deps: HashMap<char, HashSet<char>>) -> String {
@@ -89,16 +99,46 @@ fn calculate_time(
     time
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let deps = parse_dependencies(path);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 7;
+
+    type Input = HashMap<char, HashSet<char>>;
+
+    fn parse(input: &str) -> Result<HashMap<char, HashSet<char>>, ParseError> {
+        parse_dependencies(input)
+    }
+
+    fn part1(deps: &HashMap<char, HashSet<char>>) -> impl Display {
+        determine_order(deps.clone())
+    }
+
+    fn part2(deps: &HashMap<char, HashSet<char>>) -> impl Display {
+        calculate_time(deps.clone(), 5, 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
 
-    let part1_result = determine_order(deps.clone());
-    let part2_result = calculate_time(deps, 5, 60);
+    #[test]
+    fn test_order() {
+        assert_eq!(determine_order(Day07::parse(EXAMPLE).unwrap()), "CABDFE");
+    }
 
-    println!("{sep} Part 1 {sep}");
-    println!("{}", part1_result);
-    println!("{sep} Part 2 {sep}");
-    println!("{}", part2_result);
+    #[test]
+    fn test_two_workers() {
+        assert_eq!(calculate_time(Day07::parse(EXAMPLE).unwrap(), 2, 0), 15);
+    }
 }
@@ -0,0 +1,90 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+/// The index just past the node starting at `start`, if `data` holds all of it.
+fn node_end(data: &[usize], start: usize) -> Option<usize> {
+    let (&child_count, &metadata_count) = (data.get(start)?, data.get(start + 1)?);
+    let mut end = start + 2;
+    for _ in 0..child_count {
+        end = node_end(data, end)?;
+    }
+    (end + metadata_count <= data.len()).then_some(end + metadata_count)
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let data = input
+        .split_whitespace()
+        .map(|n| parse::number(input, n))
+        .collect::<Result<Vec<usize>, _>>()?;
+    match node_end(&data, 0) {
+        None => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "the rest of the tree",
+        )),
+        Some(end) if end < data.len() => {
+            let extra = input.split_whitespace().nth(end).unwrap_or_default();
+            Err(ParseError::at(input, extra, "the end of the tree"))
+        }
+        Some(_) => Ok(data),
+    }
+}
+
+fn parse_node(data: &mut impl Iterator<Item = usize>) -> (usize, usize) {
+    let child_count = data.next().unwrap();
+    let metadata_count = data.next().unwrap();
+
+    let mut metadata_sum = 0;
+    let mut child_values = Vec::new();
+
+    for _ in 0..child_count {
+        let (sum, value) = parse_node(data);
+        metadata_sum += sum;
+        child_values.push(value);
+    }
+
+    let mut node_value = 0;
+    for _ in 0..metadata_count {
+        let entry = data.next().unwrap();
+        metadata_sum += entry;
+        if child_count == 0 {
+            node_value += entry;
+        } else if entry > 0 && entry <= child_values.len() {
+            node_value += child_values[entry - 1];
+        }
+    }
+
+    (metadata_sum, node_value)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 8;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Vec<usize>) -> impl Display {
+        parse_node(&mut data.iter().copied()).0
+    }
+
+    fn part2(data: &Vec<usize>) -> impl Display {
+        parse_node(&mut data.iter().copied()).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_license_tree() {
+        let data = Day08::parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2").unwrap();
+        assert_eq!(parse_node(&mut data.into_iter()), (138, 66));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+fn parse_game_parameters(content: &str) -> Result<(usize, usize), ParseError> {
+    let players = parse::number(content, parse::word(content, 0, "a number of players")?)?;
+    let last_marble = parse::number(content, parse::word(content, 6, "the last marble")?)?;
+    Ok((players, last_marble))
+}
+
+fn simulate_marble_game(num_players: usize, last_marble: usize) -> usize {
+    let mut circle = VecDeque::new();
+    circle.push_back(0);
+    let mut scores = vec![0; num_players];
+
+    for marble in 1..=last_marble {
+        let player = (marble - 1) % num_players;
+
+        if marble % 23 == 0 {
+            for _ in 0..7 {
+                let back = circle.pop_back().unwrap();
+                circle.push_front(back);
+            }
+            let removed = circle.pop_front().unwrap();
+            scores[player] += marble + removed;
+        } else {
+            for _ in 0..2 {
+                let front = circle.pop_front().unwrap();
+                circle.push_back(front);
+            }
+            circle.push_front(marble);
+        }
+    }
+
+    *scores.iter().max().unwrap_or(&0)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 9;
+
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<(usize, usize), ParseError> {
+        parse_game_parameters(input)
+    }
+
+    fn part1(game: &(usize, usize)) -> impl Display {
+        let &(num_players, last_marble) = game;
+        simulate_marble_game(num_players, last_marble)
+    }
+
+    fn part2(game: &(usize, usize)) -> impl Display {
+        let &(num_players, last_marble) = game;
+        simulate_marble_game(num_players, last_marble * 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(
+            parse_game_parameters("10 players; last marble is worth 1618 points"),
+            Ok((10, 1618))
+        );
+        let err = parse_game_parameters("10 players").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+
+    #[test]
+    fn test_high_scores() {
+        for (players, last_marble, score) in [
+            (9, 25, 32),
+            (10, 1618, 8317),
+            (13, 7999, 146373),
+            (17, 1104, 2764),
+            (21, 6111, 54718),
+            (30, 5807, 37305),
+        ] {
+            assert_eq!(
+                simulate_marble_game(players, last_marble),
+                score,
+                "{players} players, last marble {last_marble}"
+            );
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_common::{Grid, ParseError, Solution, ocr, parse};
+use std::cmp::{max, min};
+use std::fmt::Display;
+
+#[derive(Clone, Copy)]
+pub struct Point {
+    x: i64,
+    y: i64,
+    vx: i64,
+    vy: i64,
+}
+
+fn parse_vector(line: &str, name: &str) -> Result<(i64, i64), ParseError> {
+    let expected = format!("`{name}=<X, Y>`");
+    let (x, y) = line
+        .split_once(&format!("{name}=<"))
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(vector, _)| vector.split_once(','))
+        .ok_or_else(|| ParseError::at(line, line, expected))?;
+    Ok((
+        parse::number(line, x.trim())?,
+        parse::number(line, y.trim())?,
+    ))
+}
+
+fn parse_line(line: &str) -> Result<Point, ParseError> {
+    let (x, y) = parse_vector(line, "position")?;
+    let (vx, vy) = parse_vector(line, "velocity")?;
+    Ok(Point { x, y, vx, vy })
+}
+
+fn advance(points: &mut [Point]) {
+    for p in points.iter_mut() {
+        p.x += p.vx;
+        p.y += p.vy;
+    }
+}
+
+fn revert(points: &mut [Point]) {
+    for p in points.iter_mut() {
+        p.x -= p.vx;
+        p.y -= p.vy;
+    }
+}
+
+fn area(points: &[Point]) -> i64 {
+    let (min_x, max_x, min_y, max_y) = bounds(points);
+    (max_x - min_x) * (max_y - min_y)
+}
+
+// Determines the bounding box of the points
+fn bounds(points: &[Point]) -> (i64, i64, i64, i64) {
+    let mut min_x = i64::MAX;
+    let mut max_x = i64::MIN;
+    let mut min_y = i64::MAX;
+    let mut max_y = i64::MIN;
+    for p in points {
+        min_x = min(min_x, p.x);
+        max_x = max(max_x, p.x);
+        min_y = min(min_y, p.y);
+        max_y = max(max_y, p.y);
+    }
+    (min_x, max_x, min_y, max_y)
+}
+
+// The points as a picture just big enough to hold them, lit where a point is
+fn picture(points: &[Point]) -> Grid<bool> {
+    let (min_x, max_x, min_y, max_y) = bounds(points);
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut grid = Grid::new(width, height, false);
+    for p in points {
+        grid[((p.x - min_x) as usize, (p.y - min_y) as usize)] = true;
+    }
+    grid
+}
+
+// Moves the points until their bounding box stops shrinking, returning the
+// picture of the message they spell and the number of seconds it took
+fn align(points: &[Point]) -> (Grid<bool>, usize) {
+    let mut points = points.to_vec();
+    let mut time = 0;
+    let mut prev_area = area(&points);
+    loop {
+        advance(&mut points);
+        time += 1;
+        let curr_area = area(&points);
+        if curr_area > prev_area {
+            revert(&mut points);
+            time -= 1;
+            break;
+        }
+        prev_area = curr_area;
+    }
+
+    (picture(&points), time)
+}
+
+/// What the points spell, and how long they take to spell it.
+pub struct Message {
+    letters: String,
+    seconds: usize,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 10;
+
+    type Input = Message;
+
+    // The points only come together once, so they are moved into place and
+    // read here, and points that never spell letters are refused.
+    fn parse(input: &str) -> Result<Message, ParseError> {
+        let points = parse::lines(input, parse_line)?;
+        let nowhere = &input[input.len()..];
+        let Some(first) = points.first() else {
+            return Err(ParseError::at(input, nowhere, "a point"));
+        };
+        if points.iter().all(|p| (p.vx, p.vy) == (first.vx, first.vy)) {
+            return Err(ParseError::at(
+                input,
+                nowhere,
+                "points that move relative to each other",
+            ));
+        }
+        let (picture, seconds) = align(&points);
+        let letters = ocr::read(&picture).map_err(|err| {
+            ParseError::at(input, nowhere, format!("points that spell letters ({err})"))
+        })?;
+        Ok(Message { letters, seconds })
+    }
+
+    fn part1(message: &Message) -> impl Display {
+        message.letters.clone()
+    }
+
+    fn part2(message: &Message) -> impl Display {
+        message.seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>";
+
+    #[test]
+    fn test_message() {
+        let (message, seconds) = align(&parse::lines(EXAMPLE, parse_line).unwrap());
+        let hi = [
+            "#   #  ###",
+            "#   #   # ",
+            "#   #   # ",
+            "#####   # ",
+            "#   #   # ",
+            "#   #   # ",
+            "#   #   # ",
+            "#   #  ###",
+        ];
+        assert_eq!(
+            message.render(|&lit| if lit { '#' } else { ' ' }),
+            hi.join("\n")
+        );
+        // The example letters are smaller than the real ones
+        assert_eq!(ocr::read(&message), Err(ocr::OcrError::Height(8)));
+        assert_eq!(seconds, 3);
+        let err = Day10::parse(EXAMPLE).err().unwrap();
+        assert!(
+            err.expected
+                .starts_with("points that spell letters (no font is 8 rows")
+        );
+        assert!(Day10::parse("").is_err());
+        assert!(Day10::parse("position=<1, 2> velocity=<3, 3>").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::fmt::Display;
+
+const GRID_SIZE: usize = 300;
+
+fn power_level(x: usize, y: usize, serial: i32) -> i32 {
+    let rack_id = x as i32 + 10;
+    let mut power = rack_id * y as i32;
+    power += serial;
+    power *= rack_id;
+    (power / 100 % 10) - 5
+}
+
+fn build_summed_area_table(serial: i32) -> Vec<Vec<i32>> {
+    let mut sat = vec![vec![0; GRID_SIZE + 1]; GRID_SIZE + 1];
+    for y in 1..=GRID_SIZE {
+        for x in 1..=GRID_SIZE {
+            let pl = power_level(x, y, serial);
+            sat[y][x] = pl + sat[y - 1][x] + sat[y][x - 1] - sat[y - 1][x - 1];
+        }
+    }
+    sat
+}
+
+fn total_power(sat: &[Vec<i32>], x: usize, y: usize, size: usize) -> i32 {
+    let x1 = x - 1;
+    let y1 = y - 1;
+    let x2 = x + size - 1;
+    let y2 = y + size - 1;
+    sat[y2][x2] - sat[y1][x2] - sat[y2][x1] + sat[y1][x1]
+}
+
+fn part1(sat: &[Vec<i32>]) -> (usize, usize) {
+    let mut max_power = i32::MIN;
+    let mut coord = (0, 0);
+    for y in 1..=GRID_SIZE - 2 {
+        for x in 1..=GRID_SIZE - 2 {
+            let power = total_power(sat, x, y, 3);
+            if power > max_power {
+                max_power = power;
+                coord = (x, y);
+            }
+        }
+    }
+    coord
+}
+
+fn part2(sat: &[Vec<i32>]) -> (usize, usize, usize) {
+    let mut max_power = i32::MIN;
+    let mut result = (0, 0, 0);
+    for size in 1..=GRID_SIZE {
+        for y in 1..=GRID_SIZE - size + 1 {
+            for x in 1..=GRID_SIZE - size + 1 {
+                let power = total_power(sat, x, y, size);
+                if power > max_power {
+                    max_power = power;
+                    result = (x, y, size);
+                }
+            }
+        }
+    }
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 11;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        let serial: i32 = parse::number(input, input.trim())?;
+        Ok(build_summed_area_table(serial))
+    }
+
+    fn part1(sat: &Vec<Vec<i32>>) -> impl Display {
+        let (x, y) = part1(sat);
+        format!("{x},{y}")
+    }
+
+    fn part2(sat: &Vec<Vec<i32>>) -> impl Display {
+        let (x, y, size) = part2(sat);
+        format!("{x},{y},{size}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_level() {
+        assert_eq!(power_level(3, 5, 8), 4);
+        assert_eq!(power_level(122, 79, 57), -5);
+        assert_eq!(power_level(217, 196, 39), 0);
+        assert_eq!(power_level(101, 153, 71), 4);
+    }
+
+    #[test]
+    fn test_best_3x3() {
+        let sat = build_summed_area_table(18);
+        assert_eq!(part1(&sat), (33, 45));
+        assert_eq!(total_power(&sat, 33, 45, 3), 29);
+        assert_eq!(part1(&build_summed_area_table(42)), (21, 61));
+    }
+
+    #[test]
+    fn test_best_any_size() {
+        assert_eq!(part2(&build_summed_area_table(18)), (90, 269, 16));
+        assert_eq!(part2(&build_summed_area_table(42)), (232, 251, 12));
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_common::{ParseError, Solution, parse};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+fn parse_rule(line: &str) -> Result<(String, char), ParseError> {
+    let expected = "a rule `LLCRR => N`";
+    let (pattern, result) = line
+        .split_once(" => ")
+        .ok_or_else(|| ParseError::at(line, line, expected))?;
+    let pots = |c| c == '#' || c == '.';
+    if pattern.len() != 5 {
+        return Err(ParseError::at(line, pattern, "five pots"));
+    }
+    parse::only(line, pattern, pots, "`#` or `.`")?;
+    match parse::only(line, result, pots, "`#` or `.`")?
+        .chars()
+        .collect::<Vec<_>>()[..]
+    {
+        [pot] => Ok((pattern.to_string(), pot)),
+        _ => Err(ParseError::at(line, result, "a single pot")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(HashSet<i64>, HashMap<String, char>), ParseError> {
+    let (initial_state_line, rules_block) = input.split_once('\n').unwrap_or((input, ""));
+    let initial_state_str = initial_state_line
+        .strip_prefix("initial state: ")
+        .ok_or_else(|| ParseError::at(input, initial_state_line, "`initial state: ...`"))?;
+    let initial_state_str = parse::only(
+        input,
+        initial_state_str,
+        |c| c == '#' || c == '.',
+        "`#` or `.`",
+    )?;
+    let mut state = HashSet::new();
+    for (i, c) in initial_state_str.chars().enumerate() {
+        if c == '#' {
+            state.insert(i as i64);
+        }
+    }
+    let rules_block = rules_block.trim_start_matches('\n');
+    let skipped = input.len() - rules_block.len();
+    let rules = parse::lines(rules_block, parse_rule)
+        .map_err(|e| e.below(input[..skipped].matches('\n').count()))?;
+    Ok((state, rules.into_iter().collect()))
+}
+
+fn next_generation(state: &HashSet<i64>, rules: &HashMap<String, char>) -> HashSet<i64> {
+    let mut new_state = HashSet::new();
+    let min = *state.iter().min().unwrap();
+    let max = *state.iter().max().unwrap();
+    for i in (min - 2)..=(max + 2) {
+        let pattern: String = ((i - 2)..=(i + 2))
+            .map(|j| if state.contains(&j) { '#' } else { '.' })
+            .collect();
+        if let Some(&'#') = rules.get(&pattern) {
+            new_state.insert(i);
+        }
+    }
+    new_state
+}
+
+fn sum_of_pots(state: &HashSet<i64>) -> i64 {
+    state.iter().sum()
+}
+
+fn part1(state: &HashSet<i64>, rules: &HashMap<String, char>) -> i64 {
+    let mut current_state = state.clone();
+    for _ in 0..20 {
+        current_state = next_generation(&current_state, rules);
+    }
+    sum_of_pots(&current_state)
+}
+
+fn part2(state: &HashSet<i64>, rules: &HashMap<String, char>) -> i64 {
+    let mut current_state = state.clone();
+    let mut prev_sum = sum_of_pots(&current_state);
+    let mut prev_diff = 0;
+    let mut same_diff_count = 0;
+    let target = 50_000_000_000i64;
+    for generation in 1.. {
+        current_state = next_generation(&current_state, rules);
+        let current_sum = sum_of_pots(&current_state);
+        let diff = current_sum - prev_sum;
+        if diff == prev_diff {
+            same_diff_count += 1;
+            if same_diff_count >= 100 {
+                return current_sum + (target - generation) * diff;
+            }
+        } else {
+            same_diff_count = 0;
+            prev_diff = diff;
+        }
+        prev_sum = current_sum;
+    }
+    0
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 12;
+
+    type Input = (HashSet<i64>, HashMap<String, char>);
+
+    fn parse(input: &str) -> Result<(HashSet<i64>, HashMap<String, char>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(garden: &(HashSet<i64>, HashMap<String, char>)) -> impl Display {
+        let (state, rules) = garden;
+        part1(state, rules)
+    }
+
+    fn part2(garden: &(HashSet<i64>, HashMap<String, char>)) -> impl Display {
+        let (state, rules) = garden;
+        part2(state, rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #";
+
+    #[test]
+    fn test_generations() {
+        let (state, rules) = Day12::parse(EXAMPLE).unwrap();
+        let first = next_generation(&state, &rules);
+        let mut pots: Vec<i64> = first.into_iter().collect();
+        pots.sort();
+        // Generation 1 is #...#....#.....#..#..#..# starting at pot 0.
+        assert_eq!(pots, [0, 4, 9, 15, 18, 21, 24]);
+        assert_eq!(part1(&state, &rules), 325);
+    }
+}
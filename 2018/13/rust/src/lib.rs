@@ -1,8 +1,9 @@
+use aoc_common::{Grid, ParseError, Solution};
 use std::collections::HashMap;
-use std::fs;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -10,14 +11,14 @@ enum Direction {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Turn {
+pub enum Turn {
     Left,
     Straight,
     Right,
 }
 
 #[derive(Clone, Debug)]
-struct Cart {
+pub struct Cart {
     x: usize,
     y: usize,
     dir: Direction,
@@ -37,74 +38,43 @@ impl Cart {
     }
 }
 
-fn parse_input(path: &str) -> (Vec<Vec<char>>, Vec<Cart>) {
-    let input = fs::read_to_string(path).expect("Error reading input file");
-    let mut grid = Vec::new();
-    let mut carts = Vec::new();
+fn parse_input(input: &str) -> Result<(Grid<char>, Vec<Cart>), ParseError> {
+    let mut grid = Grid::parse_padded(
+        input,
+        ' ',
+        |c| " -|/\\+^v<>".contains(c).then_some(c),
+        "a track piece or a cart",
+    )?;
 
-    for (y, line) in input.lines().enumerate() {
-        let mut row = Vec::new();
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '^' => {
-                    carts.push(Cart::new(x, y, Direction::Up));
-                    row.push('|');
-                }
-                'v' => {
-                    carts.push(Cart::new(x, y, Direction::Down));
-                    row.push('|');
-                }
-                '<' => {
-                    carts.push(Cart::new(x, y, Direction::Left));
-                    row.push('-');
-                }
-                '>' => {
-                    carts.push(Cart::new(x, y, Direction::Right));
-                    row.push('-');
-                }
-                _ => row.push(c),
-            }
-        }
-        grid.push(row);
+    let mut carts = Vec::new();
+    for pos in grid.positions() {
+        let (dir, track) = match grid[pos] {
+            '^' => (Direction::Up, '|'),
+            'v' => (Direction::Down, '|'),
+            '<' => (Direction::Left, '-'),
+            '>' => (Direction::Right, '-'),
+            _ => continue,
+        };
+        carts.push(Cart::new(pos.0, pos.1, dir));
+        grid[pos] = track;
     }
-    (grid, carts)
+    Ok((grid, carts))
 }
 
-fn move_cart(cart: &mut Cart, grid: &Vec<Vec<char>>) {
+fn move_cart(cart: &mut Cart, grid: &Grid<char>) {
     // Move one step forward
-    match cart.dir {
-        Direction::Up => {
-            if cart.y > 0 {
-                cart.y -= 1;
-            } else {
-                panic!("Cart moved out of bounds (up)");
-            }
-        }
-        Direction::Down => {
-            if cart.y + 1 < grid.len() {
-                cart.y += 1;
-            } else {
-                panic!("Cart moved out of bounds (down)");
-            }
-        }
-        Direction::Left => {
-            if cart.x > 0 {
-                cart.x -= 1;
-            } else {
-                panic!("Cart moved out of bounds (left)");
-            }
-        }
-        Direction::Right => {
-            if cart.x + 1 < grid[0].len() {
-                cart.x += 1;
-            } else {
-                panic!("Cart moved out of bounds (right)");
-            }
-        }
-    }
+    let step = match cart.dir {
+        Direction::Up => (0, -1),
+        Direction::Down => (0, 1),
+        Direction::Left => (-1, 0),
+        Direction::Right => (1, 0),
+    };
+    (cart.x, cart.y) = grid
+        .step((cart.x, cart.y), step)
+        .unwrap_or_else(|| panic!("Cart moved out of bounds ({:?})", cart.dir));
 
     // Update direction based on track
-    let track_char = grid[cart.y][cart.x];
+    let track_char = grid[(cart.x, cart.y)];
     match track_char {
         '/' => {
             cart.dir = match cart.dir {
@@ -158,7 +128,7 @@ fn move_cart(cart: &mut Cart, grid: &Vec<Vec<char>>) {
     }
 }
 
-fn part1(initial_carts: Vec<Cart>, grid: &Vec<Vec<char>>) -> (usize, usize) {
+fn part1(initial_carts: Vec<Cart>, grid: &Grid<char>) -> (usize, usize) {
     let mut carts = initial_carts;
 
     loop {
@@ -192,7 +162,7 @@ fn part1(initial_carts: Vec<Cart>, grid: &Vec<Vec<char>>) -> (usize, usize) {
     }
 }
 
-fn part2(initial_carts: Vec<Cart>, grid: &Vec<Vec<char>>) -> (usize, usize) {
+fn part2(initial_carts: Vec<Cart>, grid: &Grid<char>) -> (usize, usize) {
     let mut carts = initial_carts;
 
     loop {
@@ -253,18 +223,29 @@ fn part2(initial_carts: Vec<Cart>, grid: &Vec<Vec<char>>) -> (usize, usize) {
     (last_cart.x, last_cart.y)
 }
 
-fn main() {
-    let path = "../input.txt";
-    let sep = "=".repeat(20);
-    let (grid, initial_carts) = parse_input(path);
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 13;
+
+    type Input = (Grid<char>, Vec<Cart>);
 
-    println!("{sep} Part 1 {sep}");
-    let (x1, y1) = part1(initial_carts.clone(), &grid);
-    println!("First collision occurs at: {x1},{y1}");
+    fn parse(input: &str) -> Result<(Grid<char>, Vec<Cart>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(tracks: &(Grid<char>, Vec<Cart>)) -> impl Display {
+        let (grid, carts) = tracks;
+        let (x, y) = part1(carts.clone(), grid);
+        format!("{x},{y}")
+    }
 
-    println!("{sep} Part 2 {sep}");
-    let (x2, y2) = part2(initial_carts, &grid);
-    println!("Last remaining cart is at: {x2},{y2}");
+    fn part2(tracks: &(Grid<char>, Vec<Cart>)) -> impl Display {
+        let (grid, carts) = tracks;
+        let (x, y) = part2(carts.clone(), grid);
+        format!("{x},{y}")
+    }
 }
 
 #[cfg(test)]
@@ -275,11 +256,7 @@ mod tests {
     fn test_cart_movement() {
         // Test basic movement
         let mut cart = Cart::new(1, 1, Direction::Right);
-        let grid = vec![
-            vec!['-', '-', '-'],
-            vec!['-', '-', '-'],
-            vec!['-', '-', '-'],
-        ];
+        let grid = Grid::new(3, 3, '-');
 
         move_cart(&mut cart, &grid);
         assert_eq!(cart.x, 2);
@@ -289,11 +266,7 @@ mod tests {
     #[test]
     fn test_intersection_turns() {
         let mut cart = Cart::new(0, 1, Direction::Right);
-        let grid = vec![
-            vec!['-', '|', '-'],
-            vec!['-', '+', '-'],
-            vec!['-', '|', '-'],
-        ];
+        let grid = Grid::parse("-|-\n-+-\n-|-", Some, "").unwrap();
 
         // Move to intersection and turn left (which should be Up from Right)
         move_cart(&mut cart, &grid);
@@ -302,4 +275,29 @@ mod tests {
         assert_eq!(cart.dir, Direction::Up);
         assert_eq!(cart.next_turn, Turn::Straight);
     }
+
+    #[test]
+    fn test_first_crash() {
+        let tracks = [
+            r"/->-\        ",
+            r"|   |  /----\",
+            r"| /-+--+-\  |",
+            r"| | |  | v  |",
+            r"\-+-/  \-+--/",
+            r"  \------/   ",
+        ]
+        .join("\n");
+        let (grid, carts) = Day13::parse(&tracks).unwrap();
+        assert_eq!(part1(carts, &grid), (7, 3));
+    }
+
+    #[test]
+    fn test_last_cart() {
+        let tracks = [
+            r"/>-<\  ", r"|   |  ", r"| /<+-\", r"| | | v", r"\>+</ |", r"  |   ^", r"  \<->/",
+        ]
+        .join("\n");
+        let (grid, carts) = Day13::parse(&tracks).unwrap();
+        assert_eq!(part2(carts, &grid), (6, 4));
+    }
 }
@@ -0,0 +1,45 @@
+use aoc_common::{ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 16;
+    const SOLVED: bool = false;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(_input: &String) -> impl Display {
+        "unsolved"
+    }
+
+    fn part2(_input: &String) -> impl Display {
+        "unsolved"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "the worked example has not been filled in"]
+    fn test_part1() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input).to_string(), "");
+    }
+
+    #[test]
+    #[ignore = "the worked example has not been filled in"]
+    fn test_part2() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input).to_string(), "");
+    }
+}
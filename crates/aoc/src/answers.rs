@@ -0,0 +1,104 @@
+//! The checked-in answers manifest, `answers.json` at the repository root.
+//!
+//! The manifest is a JSON array of `{"year", "day", "part", "answer"}`
+//! objects. Answers are compared as the strings the solutions display, so a
+//! numeric answer may be written either as a number or as a string. An
+//! answer that is not known yet, such as those of a day `aoc new` just
+//! created, is `null` and counts as pending.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input;
+use serde_json::Value;
+
+/// One expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` until the day is solved.
+    pub answer: Option<String>,
+}
+
+/// Location of the manifest in this repository.
+pub fn manifest_path() -> PathBuf {
+    manifest_path_in(input::repo_root())
+}
+
+/// Location of the manifest in the repository at `root`.
+pub fn manifest_path_in(root: &Path) -> PathBuf {
+    root.join("answers.json")
+}
+
+/// Reads and parses the manifest at `path`.
+pub fn load(path: &Path) -> Result<Vec<Expected>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parses the manifest text.
+pub fn parse(text: &str) -> Result<Vec<Expected>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    let entries = value
+        .as_array()
+        .ok_or("the manifest must be an array of answers")?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| parse_entry(entry).map_err(|e| format!("entry {i}: {e}")))
+        .collect()
+}
+
+fn parse_entry(entry: &Value) -> Result<Expected, String> {
+    let number = |key: &str| {
+        entry
+            .get(key)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| format!("missing or non-numeric `{key}`"))
+    };
+    let year = number("year")?;
+    let day = number("day")?;
+    let part = number("part")?;
+    let answer = match entry.get("answer") {
+        Some(Value::String(answer)) => Some(answer.clone()),
+        Some(Value::Number(answer)) => Some(answer.to_string()),
+        Some(Value::Null) => None,
+        _ => return Err("`answer` must be a string, a number or null".to_string()),
+    };
+    Ok(Expected {
+        year: u16::try_from(year).map_err(|_| format!("year {year} is out of range"))?,
+        day: u8::try_from(day).map_err(|_| format!("day {day} is out of range"))?,
+        part: u8::try_from(part).map_err(|_| format!("part {part} is out of range"))?,
+        answer,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_may_be_numbers_or_strings() {
+        let manifest = r#"[
+            {"year": 2015, "day": 1, "part": 1, "answer": 74},
+            {"year": 2016, "day": 8, "part": 2, "answer": "\n#..#\n####"},
+            {"year": 2018, "day": 14, "part": 1, "answer": null}
+        ]"#;
+        let expected = parse(manifest).unwrap();
+        assert_eq!(expected[0].answer.as_deref(), Some("74"));
+        assert_eq!(expected[1].answer.as_deref(), Some("\n#..#\n####"));
+        assert_eq!(expected[2].answer, None);
+        assert_eq!(
+            (expected[1].year, expected[1].day, expected[1].part),
+            (2016, 8, 2)
+        );
+    }
+
+    #[test]
+    fn malformed_entries_are_pointed_out() {
+        let err = parse(r#"[{"year": 2015, "day": 1, "answer": 3}]"#).unwrap_err();
+        assert_eq!(err, "entry 0: missing or non-numeric `part`");
+    }
+}
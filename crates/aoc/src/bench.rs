@@ -0,0 +1,222 @@
+//! Timing of the parse, part 1 and part 2 phases of a puzzle.
+//!
+//! Each sample parses the input afresh and answers both parts from it, timing
+//! the three phases separately. Warmup runs do the same work untimed.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_common::{ParseError, Puzzle};
+use serde_json::{Value, json};
+
+/// How many times to run each puzzle.
+///
+/// Warmup runs are untimed and precede the timed samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+/// Summary of the samples taken for one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Stats {
+            median,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "median_ms": millis(self.median),
+            "min_ms": millis(self.min),
+            "max_ms": millis(self.max),
+        })
+    }
+}
+
+/// The timings of one puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayReport {
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "parse": self.parse.to_json(),
+            "part1": self.part1.to_json(),
+            "part2": self.part2.to_json(),
+        })
+    }
+}
+
+/// Times `puzzle` on the raw input `raw`. `settings.samples` must be at least
+/// one.
+pub fn run(puzzle: &dyn Puzzle, raw: &str, settings: Settings) -> Result<DayReport, ParseError> {
+    // An input that does not parse is reported before any timing starts, so
+    // the samples below cannot fail.
+    puzzle.parse(raw)?;
+    for _ in 0..settings.warmup {
+        let input = puzzle.parse(raw)?;
+        black_box(puzzle.part1(input.as_ref()));
+        black_box(puzzle.part2(input.as_ref()));
+    }
+
+    let mut parse = Vec::with_capacity(settings.samples);
+    let mut part1 = Vec::with_capacity(settings.samples);
+    let mut part2 = Vec::with_capacity(settings.samples);
+    for _ in 0..settings.samples {
+        let start = Instant::now();
+        let input = black_box(puzzle.parse(raw)?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part1(input.as_ref()));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part2(input.as_ref()));
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayReport {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+/// The JSON report written by `aoc bench`.
+pub fn report(settings: Settings, days: &[DayReport]) -> Value {
+    json!({
+        "warmup": settings.warmup,
+        "samples": settings.samples,
+        "days": days.iter().map(DayReport::to_json).collect::<Vec<_>>(),
+    })
+}
+
+/// One line of the human readable summary: the median of each phase, with
+/// the spread of the samples.
+pub fn summary_line(day: &DayReport) -> String {
+    let phase = |stats: Stats| {
+        format!(
+            "{:>10} ({}..{})",
+            format_duration(stats.median),
+            format_duration(stats.min),
+            format_duration(stats.max)
+        )
+    };
+    format!(
+        "{} day {:>2}  parse {}  part 1 {}  part 2 {}",
+        day.year,
+        day.day,
+        phase(day.parse),
+        phase(day.part1),
+        phase(day.part2)
+    )
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn format_duration(duration: Duration) -> String {
+    let ms = millis(duration);
+    if ms >= 1000.0 {
+        format!("{:.2}s", ms / 1000.0)
+    } else if ms >= 1.0 {
+        format!("{ms:.2}ms")
+    } else {
+        format!("{:.1}µs", ms * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn median_of_odd_and_even_sample_counts() {
+        let odd = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            odd,
+            Stats {
+                median: ms(3),
+                min: ms(1),
+                max: ms(5)
+            }
+        );
+        let even = Stats::from_samples(&[ms(4), ms(1), ms(8), ms(2)]);
+        assert_eq!(
+            even,
+            Stats {
+                median: ms(3),
+                min: ms(1),
+                max: ms(8)
+            }
+        );
+    }
+
+    #[test]
+    fn report_lists_every_phase_in_milliseconds() {
+        let stats = Stats {
+            median: ms(2),
+            min: ms(1),
+            max: ms(4),
+        };
+        let day = DayReport {
+            year: 2017,
+            day: 15,
+            parse: stats,
+            part1: stats,
+            part2: stats,
+        };
+        let settings = Settings {
+            warmup: 1,
+            samples: 3,
+        };
+        let report = report(settings, &[day]);
+        assert_eq!(report["samples"], 3);
+        assert_eq!(report["days"][0]["day"], 15);
+        assert_eq!(report["days"][0]["part2"]["median_ms"], 2.0);
+        assert_eq!(report["days"][0]["parse"]["max_ms"], 4.0);
+    }
+
+    #[test]
+    fn durations_are_shown_in_a_readable_unit() {
+        assert_eq!(format_duration(Duration::from_micros(250)), "250.0µs");
+        assert_eq!(format_duration(ms(12)), "12.00ms");
+        assert_eq!(format_duration(ms(2500)), "2.50s");
+    }
+}
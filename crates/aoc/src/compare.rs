@@ -0,0 +1,503 @@
+//! Differential testing of the Rust solutions against the Python and Go
+//! variants kept next to them.
+//!
+//! The scripts read `../input.txt` relative to their own folder, so each one is
+//! run from a scratch copy of that folder with the input written beside it.
+//! Their output is free-form: some print `Part 1: 42`, others a banner followed
+//! by a sentence. [`normalize`] splits the output into the two parts and picks
+//! the answer out of each by where it stands, never by what Rust answered, so
+//! a script printing the right number on its way to a wrong one still
+//! disagrees.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Grid, ocr};
+
+/// A language other than Rust that a day may be solved in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Python,
+    Go,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Python, Language::Go];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Python => "python",
+            Language::Go => "go",
+        }
+    }
+
+    /// Folder names this language is kept under, misspellings included.
+    fn folders(self) -> &'static [&'static str] {
+        match self {
+            Language::Python => &["python", "pyhton"],
+            Language::Go => &["go"],
+        }
+    }
+
+    /// The command running the solution in `dir`, or `None` when the folder
+    /// has no entry point.
+    fn command(self, dir: &Path) -> Option<Command> {
+        match self {
+            Language::Python => {
+                dir.join("main.py").is_file().then_some(())?;
+                let mut command = Command::new("python3");
+                command.arg("main.py");
+                Some(command)
+            }
+            Language::Go => {
+                let mut sources: Vec<String> = fs::read_dir(dir)
+                    .ok()?
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .filter(|name| name.ends_with(".go") && !name.ends_with("_test.go"))
+                    .collect();
+                if sources.is_empty() {
+                    return None;
+                }
+                sources.sort();
+                let mut command = Command::new("go");
+                command.arg("run").args(sources);
+                Some(command)
+            }
+        }
+    }
+}
+
+/// One non-Rust solution of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub language: Language,
+    pub dir: PathBuf,
+}
+
+/// Every non-Rust variant of a day below `root`, in [`Language::ALL`] order.
+pub fn variants(root: &Path, year: u16, day: u8) -> Vec<Variant> {
+    let year_dir = root.join(year.to_string());
+    let mut day_dirs = vec![
+        year_dir.join(day.to_string()),
+        year_dir.join(format!("{day:02}")),
+    ];
+    day_dirs.dedup();
+
+    let mut found = Vec::new();
+    for language in Language::ALL {
+        for day_dir in &day_dirs {
+            for folder in language.folders() {
+                let dir = day_dir.join(folder);
+                if dir.is_dir() {
+                    found.push(Variant { language, dir });
+                }
+            }
+        }
+    }
+    found
+}
+
+/// What running a variant gave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The variant ran to completion and printed this.
+    Output(String),
+    /// The variant ran but did not succeed.
+    Failed(String),
+    /// The variant could not be started, e.g. because its toolchain is not
+    /// installed. This is not counted as a disagreement.
+    Unavailable(String),
+}
+
+/// Runs `variant` on `input`, giving up after `timeout`.
+pub fn run(variant: &Variant, year: u16, day: u8, input: &str, timeout: Duration) -> Outcome {
+    let scratch = std::env::temp_dir().join(format!(
+        "aoc-compare-{}-{year}-{day}-{}",
+        std::process::id(),
+        variant.language.name()
+    ));
+    let outcome = run_in(variant, &scratch, input, timeout);
+    let _ = fs::remove_dir_all(&scratch);
+    outcome
+}
+
+fn run_in(variant: &Variant, scratch: &Path, input: &str, timeout: Duration) -> Outcome {
+    let workdir = scratch.join(variant.language.name());
+    let prepared =
+        copy_dir(&variant.dir, &workdir).and_then(|()| fs::write(scratch.join("input.txt"), input));
+    if let Err(e) = prepared {
+        return Outcome::Failed(format!("cannot prepare {}: {e}", scratch.display()));
+    }
+    let Some(mut command) = variant.language.command(&workdir) else {
+        return Outcome::Unavailable("no entry point".to_string());
+    };
+    let child = command
+        .current_dir(&workdir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let program = command.get_program().to_string_lossy().into_owned();
+            return Outcome::Unavailable(format!("{program} is not installed"));
+        }
+        Err(e) => return Outcome::Failed(format!("cannot start: {e}")),
+    };
+
+    // Drain both pipes while waiting, so a chatty script cannot block on a
+    // full pipe and be mistaken for a slow one.
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stdout = thread::spawn(move || {
+        let mut text = String::new();
+        let _ = stdout.read_to_string(&mut text);
+        text
+    });
+    let stderr = thread::spawn(move || {
+        let mut text = String::new();
+        let _ = stderr.read_to_string(&mut text);
+        text
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < timeout => thread::sleep(Duration::from_millis(20)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Outcome::Failed(format!("timed out after {}s", timeout.as_secs()));
+            }
+            Err(e) => return Outcome::Failed(format!("cannot wait: {e}")),
+        }
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if status.success() {
+        Outcome::Output(stdout)
+    } else {
+        let reason = stderr.lines().rev().find(|line| !line.trim().is_empty());
+        Outcome::Failed(match reason {
+            Some(line) => format!("{status}: {}", line.trim()),
+            None => status.to_string(),
+        })
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Picks the answers to both parts out of a variant's `output`.
+///
+/// Lines mentioning `Part 1` or `Part 2` (as `Part 1: 42` or as a banner such
+/// as `===== Part 1 =====`) start the section of that part; output without
+/// such markers is taken to be one answer per line. Within a section the
+/// answer is the last word, since scripts often phrase it as a sentence. A
+/// section that is a picture drawn with `#` and `.` (or `█` and spaces) is
+/// read as letters, as the Rust solutions do, and kept whole when the letters
+/// are not in the font.
+pub fn normalize(output: &str) -> [Option<String>; 2] {
+    let mut sections: [Vec<&str>; 2] = [Vec::new(), Vec::new()];
+    let mut current = None;
+    let mut marked = false;
+    for line in output.lines() {
+        if let Some((part, rest)) = part_marker(line) {
+            marked = true;
+            current = Some(part);
+            if !rest.is_empty() {
+                sections[part].push(rest);
+            }
+        } else if let Some(part) = current
+            && !line.trim().is_empty()
+        {
+            sections[part].push(line.trim_end());
+        }
+    }
+    if !marked {
+        let mut lines = output
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.trim().is_empty());
+        sections[0].extend(lines.next());
+        sections[1].extend(lines);
+    }
+
+    sections.map(|section| pick(&section))
+}
+
+/// Finds `part N` in `line`, giving the part's index and whatever follows
+/// the marker once decoration is stripped.
+fn part_marker(line: &str) -> Option<(usize, &str)> {
+    let lower = line.to_ascii_lowercase();
+    let at = lower.find("part")?;
+    let after = lower[at + 4..].trim_start();
+    let part = match after.as_bytes() {
+        [b'1', rest @ ..] if !rest.first().is_some_and(u8::is_ascii_digit) => 0,
+        [b'2', rest @ ..] if !rest.first().is_some_and(u8::is_ascii_digit) => 1,
+        _ => return None,
+    };
+    let rest = &line[line.len() - after.len() + 1..];
+    let rest = rest.trim_matches(|c: char| c.is_whitespace() || "=-*:#>".contains(c));
+    Some((part, rest))
+}
+
+fn pick(section: &[&str]) -> Option<String> {
+    let drawn = |line: &&str| line.chars().all(|c| "#.█ ".contains(c));
+    if section.len() > 1 && section.iter().all(drawn) {
+        return read_picture(section).or_else(|| Some(section.join("\n")));
+    }
+    section
+        .iter()
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || ":,=()[]{}\"'".contains(c)))
+        .rfind(|word| !word.is_empty())
+        .map(|word| word.trim_end_matches('.').to_string())
+}
+
+// The letters drawn in `rows`, lit at `#` and `█`. Blank columns on the left
+// are left out, as are blank rows above and below unless the picture is
+// already as tall as a font.
+fn read_picture(rows: &[&str]) -> Option<String> {
+    let rows: Vec<Vec<bool>> = (rows.iter())
+        .map(|row| row.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+    let blank = |row: &&Vec<bool>| !row.contains(&true);
+    let mut rows: Vec<&Vec<bool>> = rows.iter().collect();
+    if ![ocr::SMALL.height, ocr::LARGE.height].contains(&rows.len()) {
+        rows.retain(|row| !blank(row));
+    }
+    let left = rows
+        .iter()
+        .filter_map(|row| row.iter().position(|&lit| lit))
+        .min()?;
+    let width = rows.iter().map(|row| row.len()).max()? - left;
+    let picture = Grid::from_fn(width, rows.len(), |(x, y)| {
+        rows[y].get(left + x).copied().unwrap_or(false)
+    });
+    ocr::read(&picture).ok()
+}
+
+/// A part on which a variant did not give the Rust answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub language: Language,
+    pub rust: String,
+    /// The variant's answer, or why it has none.
+    pub other: String,
+}
+
+/// Compares what `variant` gave with the Rust answers of its day.
+pub fn disagreements(
+    year: u16,
+    day: u8,
+    language: Language,
+    rust: &[String; 2],
+    outcome: &Outcome,
+) -> Vec<Disagreement> {
+    let answers = match outcome {
+        Outcome::Output(output) => normalize(output).map(|answer| match answer {
+            Some(answer) => answer,
+            None => "(no answer)".to_string(),
+        }),
+        Outcome::Failed(reason) => [format!("({reason})"), format!("({reason})")],
+        Outcome::Unavailable(_) => return Vec::new(),
+    };
+    (1..)
+        .zip(rust.iter().zip(answers))
+        .filter(|(_, (rust, other))| rust.trim() != other)
+        .map(|(part, (rust, other))| Disagreement {
+            year,
+            day,
+            part,
+            language,
+            rust: rust.clone(),
+            other,
+        })
+        .collect()
+}
+
+/// Lays out `rows` as an aligned text table with a header.
+pub fn table(rows: &[Disagreement]) -> String {
+    let header = ["year", "day", "part", "language", "rust", "other"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.to_string(),
+                row.language.name().to_string(),
+                one_line(&row.rust),
+                one_line(&row.other),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    let header = header.map(str::to_string);
+    for row in std::iter::once(&header).chain(&cells) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        let _ = writeln!(out, "{}", line.join("  ").trim_end());
+    }
+    out
+}
+
+/// Multi-line answers, such as letters drawn on a screen, are shown on one
+/// line with `/` between the rows.
+fn one_line(answer: &str) -> String {
+    answer.trim().lines().collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(part1: &str, part2: &str) -> [String; 2] {
+        [part1.to_string(), part2.to_string()]
+    }
+
+    #[test]
+    fn labelled_answers() {
+        let output = "Part 1: 138\nPart 2: 1771\n";
+        assert_eq!(
+            normalize(output),
+            [Some("138".to_string()), Some("1771".to_string())]
+        );
+    }
+
+    #[test]
+    fn banners_and_sentences() {
+        let output = "==================== Part 1 ====================\n\
+                      Total raw length: 6195\n\
+                      Total parsed length: 4845\n\
+                      ==================== Part 2 ====================\n\
+                      The first house is house 665280\n";
+        assert_eq!(
+            normalize(output),
+            [Some("4845".to_string()), Some("665280".to_string())]
+        );
+    }
+
+    #[test]
+    fn bare_lines() {
+        assert_eq!(
+            normalize("12\n\n34\n"),
+            [Some("12".to_string()), Some("34".to_string())]
+        );
+        assert_eq!(normalize(""), [None, None]);
+        let drawn = "Part 1: 6\nPart 2:\n#..#\n####\n";
+        assert_eq!(normalize(drawn)[1].as_deref(), Some("#..#\n####"));
+    }
+
+    #[test]
+    fn pictures_are_read_as_letters() {
+        let he = [
+            "#..#.####",
+            "#..#.#...",
+            "####.###.",
+            "#..#.#...",
+            "#..#.#...",
+            "#..#.####",
+        ];
+        let output = format!("Part 1: 6\nPart 2:\n{}\n", he.join("\n"));
+        assert_eq!(normalize(&output)[1].as_deref(), Some("HE"));
+
+        // Drawn with blocks and spaces, indented, with a blank line around
+        let blocks: Vec<String> = (he.iter())
+            .map(|row| format!("  {}", row.replace('#', "█").replace('.', " ")))
+            .collect();
+        let output = format!("Part 2:\n\n{}\n\n", blocks.join("\n"));
+        assert_eq!(normalize(&output)[1].as_deref(), Some("HE"));
+    }
+
+    #[test]
+    fn the_rust_answer_in_passing_is_not_agreement() {
+        let rust = expected("4845", "7");
+        let output = Outcome::Output(
+            "Part 1\nexample: 4845\nanswer: 4846\nPart 2: debug 7, answer 8\n".to_string(),
+        );
+        let rows = disagreements(2015, 8, Language::Python, &rust, &output);
+        let others: Vec<_> = rows
+            .iter()
+            .map(|row| (row.part, row.other.as_str()))
+            .collect();
+        assert_eq!(others, [(1, "4846"), (2, "8")]);
+    }
+
+    #[test]
+    fn part_numbers_are_not_confused_with_answers() {
+        assert_eq!(part_marker("part 12"), None);
+        assert_eq!(part_marker("Part2 => 7"), Some((1, "7")));
+        assert_eq!(part_marker("part 1:  42"), Some((0, "42")));
+    }
+
+    #[test]
+    fn unavailable_variants_do_not_disagree() {
+        let rust = expected("1", "2");
+        let missing = Outcome::Unavailable("go is not installed".to_string());
+        assert!(disagreements(2015, 1, Language::Go, &rust, &missing).is_empty());
+
+        let wrong = Outcome::Output("Part 1: 1\nPart 2: 3\n".to_string());
+        let rows = disagreements(2015, 1, Language::Python, &rust, &wrong);
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].part, rows[0].other.as_str()), (2, "3"));
+    }
+
+    #[test]
+    fn table_columns_line_up() {
+        let rows = [Disagreement {
+            year: 2015,
+            day: 8,
+            part: 1,
+            language: Language::Python,
+            rust: "1350".to_string(),
+            other: "(timed out after 60s)".to_string(),
+        }];
+        assert_eq!(
+            table(&rows),
+            "year  day  part  language  rust  other\n\
+             2015  8    1     python    1350  (timed out after 60s)\n"
+        );
+    }
+
+    #[test]
+    fn misspelled_folders_are_found() {
+        let root = aoc_common::input::repo_root();
+        let found = variants(root, 2015, 2);
+        let languages: Vec<_> = found.iter().map(|v| v.language).collect();
+        assert_eq!(languages, [Language::Python, Language::Go]);
+        assert!(found[0].dir.ends_with("2015/2/pyhton"));
+    }
+}
@@ -0,0 +1,366 @@
+//! `aoc debug`: running one of the register-machine days under the
+//! [`Debugger`], driven by commands read line by line, or running copies of
+//! its program as a [`Network`](aoc_vm::network::Network) and showing how
+//! that went.
+
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::PathBuf;
+
+use aoc_common::Solution;
+use aoc_common::input::RawInput;
+use aoc_vm::debug::{Breakpoint, Debugger};
+use aoc_vm::network::Topology;
+use aoc_vm::{Instruction, Machine, Reg, Word};
+
+/// How a session starts.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Registers set before the first instruction.
+    pub registers: Vec<(Reg, Word)>,
+    pub breakpoints: Vec<Breakpoint>,
+    pub watches: Vec<Reg>,
+    /// How many executed instructions `history` shows.
+    pub history: usize,
+    /// Where to write the trace of every instruction carried out.
+    pub trace: Option<PathBuf>,
+}
+
+type Simulation = fn(&RawInput, usize, Topology) -> Result<String, String>;
+
+/// The days whose programs talk to each other.
+const NETWORKS: &[(u16, u8, Simulation)] = &[(2017, 18, duet)];
+
+fn duet(input: &RawInput, count: usize, topology: Topology) -> Result<String, String> {
+    let program = aoc_2017_day_18_rust::Day18::parse(&input.text)
+        .map_err(|e| e.in_file(input.origin.clone()).to_string())?;
+    let report = aoc_2017_day_18_rust::simulate(&program, count, topology, aoc_vm::max_steps())?;
+    Ok(report.to_string())
+}
+
+/// Runs `count` copies of the program of a day connected by `topology` and
+/// writes out how each of them ended.
+pub fn network(
+    year: u16,
+    day: u8,
+    input: &RawInput,
+    count: usize,
+    topology: Topology,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    let &(_, _, simulation) = NETWORKS
+        .iter()
+        .find(|&&(y, d, _)| (y, d) == (year, day))
+        .ok_or_else(|| format!("{year} day {day} does not run as a network"))?;
+    let report = simulation(input, count, topology)?;
+    write!(out, "{report}").map_err(|e| e.to_string())
+}
+
+type Session = fn(&RawInput, &Options, &mut dyn BufRead, &mut dyn Write) -> Result<(), String>;
+
+/// The days that run on the register machine.
+const DAYS: &[(u16, u8, Session)] = &[
+    (2015, 23, session::<aoc_2015_day_23_rust::Day23, _>),
+    (2016, 12, session::<aoc_2016_day_12_rust::Day12, _>),
+    (2016, 23, session::<aoc_2016_day_23_rust::Day23, _>),
+    (2016, 25, session::<aoc_2016_day_25_rust::Day25, _>),
+    (2017, 18, session::<aoc_2017_day_18_rust::Day18, _>),
+    (2017, 23, session::<aoc_2017_day_23_rust::Day23, _>),
+];
+
+/// Whether a day can be debugged.
+pub fn supports(year: u16, day: u8) -> bool {
+    DAYS.iter().any(|&(y, d, _)| (y, d) == (year, day))
+}
+
+/// Parses `input` as the program of a day and debugs it, reading commands
+/// from `commands` until `quit` or the end of the input.
+pub fn start(
+    year: u16,
+    day: u8,
+    input: &RawInput,
+    options: &Options,
+    commands: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    let &(_, _, session) = DAYS
+        .iter()
+        .find(|&&(y, d, _)| (y, d) == (year, day))
+        .ok_or_else(|| format!("{year} day {day} does not run on the register machine"))?;
+    session(input, options, commands, out)
+}
+
+fn session<S, I>(
+    input: &RawInput,
+    options: &Options,
+    commands: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> Result<(), String>
+where
+    S: Solution<Input = Vec<I>>,
+    I: Instruction + Display,
+{
+    let program = S::parse(&input.text).map_err(|e| e.in_file(input.origin.clone()).to_string())?;
+    let mut machine = Machine::new(program);
+    for &(reg, value) in &options.registers {
+        machine.registers[reg] = value;
+    }
+    let mut debugger = Debugger::new(machine, options.history);
+    debugger.breakpoints = options.breakpoints.clone();
+    debugger.watches = options.watches.clone();
+    if let Some(path) = &options.trace {
+        let file = File::create(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        debugger.trace_to(BufWriter::new(file));
+    }
+    repl(&mut debugger, commands, out).map_err(|e| e.to_string())?;
+    debugger.flush().map_err(|e| e.to_string())
+}
+
+/// A command typed at the prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Carry out this many instructions, unless something stops the run
+    /// first.
+    Step(u64),
+    Continue,
+    Registers,
+    History,
+    Break(Breakpoint),
+    Watch(Reg),
+    /// Forget every breakpoint and watchpoint.
+    Clear,
+    Help,
+    Quit,
+}
+
+const HELP: &str = "\
+step [N]      carry out the next N instructions (1 by default; an empty line too)
+continue      run until a breakpoint, a watchpoint, input or the end
+regs          show the program counter and the registers
+history       show the last instructions carried out
+break PC      stop before the instruction at PC
+break OP      stop before any OP instruction, e.g. `break jnz`
+watch R       stop when register R changes
+clear         remove every breakpoint and watchpoint
+quit          stop debugging";
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Command, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let command = match words.as_slice() {
+            [] | ["s" | "step"] => Command::Step(1),
+            ["s" | "step", n] => {
+                Command::Step(n.parse().map_err(|_| format!("`{n}` is not a count"))?)
+            }
+            ["c" | "continue"] => Command::Continue,
+            ["r" | "regs"] => Command::Registers,
+            ["h" | "history"] => Command::History,
+            ["b" | "break", at] => Command::Break(breakpoint(at)?),
+            ["w" | "watch", reg] => Command::Watch(register(reg)?),
+            ["clear"] => Command::Clear,
+            ["?" | "help"] => Command::Help,
+            ["q" | "quit"] => Command::Quit,
+            _ => return Err(format!("unknown command `{}`; try `help`", line.trim())),
+        };
+        Ok(command)
+    }
+}
+
+/// A breakpoint written as a program counter or a mnemonic.
+pub fn breakpoint(text: &str) -> Result<Breakpoint, String> {
+    match text.parse() {
+        Ok(pc) => Ok(Breakpoint::Pc(pc)),
+        Err(_) if text.chars().all(|c| c.is_ascii_lowercase()) => {
+            Ok(Breakpoint::Mnemonic(text.to_string()))
+        }
+        Err(_) => Err(format!(
+            "`{text}` is neither a program counter nor an instruction"
+        )),
+    }
+}
+
+/// A register written as its letter.
+pub fn register(text: &str) -> Result<Reg, String> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_lowercase() => Ok(Reg::new(c)),
+        _ => Err(format!("`{text}` is not a register `a` to `z`")),
+    }
+}
+
+/// A topology written as `ring`, `pairwise` or `broadcast`.
+pub fn topology(text: &str) -> Result<Topology, String> {
+    match text {
+        "ring" => Ok(Topology::Ring),
+        "pairwise" => Ok(Topology::Pairwise),
+        "broadcast" => Ok(Topology::Broadcast),
+        _ => Err(format!(
+            "`{text}` is not a topology; use ring, pairwise or broadcast"
+        )),
+    }
+}
+
+/// `R=VALUE`, a register and the value to start it at.
+pub fn assignment(text: &str) -> Result<(Reg, Word), String> {
+    let (reg, value) = text
+        .split_once('=')
+        .ok_or_else(|| format!("`{text}` is not of the form R=VALUE"))?;
+    let value = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+    Ok((register(reg)?, value))
+}
+
+// Where the program stands: the next instruction, or that there is none.
+fn location<I: Instruction + Display>(debugger: &Debugger<I>) -> String {
+    match debugger.machine.current() {
+        Some(instruction) => format!("{}: {instruction}", debugger.machine.pc),
+        None => format!("{}: halted", debugger.machine.pc),
+    }
+}
+
+fn repl<I: Instruction + Display>(
+    debugger: &mut Debugger<I>,
+    commands: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    writeln!(out, "{}", location(debugger))?;
+    let mut line = String::new();
+    loop {
+        write!(out, "(debug) ")?;
+        out.flush()?;
+        line.clear();
+        if commands.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+        let command = match Command::parse(&line) {
+            Ok(command) => command,
+            Err(e) => {
+                writeln!(out, "{e}")?;
+                continue;
+            }
+        };
+        match command {
+            Command::Step(n) => {
+                for _ in 0..n {
+                    if let Some(stop) = debugger.step()? {
+                        writeln!(out, "{stop}")?;
+                        break;
+                    }
+                }
+                writeln!(out, "{}", location(debugger))?;
+            }
+            Command::Continue => {
+                let stop = debugger.run()?;
+                writeln!(out, "{stop}\n{}", location(debugger))?;
+            }
+            Command::Registers => writeln!(
+                out,
+                "{} ({} instructions carried out)",
+                debugger.registers(),
+                debugger.machine.executed()
+            )?,
+            Command::History => {
+                for executed in debugger.history() {
+                    writeln!(out, "{executed}")?;
+                }
+            }
+            Command::Break(breakpoint) => debugger.breakpoints.push(breakpoint),
+            Command::Watch(reg) => debugger.watches.push(reg),
+            Command::Clear => {
+                debugger.breakpoints.clear();
+                debugger.watches.clear();
+            }
+            Command::Help => writeln!(out, "{HELP}")?,
+            Command::Quit => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands() {
+        assert_eq!(Command::parse(""), Ok(Command::Step(1)));
+        assert_eq!(Command::parse("step 20"), Ok(Command::Step(20)));
+        assert_eq!(
+            Command::parse("b 12"),
+            Ok(Command::Break(Breakpoint::Pc(12)))
+        );
+        assert_eq!(
+            Command::parse("break out"),
+            Ok(Command::Break(Breakpoint::Mnemonic("out".to_string())))
+        );
+        assert_eq!(Command::parse("w c"), Ok(Command::Watch(Reg::new('c'))));
+        assert!(Command::parse("watch ab").is_err());
+        assert!(Command::parse("jump").is_err());
+        assert_eq!(assignment("a=-7"), Ok((Reg::new('a'), -7)));
+        assert!(assignment("a:7").is_err());
+    }
+
+    #[test]
+    fn session_runs_commands() {
+        let input = RawInput {
+            origin: "<input-str>".to_string(),
+            text: "cpy 2 a\ninc b\ndec a\njnz a -2\nout b".to_string(),
+        };
+        let options = Options {
+            registers: vec![(Reg::new('d'), 5)],
+            breakpoints: vec![Breakpoint::Pc(3)],
+            history: 2,
+            ..Options::default()
+        };
+        let mut commands = "c\nregs\nc\nhistory\nquit\nstep\n".as_bytes();
+        let mut out = Vec::new();
+        start(2016, 25, &input, &options, &mut commands, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.split("(debug) ").collect();
+        assert_eq!(
+            lines,
+            [
+                "0: cpy 2 a\n",
+                "breakpoint on pc 3\n3: jnz a -2\n",
+                "pc=3 a=1 b=1 (3 instructions carried out)\n",
+                "breakpoint on pc 3\n3: jnz a -2\n",
+                "#5 at 1: inc b\n#6 at 2: dec a\n",
+                "",
+            ]
+        );
+        assert!(
+            start(
+                2016,
+                1,
+                &input,
+                &options,
+                &mut "".as_bytes(),
+                &mut Vec::new()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn networks_report_how_they_ended() {
+        let input = RawInput {
+            origin: "<input-str>".to_string(),
+            text: "snd p\nrcv a\nrcv b".to_string(),
+        };
+        let mut out = Vec::new();
+        network(2017, 18, &input, 3, topology("ring").unwrap(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "deadlocked after 6 steps\n\
+             program 0: sent 1, received 1, waiting on `rcv b` at 2\n\
+             program 1: sent 1, received 1, waiting on `rcv b` at 2\n\
+             program 2: sent 1, received 1, waiting on `rcv b` at 2\n"
+        );
+        let pairs = topology("pairwise").unwrap();
+        assert!(network(2017, 18, &input, 3, pairs, &mut Vec::new()).is_err());
+        assert!(network(2016, 25, &input, 2, pairs, &mut Vec::new()).is_err());
+        assert!(topology("star").is_err());
+    }
+}
@@ -0,0 +1,45 @@
+//! Days that give up. A part that cannot find its answer, say because its
+//! program ran out of steps or its letters could not be read, panics with the
+//! reason; the runner reports that for the day and goes on with the next.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs `f`, turning a panic into its message. The panic is not printed as
+/// well, since the caller reports it.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.map_err(message)
+}
+
+fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "gave up without saying why".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_become_their_message() {
+        assert_eq!(catch(|| 6 * 7), Ok(42));
+        let steps = 1000;
+        assert_eq!(
+            catch(|| -> u8 { panic!("no output in {steps} steps") }),
+            Err("no output in 1000 steps".to_string())
+        );
+        assert_eq!(
+            catch(|| -> u8 { panic!("stuck") }),
+            Err("stuck".to_string())
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! The puzzles known to the `aoc` runner, the answers they are expected to
+//! give, how long they take, whether the other languages agree, how new days
+//! are added, how the register-machine days are debugged, and what happens
+//! when a day gives up.
+
+pub mod answers;
+pub mod bench;
+pub mod compare;
+pub mod debug;
+pub mod failure;
+pub mod output;
+pub mod registry;
+pub mod scaffold;
@@ -0,0 +1,454 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::Puzzle;
+use aoc_common::input::{self, InputError, Source};
+use aoc_vm::debug::Breakpoint;
+use aoc_vm::network::Topology;
+use aoc_vm::{Reg, Word};
+use clap::{Args, Parser, Subcommand};
+
+use aoc::bench::{self, Settings};
+use aoc::compare;
+use aoc::debug::{self, Options};
+use aoc::failure;
+use aoc::output::{Answer, Format};
+use aoc::registry::DAYS;
+use aoc::scaffold;
+
+/// Runs the Advent of Code solutions in this repository.
+#[derive(Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(flatten)]
+    select: Select,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Steps a register-machine program may take without producing what its
+    /// day waits for, such as its next output, or that programs run together
+    /// may take between them, before the day gives up
+    #[arg(long, value_name = "N", global = true)]
+    max_steps: Option<u64>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time parse, part 1 and part 2 of the selected days and write a JSON
+    /// report
+    Bench {
+        #[command(flatten)]
+        select: Select,
+        /// Untimed runs of each day before sampling
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+        /// Timed runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        samples: u32,
+        /// Where to write the JSON report
+        #[arg(long, value_name = "PATH", default_value = "bench.json")]
+        report: PathBuf,
+    },
+    /// Run the Python and Go solutions of the selected days on the same input
+    /// and list where they disagree with Rust
+    Compare {
+        #[command(flatten)]
+        select: Select,
+        /// Seconds a single solution may run before it is stopped
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Step through the program of a register-machine day, reading commands
+    /// from stdin (`help` lists them)
+    Debug {
+        #[command(flatten)]
+        select: Select,
+        /// Start register R at VALUE; may be repeated
+        #[arg(long = "set", value_name = "R=VALUE", value_parser = debug::assignment)]
+        registers: Vec<(Reg, Word)>,
+        /// Stop before the instruction at PC, or before any OP instruction;
+        /// may be repeated
+        #[arg(long = "break", value_name = "PC|OP", value_parser = debug::breakpoint)]
+        breakpoints: Vec<Breakpoint>,
+        /// Stop when register R changes; may be repeated
+        #[arg(long = "watch", value_name = "R", value_parser = debug::register)]
+        watches: Vec<Reg>,
+        /// How many executed instructions `history` shows
+        #[arg(long, value_name = "N", default_value_t = 20)]
+        history: usize,
+        /// Write every instruction carried out, with the registers after it,
+        /// to this file
+        #[arg(long, value_name = "PATH")]
+        trace: Option<PathBuf>,
+        /// Instead of stepping through one program, run copies of it that
+        /// send to each other as TOPOLOGY says (ring, pairwise or broadcast)
+        /// and show how each ended
+        #[arg(long, value_name = "TOPOLOGY", value_parser = debug::topology)]
+        network: Option<Topology>,
+        /// How many copies `--network` runs
+        #[arg(long, value_name = "N", default_value_t = 2, requires = "network")]
+        programs: usize,
+    },
+    /// Create the crate of a new day and register it with the workspace, the
+    /// runner and answers.json
+    New {
+        /// Puzzle year, e.g. 2018
+        year: u16,
+        /// Puzzle day, e.g. 14
+        day: u8,
+    },
+}
+
+/// Which puzzles to run, and where their input comes from.
+#[derive(Args)]
+struct Select {
+    /// Puzzle year, e.g. 2016
+    year: Option<u16>,
+    /// Puzzle day, e.g. 12
+    day: Option<u8>,
+    /// Run every day of the given year, or of every year when no year is given
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Read the input from this file, or from stdin when given `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<String>,
+    /// Use this text as the input
+    #[arg(long, value_name = "TEXT", conflicts_with_all = ["all", "input"])]
+    input_str: Option<String>,
+}
+
+impl Select {
+    fn source(&self) -> Source {
+        match (&self.input, &self.input_str) {
+            (Some(path), _) => Source::from_arg(path),
+            (None, Some(text)) => Source::Inline(text.clone()),
+            (None, None) => Source::Default,
+        }
+    }
+
+    fn puzzles(&self) -> Result<Vec<&'static dyn Puzzle>, &'static str> {
+        let selected: Vec<&dyn Puzzle> = match (self.year, self.day, self.all) {
+            (Some(year), Some(day), false) => DAYS
+                .iter()
+                .copied()
+                .filter(|p| p.year() == year && p.day() == day)
+                .collect(),
+            (Some(year), None, true) => DAYS.iter().copied().filter(|p| p.year() == year).collect(),
+            (None, None, true) => DAYS.to_vec(),
+            _ => {
+                return Err(
+                    "usage: aoc [bench|compare] <YEAR> <DAY> | aoc [bench|compare] <YEAR> --all | aoc [bench|compare] --all",
+                );
+            }
+        };
+        if selected.is_empty() {
+            return Err("no solution found for the requested puzzle");
+        }
+        let (solved, stubs): (Vec<_>, Vec<_>) = selected.into_iter().partition(|p| p.solved());
+        for stub in stubs {
+            eprintln!(
+                "{} day {}: skipped, not solved yet",
+                stub.year(),
+                stub.day()
+            );
+        }
+        if solved.is_empty() {
+            return Err("the requested puzzle is not solved yet");
+        }
+        Ok(solved)
+    }
+}
+
+fn run_day(puzzle: &dyn Puzzle, source: &Source, format: Format) -> Result<(), Box<dyn Error>> {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    if let Some(header) = format.header(year, day) {
+        println!("{header}");
+    }
+    let raw = input::load(year, day, source)?;
+    let input = puzzle.parse(&raw.text).map_err(|e| e.in_file(raw.origin))?;
+    for part in [1, 2] {
+        let start = Instant::now();
+        let answer = failure::catch(|| match part {
+            1 => puzzle.part1(input.as_ref()),
+            _ => puzzle.part2(input.as_ref()),
+        })
+        .map_err(|reason| format!("part {part}: {reason}"))?;
+        let elapsed = start.elapsed();
+        let answer = Answer {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+        };
+        println!("{}", format.answer(&answer));
+    }
+    Ok(())
+}
+
+fn run(select: &Select, format: Format) -> ExitCode {
+    let puzzles = match select.puzzles() {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = select.source();
+    let mut failed = false;
+    for puzzle in puzzles {
+        if let Err(e) = run_day(puzzle, &source, format) {
+            eprintln!("{} day {}: {e}", puzzle.year(), puzzle.day());
+            failed = true;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_bench(select: &Select, settings: Settings, report: &PathBuf) -> ExitCode {
+    let puzzles = match select.puzzles() {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = select.source();
+    let mut days = Vec::new();
+    let mut failed = false;
+    for puzzle in puzzles {
+        let (year, day) = (puzzle.year(), puzzle.day());
+        match input::load(year, day, &source) {
+            Ok(raw) => match failure::catch(|| bench::run(puzzle, &raw.text, settings)) {
+                Ok(Ok(timings)) => {
+                    println!("{}", bench::summary_line(&timings));
+                    days.push(timings);
+                }
+                Ok(Err(e)) => {
+                    eprintln!("{year} day {day}: {}", e.in_file(raw.origin));
+                    failed = true;
+                }
+                Err(reason) => {
+                    eprintln!("{year} day {day}: {reason}");
+                    failed = true;
+                }
+            },
+            Err(InputError::NotFound { .. }) => eprintln!("{year} day {day}: skipped, no input"),
+            Err(e) => {
+                eprintln!("{year} day {day}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    let json = bench::report(settings, &days);
+    let text = serde_json::to_string_pretty(&json).expect("the report is valid JSON");
+    if let Err(e) = fs::write(report, text + "\n") {
+        eprintln!("cannot write {}: {e}", report.display());
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {} ({} days)", report.display(), days.len());
+    if failed || days.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_compare(select: &Select, timeout: Duration) -> ExitCode {
+    let puzzles = match select.puzzles() {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = select.source();
+    let mut rows = Vec::new();
+    let mut failed = false;
+    for puzzle in puzzles {
+        let (year, day) = (puzzle.year(), puzzle.day());
+        let variants = compare::variants(input::repo_root(), year, day);
+        if variants.is_empty() {
+            continue;
+        }
+        let raw = match input::load(year, day, &source) {
+            Ok(raw) => raw,
+            Err(InputError::NotFound { .. }) => {
+                eprintln!("{year} day {day}: skipped, no input");
+                continue;
+            }
+            Err(e) => {
+                eprintln!("{year} day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+        let rust = match puzzle.parse(&raw.text) {
+            Ok(input) => match failure::catch(|| {
+                [puzzle.part1(input.as_ref()), puzzle.part2(input.as_ref())]
+            }) {
+                Ok(answers) => answers,
+                Err(reason) => {
+                    eprintln!("{year} day {day}: {reason}");
+                    failed = true;
+                    continue;
+                }
+            },
+            Err(e) => {
+                eprintln!("{year} day {day}: {}", e.in_file(raw.origin));
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut verdicts = Vec::new();
+        for variant in &variants {
+            let language = variant.language;
+            let outcome = compare::run(variant, year, day, &raw.text, timeout);
+            let found = compare::disagreements(year, day, language, &rust, &outcome);
+            verdicts.push(match (&outcome, found.len()) {
+                (compare::Outcome::Unavailable(why), _) => {
+                    format!("{} skipped, {why}", language.name())
+                }
+                (_, 0) => format!("{} agrees", language.name()),
+                _ => format!("{} disagrees", language.name()),
+            });
+            rows.extend(found);
+        }
+        println!("{year} day {day}: {}", verdicts.join(", "));
+    }
+
+    if rows.is_empty() {
+        println!("no disagreements");
+    } else {
+        print!("\n{}", compare::table(&rows));
+    }
+    if failed || !rows.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_debug(select: &Select, options: &Options, network: Option<(Topology, usize)>) -> ExitCode {
+    let (Some(year), Some(day), false) = (select.year, select.day, select.all) else {
+        eprintln!("usage: aoc debug <YEAR> <DAY>");
+        return ExitCode::FAILURE;
+    };
+    if network.is_none() && !debug::supports(year, day) {
+        eprintln!("{year} day {day} does not run on the register machine");
+        return ExitCode::FAILURE;
+    }
+    let source = select.source();
+    if network.is_none() && source == Source::Stdin {
+        eprintln!("the debugger reads its commands from stdin; pass the input as a file");
+        return ExitCode::FAILURE;
+    }
+    let raw = match input::load(year, day, &source) {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (stdin, stdout) = (io::stdin(), io::stdout());
+    let result = match network {
+        Some((topology, count)) => {
+            debug::network(year, day, &raw, count, topology, &mut stdout.lock())
+        }
+        None => debug::start(
+            year,
+            day,
+            &raw,
+            options,
+            &mut stdin.lock(),
+            &mut stdout.lock(),
+        ),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{year} day {day}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_new(year: u16, day: u8) -> ExitCode {
+    match scaffold::create(input::repo_root(), year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("cannot create {year} day {day}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(steps) = cli.max_steps {
+        aoc_vm::set_max_steps(steps);
+    }
+    match &cli.command {
+        None => run(&cli.select, cli.format),
+        Some(Command::Bench {
+            select,
+            warmup,
+            samples,
+            report,
+        }) => {
+            let settings = Settings {
+                warmup: *warmup as usize,
+                samples: *samples as usize,
+            };
+            run_bench(select, settings, report)
+        }
+        Some(Command::Compare { select, timeout }) => {
+            run_compare(select, Duration::from_secs(*timeout))
+        }
+        Some(Command::Debug {
+            select,
+            registers,
+            breakpoints,
+            watches,
+            history,
+            trace,
+            network,
+            programs,
+        }) => {
+            let options = Options {
+                registers: registers.clone(),
+                breakpoints: breakpoints.clone(),
+                watches: watches.clone(),
+                history: *history,
+                trace: trace.clone(),
+            };
+            run_debug(
+                select,
+                &options,
+                network.map(|topology| (topology, *programs)),
+            )
+        }
+        Some(Command::New { year, day }) => run_new(*year, *day),
+    }
+}
@@ -0,0 +1,97 @@
+//! How the runner prints answers.
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde_json::json;
+
+/// Output style of `aoc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// A banner per day followed by `Part 1: ...` and `Part 2: ...`
+    #[default]
+    Pretty,
+    /// The bare answers, one per line
+    Plain,
+    /// One JSON object per answer and line
+    Json,
+}
+
+/// One answer given by a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Time taken by the part itself, not counting the parse.
+    pub elapsed: Duration,
+}
+
+impl Format {
+    /// The line printed before the answers of a day, if any.
+    pub fn header(self, year: u16, day: u8) -> Option<String> {
+        match self {
+            Format::Pretty => Some(format!("{} Day {day} {year}", "#".repeat(10))),
+            Format::Plain | Format::Json => None,
+        }
+    }
+
+    /// Renders one answer.
+    pub fn answer(self, answer: &Answer) -> String {
+        match self {
+            Format::Pretty => format!("Part {}: {}", answer.part, answer.answer),
+            Format::Plain => answer.answer.clone(),
+            Format::Json => json!({
+                "year": answer.year,
+                "day": answer.day,
+                "part": answer.part,
+                "answer": answer.answer,
+                "elapsed_ms": answer.elapsed.as_secs_f64() * 1000.0,
+            })
+            .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer() -> Answer {
+        Answer {
+            year: 2018,
+            day: 9,
+            part: 2,
+            answer: "3212830280".to_string(),
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn pretty_keeps_the_banner() {
+        assert_eq!(
+            Format::Pretty.header(2018, 9).unwrap(),
+            "########## Day 9 2018"
+        );
+        assert_eq!(Format::Pretty.answer(&answer()), "Part 2: 3212830280");
+    }
+
+    #[test]
+    fn plain_is_the_bare_answer() {
+        assert_eq!(Format::Plain.header(2018, 9), None);
+        assert_eq!(Format::Plain.answer(&answer()), "3212830280");
+    }
+
+    #[test]
+    fn json_is_one_object_per_line() {
+        let line = Format::Json.answer(&answer());
+        assert!(!line.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["year"], 2018);
+        assert_eq!(value["day"], 9);
+        assert_eq!(value["part"], 2);
+        assert_eq!(value["answer"], "3212830280");
+        assert_eq!(value["elapsed_ms"], 1.5);
+    }
+}
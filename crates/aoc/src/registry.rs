@@ -0,0 +1,105 @@
+use aoc_common::{Puzzle, puzzle};
+
+/// Every solved puzzle, ordered by year and day.
+pub const DAYS: &[&dyn Puzzle] = &[
+    &puzzle::<aoc_2015_day_01_rust::Day01>(),
+    &puzzle::<aoc_2015_day_02_rust::Day02>(),
+    &puzzle::<aoc_2015_day_03_rust::Day03>(),
+    &puzzle::<aoc_2015_day_04_rust::Day04>(),
+    &puzzle::<aoc_2015_day_05_rust::Day05>(),
+    &puzzle::<aoc_2015_day_06_rust::Day06>(),
+    &puzzle::<aoc_2015_day_07_rust::Day07>(),
+    &puzzle::<aoc_2015_day_08_rust::Day08>(),
+    &puzzle::<aoc_2015_day_09_rust::Day09>(),
+    &puzzle::<aoc_2015_day_10_rust::Day10>(),
+    &puzzle::<aoc_2015_day_11_rust::Day11>(),
+    &puzzle::<aoc_2015_day_12_rust::Day12>(),
+    &puzzle::<aoc_2015_day_13_rust::Day13>(),
+    &puzzle::<aoc_2015_day_14_rust::Day14>(),
+    &puzzle::<aoc_2015_day_15_rust::Day15>(),
+    &puzzle::<aoc_2015_day_16_rust::Day16>(),
+    &puzzle::<aoc_2015_day_17_rust::Day17>(),
+    &puzzle::<aoc_2015_day_18_rust::Day18>(),
+    &puzzle::<aoc_2015_day_19_rust::Day19>(),
+    &puzzle::<aoc_2015_day_20_rust::Day20>(),
+    &puzzle::<aoc_2015_day_21_rust::Day21>(),
+    &puzzle::<aoc_2015_day_22_rust::Day22>(),
+    &puzzle::<aoc_2015_day_23_rust::Day23>(),
+    &puzzle::<aoc_2015_day_24_rust::Day24>(),
+    &puzzle::<aoc_2015_day_25_rust::Day25>(),
+    &puzzle::<aoc_2016_day_01_rust::Day01>(),
+    &puzzle::<aoc_2016_day_02_rust::Day02>(),
+    &puzzle::<aoc_2016_day_03_rust::Day03>(),
+    &puzzle::<aoc_2016_day_04_rust::Day04>(),
+    &puzzle::<aoc_2016_day_05_rust::Day05>(),
+    &puzzle::<aoc_2016_day_06_rust::Day06>(),
+    &puzzle::<aoc_2016_day_07_rust::Day07>(),
+    &puzzle::<aoc_2016_day_08_rust::Day08>(),
+    &puzzle::<aoc_2016_day_09_rust::Day09>(),
+    &puzzle::<aoc_2016_day_10_rust::Day10>(),
+    &puzzle::<aoc_2016_day_11_rust::Day11>(),
+    &puzzle::<aoc_2016_day_12_rust::Day12>(),
+    &puzzle::<aoc_2016_day_13_rust::Day13>(),
+    &puzzle::<aoc_2016_day_14_rust::Day14>(),
+    &puzzle::<aoc_2016_day_15_rust::Day15>(),
+    &puzzle::<aoc_2016_day_16_rust::Day16>(),
+    &puzzle::<aoc_2016_day_17_rust::Day17>(),
+    &puzzle::<aoc_2016_day_18_rust::Day18>(),
+    &puzzle::<aoc_2016_day_19_rust::Day19>(),
+    &puzzle::<aoc_2016_day_20_rust::Day20>(),
+    &puzzle::<aoc_2016_day_21_rust::Day21>(),
+    &puzzle::<aoc_2016_day_22_rust::Day22>(),
+    &puzzle::<aoc_2016_day_23_rust::Day23>(),
+    &puzzle::<aoc_2016_day_24_rust::Day24>(),
+    &puzzle::<aoc_2016_day_25_rust::Day25>(),
+    &puzzle::<aoc_2017_day_01_rust::Day01>(),
+    &puzzle::<aoc_2017_day_02_rust::Day02>(),
+    &puzzle::<aoc_2017_day_03_rust::Day03>(),
+    &puzzle::<aoc_2017_day_04_rust::Day04>(),
+    &puzzle::<aoc_2017_day_05_rust::Day05>(),
+    &puzzle::<aoc_2017_day_06_rust::Day06>(),
+    &puzzle::<aoc_2017_day_07_rust::Day07>(),
+    &puzzle::<aoc_2017_day_08_rust::Day08>(),
+    &puzzle::<aoc_2017_day_09_rust::Day09>(),
+    &puzzle::<aoc_2017_day_10_rust::Day10>(),
+    &puzzle::<aoc_2017_day_11_rust::Day11>(),
+    &puzzle::<aoc_2017_day_12_rust::Day12>(),
+    &puzzle::<aoc_2017_day_13_rust::Day13>(),
+    &puzzle::<aoc_2017_day_14_rust::Day14>(),
+    &puzzle::<aoc_2017_day_15_rust::Day15>(),
+    &puzzle::<aoc_2017_day_16_rust::Day16>(),
+    &puzzle::<aoc_2017_day_17_rust::Day17>(),
+    &puzzle::<aoc_2017_day_18_rust::Day18>(),
+    &puzzle::<aoc_2017_day_19_rust::Day19>(),
+    &puzzle::<aoc_2017_day_20_rust::Day20>(),
+    &puzzle::<aoc_2017_day_21_rust::Day21>(),
+    &puzzle::<aoc_2017_day_22_rust::Day22>(),
+    &puzzle::<aoc_2017_day_23_rust::Day23>(),
+    &puzzle::<aoc_2017_day_24_rust::Day24>(),
+    &puzzle::<aoc_2017_day_25_rust::Day25>(),
+    &puzzle::<aoc_2018_day_01_rust::Day01>(),
+    &puzzle::<aoc_2018_day_02_rust::Day02>(),
+    &puzzle::<aoc_2018_day_03_rust::Day03>(),
+    &puzzle::<aoc_2018_day_04_rust::Day04>(),
+    &puzzle::<aoc_2018_day_05_rust::Day05>(),
+    &puzzle::<aoc_2018_day_06_rust::Day06>(),
+    &puzzle::<aoc_2018_day_07_rust::Day07>(),
+    &puzzle::<aoc_2018_day_08_rust::Day08>(),
+    &puzzle::<aoc_2018_day_09_rust::Day09>(),
+    &puzzle::<aoc_2018_day_10_rust::Day10>(),
+    &puzzle::<aoc_2018_day_11_rust::Day11>(),
+    &puzzle::<aoc_2018_day_12_rust::Day12>(),
+    &puzzle::<aoc_2018_day_13_rust::Day13>(),
+    &puzzle::<aoc_2018_day_14_rust::Day14>(),
+    &puzzle::<aoc_2018_day_15_rust::Day15>(),
+    &puzzle::<aoc_2018_day_16_rust::Day16>(),
+    &puzzle::<aoc_2018_day_17_rust::Day17>(),
+    &puzzle::<aoc_2018_day_18_rust::Day18>(),
+    &puzzle::<aoc_2018_day_19_rust::Day19>(),
+    &puzzle::<aoc_2018_day_20_rust::Day20>(),
+    &puzzle::<aoc_2018_day_21_rust::Day21>(),
+    &puzzle::<aoc_2018_day_22_rust::Day22>(),
+    &puzzle::<aoc_2018_day_23_rust::Day23>(),
+    &puzzle::<aoc_2018_day_24_rust::Day24>(),
+    &puzzle::<aoc_2018_day_25_rust::Day25>(),
+];
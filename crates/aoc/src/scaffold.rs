@@ -0,0 +1,371 @@
+//! `aoc new`: creating the crate of a new day and registering it everywhere a
+//! day has to be known.
+//!
+//! A day lives in `<year>/<day>/rust` as the package `aoc_<year>_day_<DD>_rust`.
+//! The day folder follows the style the year already uses, so 2015 and 2016
+//! keep their unpadded `7` while later years use `07`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, Expected};
+
+/// The package name of a day, with the day always padded to two digits.
+pub fn package(year: u16, day: u8) -> String {
+    format!("aoc_{year}_day_{day:02}_rust")
+}
+
+/// The folder of a day below `root`. An existing folder is used as is;
+/// otherwise the day is padded unless the year already has unpadded days.
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    let year_dir = root.join(year.to_string());
+    let unpadded = year_dir.join(day.to_string());
+    let padded = year_dir.join(format!("{day:02}"));
+    if unpadded.is_dir() {
+        return unpadded;
+    }
+    if padded.is_dir() {
+        return padded;
+    }
+    let year_unpadded = (1..=9).any(|d: u8| year_dir.join(d.to_string()).is_dir());
+    if year_unpadded { unpadded } else { padded }
+}
+
+/// The `Cargo.toml` of a new day.
+pub fn cargo_toml(year: u16, day: u8) -> String {
+    format!(
+        "[package]\n\
+         name = \"{}\"\n\
+         version.workspace = true\n\
+         edition.workspace = true\n\
+         \n\
+         [dependencies]\n\
+         aoc_common.workspace = true\n",
+        package(year, day)
+    )
+}
+
+/// The `src/lib.rs` of a new day: a [`Solution`](aoc_common::Solution) marked
+/// as not solved, which the runner skips, and an ignored test per part waiting
+/// for the puzzle's worked example.
+pub fn lib_rs(year: u16, day: u8) -> String {
+    let name = format!("Day{day:02}");
+    format!(
+        r#"use aoc_common::{{ParseError, Solution}};
+use std::fmt::Display;
+
+pub struct {name};
+
+impl Solution for {name} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const SOLVED: bool = false;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {{
+        Ok(input.trim().to_string())
+    }}
+
+    fn part1(_input: &String) -> impl Display {{
+        "unsolved"
+    }}
+
+    fn part2(_input: &String) -> impl Display {{
+        "unsolved"
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "the worked example has not been filled in"]
+    fn test_part1() {{
+        let input = {name}::parse(EXAMPLE).unwrap();
+        assert_eq!({name}::part1(&input).to_string(), "");
+    }}
+
+    #[test]
+    #[ignore = "the worked example has not been filled in"]
+    fn test_part2() {{
+        let input = {name}::parse(EXAMPLE).unwrap();
+        assert_eq!({name}::part2(&input).to_string(), "");
+    }}
+}}
+"#
+    )
+}
+
+/// Inserts `line` among the lines of `text` accepted by `is_peer`, keeping
+/// those sorted. Without any peer, `line` goes after the first line accepted
+/// by `anchor`.
+fn insert_line(
+    text: &str,
+    line: &str,
+    is_peer: impl Fn(&str) -> bool,
+    anchor: impl Fn(&str) -> bool,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let peers: Vec<usize> = (0..lines.len()).filter(|&i| is_peer(lines[i])).collect();
+    let at = match (peers.iter().find(|&&i| lines[i] > line), peers.last()) {
+        (Some(&next), _) => next,
+        (None, Some(&last)) => last + 1,
+        (None, None) => {
+            let anchor = lines
+                .iter()
+                .position(|l| anchor(l))
+                .ok_or_else(|| format!("nowhere to insert `{}`", line.trim()))?;
+            anchor + 1
+        }
+    };
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Renders the manifest one answer per line, ordered by year, day and part.
+pub fn render_manifest(entries: &[Expected]) -> String {
+    let mut entries = entries.to_vec();
+    entries.sort_by_key(|e| (e.year, e.day, e.part));
+    let lines: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            let answer = match &e.answer {
+                None => "null".to_string(),
+                Some(answer)
+                    if answer
+                        .parse::<u64>()
+                        .is_ok_and(|n| n.to_string() == *answer) =>
+                {
+                    answer.clone()
+                }
+                Some(answer) => serde_json::Value::from(answer.as_str()).to_string(),
+            };
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {answer}}}",
+                e.year, e.day, e.part
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", lines.join(",\n"))
+    }
+}
+
+/// Creates day `day` of `year` below the repository `root` and registers it
+/// with the workspace, the runner and the answers manifest, where its answers
+/// stay pending (`null`) until they are known. Nothing is written if the day
+/// already exists. Returns the files created or changed.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}; days run from 1 to 25"));
+    }
+    if year < 2015 {
+        return Err(format!("Advent of Code started in 2015, not {year}"));
+    }
+
+    let crate_dir = day_dir(root, year, day).join("rust");
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+    let package = package(year, day);
+    let runner_manifest = root.join("crates/aoc/Cargo.toml");
+    let registry = root.join("crates/aoc/src/registry.rs");
+    let workspace = root.join("Cargo.toml");
+    let manifest = answers::manifest_path_in(root);
+
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()));
+    let runner_text = read(&runner_manifest)?;
+    let registry_text = read(&registry)?;
+    let workspace_text = read(&workspace)?;
+    let mut expected = answers::load(&manifest)?;
+    if runner_text.contains(&format!("{package} =")) || registry_text.contains(&package) {
+        return Err(format!("{package} is already registered"));
+    }
+    if expected.iter().any(|e| (e.year, e.day) == (year, day)) {
+        return Err(format!(
+            "{year} day {day} is already in {}",
+            manifest.display()
+        ));
+    }
+
+    // Work out every change before writing, so a failure leaves the tree as
+    // it was.
+    let relative = crate_dir
+        .strip_prefix(root)
+        .expect("the day is below the root");
+    let dependency = format!("{package} = {{ path = \"../../{}\" }}", relative.display());
+    let runner_text = insert_line(
+        &runner_text,
+        &dependency,
+        |l| l.starts_with("aoc_") && l.contains("_rust ="),
+        |l| l.starts_with("aoc_common"),
+    )?;
+    let entry = format!("    &puzzle::<{package}::Day{day:02}>(),");
+    let registry_text = insert_line(
+        &registry_text,
+        &entry,
+        |l| l.trim_start().starts_with("&puzzle::<"),
+        |l| l.contains("DAYS"),
+    )?;
+    let member = format!("    \"{year}/*/rust\",");
+    let workspace_text = if workspace_text.contains(member.trim()) {
+        workspace_text
+    } else {
+        insert_line(
+            &workspace_text,
+            &member,
+            |l| l.trim_start().starts_with("\"20") && l.contains("/rust"),
+            |l| l.starts_with("members"),
+        )?
+    };
+    for part in [1, 2] {
+        expected.push(Expected {
+            year,
+            day,
+            part,
+            answer: None,
+        });
+    }
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), cargo_toml(year, day)),
+        (crate_dir.join("src/lib.rs"), lib_rs(year, day)),
+        (runner_manifest, runner_text),
+        (registry, registry_text),
+        (workspace, workspace_text),
+        (manifest, render_manifest(&expected)),
+    ];
+    fs::create_dir_all(crate_dir.join("src"))
+        .map_err(|e| format!("{}: {e}", crate_dir.display()))?;
+    let mut written = Vec::new();
+    for (path, text) in files {
+        fs::write(&path, text).map_err(|e| format!("{}: {e}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folders_follow_the_year() {
+        let root = aoc_common::input::repo_root();
+        assert!(day_dir(root, 2015, 7).ends_with("2015/7"));
+        assert!(day_dir(root, 2017, 7).ends_with("2017/07"));
+        assert!(day_dir(root, 2018, 1).ends_with("2018/01"));
+        assert!(day_dir(root, 2019, 3).ends_with("2019/03"));
+        assert_eq!(package(2016, 3), "aoc_2016_day_03_rust");
+    }
+
+    // A repository with one day, 2018/13, in a scratch folder.
+    fn scratch_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\n    \"2018/*/rust\",\n]\n",
+            ),
+            (
+                "crates/aoc/Cargo.toml",
+                "[dependencies]\naoc_common.workspace = true\n\
+                 aoc_2018_day_13_rust = { path = \"../../2018/13/rust\" }\n",
+            ),
+            (
+                "crates/aoc/src/registry.rs",
+                "pub const DAYS: &[&dyn Puzzle] = &[\n    \
+                 &puzzle::<aoc_2018_day_13_rust::Day13>(),\n];\n",
+            ),
+            (
+                "answers.json",
+                "[\n  {\"year\": 2018, \"day\": 13, \"part\": 1, \"answer\": \"7,3\"}\n]\n",
+            ),
+            ("2018/13/rust/Cargo.toml", ""),
+        ];
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn existing_days_are_refused() {
+        let root = scratch_root("existing");
+        let err = create(&root, 2018, 13).unwrap_err();
+        assert!(err.ends_with("2018/13/rust already exists"), "{err}");
+        assert!(create(&root, 2018, 26).is_err());
+
+        create(&root, 2018, 14).unwrap();
+        let expected = answers::load(&answers::manifest_path_in(&root)).unwrap();
+        let pending: Vec<_> = (expected.iter())
+            .filter(|e| e.answer.is_none())
+            .map(|e| (e.year, e.day, e.part))
+            .collect();
+        assert_eq!(pending, [(2018, 14, 1), (2018, 14, 2)]);
+        assert!(create(&root, 2018, 14).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn lines_are_inserted_in_order() {
+        let text = "[dependencies]\naoc_common.workspace = true\na_1_rust = 1\na_3_rust = 3\n";
+        let peer = |l: &str| l.contains("_rust");
+        let anchor = |l: &str| l.starts_with("aoc_common");
+        assert_eq!(
+            insert_line(text, "a_2_rust = 2", peer, anchor).unwrap(),
+            "[dependencies]\naoc_common.workspace = true\na_1_rust = 1\na_2_rust = 2\na_3_rust = 3\n"
+        );
+        assert_eq!(
+            insert_line(
+                "[dependencies]\naoc_common.workspace = true\n",
+                "a_2_rust = 2",
+                peer,
+                anchor
+            )
+            .unwrap(),
+            "[dependencies]\naoc_common.workspace = true\na_2_rust = 2\n"
+        );
+    }
+
+    #[test]
+    fn manifest_round_trips() {
+        let text = render_manifest(&[
+            Expected {
+                year: 2018,
+                day: 14,
+                part: 1,
+                answer: None,
+            },
+            Expected {
+                year: 2016,
+                day: 8,
+                part: 2,
+                answer: Some("\n#..#".to_string()),
+            },
+            Expected {
+                year: 2016,
+                day: 8,
+                part: 1,
+                answer: Some("0123".to_string()),
+            },
+        ]);
+        assert_eq!(
+            text,
+            "[\n  {\"year\": 2016, \"day\": 8, \"part\": 1, \"answer\": \"0123\"},\n  \
+             {\"year\": 2016, \"day\": 8, \"part\": 2, \"answer\": \"\\n#..#\"},\n  \
+             {\"year\": 2018, \"day\": 14, \"part\": 1, \"answer\": null}\n]\n"
+        );
+        assert_eq!(answers::parse(&text).unwrap().len(), 3);
+    }
+}
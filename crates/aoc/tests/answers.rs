@@ -0,0 +1,140 @@
+//! Runs every puzzle that has a local input and compares its answers with
+//! `answers.json`. Days without an input, and days not solved yet, are
+//! reported as skipped, and answers recorded as `null` as pending; neither
+//! counts as checked. A run that checks nothing says so on stderr past the
+//! test harness, and fails if there were inputs with answers to check.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write as _};
+
+use aoc::answers::{self, Expected};
+use aoc::registry::DAYS;
+use aoc_common::input::{self, InputError, Source};
+
+fn manifest() -> Vec<Expected> {
+    answers::load(&answers::manifest_path()).unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[test]
+fn manifest_only_names_known_puzzles_once() {
+    let known: HashMap<_, _> = (DAYS.iter())
+        .map(|p| ((p.year(), p.day()), p.solved()))
+        .collect();
+    let mut seen = HashSet::new();
+    for entry in manifest() {
+        let key = (entry.year, entry.day, entry.part);
+        let solved = known.get(&(entry.year, entry.day));
+        assert!(
+            solved.is_some(),
+            "{} day {} is not in the registry",
+            entry.year,
+            entry.day
+        );
+        assert!(
+            solved == Some(&true) || entry.answer.is_none(),
+            "{} day {} is not solved yet but has an answer",
+            entry.year,
+            entry.day
+        );
+        assert!(
+            matches!(entry.part, 1 | 2),
+            "{} day {} has no part {}",
+            entry.year,
+            entry.day,
+            entry.part
+        );
+        assert!(seen.insert(key), "{key:?} is listed twice");
+    }
+}
+
+#[test]
+fn answers_match_the_manifest() {
+    let expected: HashMap<_, _> = manifest()
+        .into_iter()
+        .map(|e| ((e.year, e.day, e.part), e.answer))
+        .collect();
+
+    let mut checked = 0;
+    let mut found = Vec::new();
+    let mut skipped = Vec::new();
+    let mut unsolved = Vec::new();
+    let mut pending = Vec::new();
+    let mut unlisted = Vec::new();
+    let mut mismatches = Vec::new();
+    for puzzle in DAYS {
+        let (year, day) = (puzzle.year(), puzzle.day());
+        if !puzzle.solved() {
+            unsolved.push(format!("{year}/{day}"));
+            continue;
+        }
+        let raw = match input::load(year, day, &Source::Default) {
+            Ok(raw) => raw,
+            Err(InputError::NotFound { .. }) => {
+                skipped.push(format!("{year}/{day}"));
+                continue;
+            }
+            Err(e) => panic!("{year} day {day}: {e}"),
+        };
+        let parsed = puzzle
+            .parse(&raw.text)
+            .unwrap_or_else(|e| panic!("{year} day {day}: {}", e.in_file(raw.origin)));
+        // Whether any part is not pending, and so ought to be checked
+        let mut due = false;
+        for part in [1, 2] {
+            let want = match expected.get(&(year, day, part)) {
+                Some(Some(want)) => want,
+                Some(None) => {
+                    pending.push(format!("{year}/{day} part {part}"));
+                    continue;
+                }
+                None => {
+                    due = true;
+                    unlisted.push(format!("{year}/{day} part {part}"));
+                    continue;
+                }
+            };
+            due = true;
+            let got = match part {
+                1 => puzzle.part1(parsed.as_ref()),
+                _ => puzzle.part2(parsed.as_ref()),
+            };
+            checked += 1;
+            if &got != want {
+                mismatches.push(format!(
+                    "{year} day {day} part {part}: expected {want:?}, got {got:?}"
+                ));
+            }
+        }
+        if due {
+            found.push(format!("{year}/{day}"));
+        }
+    }
+
+    println!("checked {checked} answers");
+    if !skipped.is_empty() {
+        println!("skipped (no input): {}", skipped.join(", "));
+    }
+    if !unlisted.is_empty() {
+        println!("not in the manifest: {}", unlisted.join(", "));
+    }
+    if !unsolved.is_empty() {
+        println!("skipped (not solved yet): {}", unsolved.join(", "));
+    }
+    if !pending.is_empty() {
+        println!("pending (no answer recorded): {}", pending.join(", "));
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    assert!(
+        checked > 0 || found.is_empty(),
+        "inputs were found for {} but answers.json records none of their answers",
+        found.join(", ")
+    );
+    if checked == 0 {
+        // Written to stderr directly, so the harness does not swallow it
+        let _ = writeln!(
+            io::stderr(),
+            "warning: no answers were checked, as no day has a local input; \
+             put inputs in place or set AOC_INPUT_DIR to check answers.json"
+        );
+    }
+}
@@ -0,0 +1,366 @@
+//! A rectangular grid of cells, addressed by `(x, y)` with `(0, 0)` at the
+//! top left.
+
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A position in a [`Grid`], as `(x, y)`.
+pub type Pos = (usize, usize);
+
+/// The four orthogonal steps, as `(dx, dy)`: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The eight steps to the surrounding cells, as `(dx, dy)`, in reading order.
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A `width` by `height` grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid whose cells are `cell((x, y))`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads a grid with one row per line of `input`, turning each character
+    /// into a cell with `cell`. Every line must be as wide as the first, and
+    /// characters `cell` refuses are reported as not being `expected`.
+    pub fn parse(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let found = cell(c)
+                    .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(found);
+            }
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row {width} cells wide"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` is inside the grid.
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position one `(dx, dy)` step away from `pos`, if it is inside the
+    /// grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four cells orthogonally next to `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The up to eight cells around `pos`, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Every position, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, in reading order, that matches.
+    pub fn find(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| matches(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// How many cells match.
+    pub fn count(&self, matches: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| matches(cell)).count()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Shifts row `y` right by `by` cells, wrapping around.
+    pub fn rotate_row(&mut self, y: usize, by: usize) {
+        let width = self.width;
+        self.cells[y * width..(y + 1) * width].rotate_right(by % width.max(1));
+    }
+
+    /// Shifts column `x` down by `by` cells, wrapping around.
+    pub fn rotate_column(&mut self, x: usize, by: usize) {
+        assert!(x < self.width, "column {x} is outside the grid");
+        for _ in 0..by % self.height.max(1) {
+            for y in (1..self.height).rev() {
+                self.cells
+                    .swap(y * self.width + x, (y - 1) * self.width + x);
+            }
+        }
+    }
+
+    /// The grid with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// One line per row, each cell drawn as `draw(cell)`.
+    pub fn render(&self, draw: impl Fn(&T) -> char) -> String {
+        let rows: Vec<String> = self
+            .rows()
+            .map(|row| row.iter().map(&draw).collect())
+            .collect();
+        rows.join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Like [`Grid::parse`], but lines may have different lengths; short
+    /// lines are padded with `fill` up to the longest.
+    pub fn parse_padded(
+        input: &str,
+        fill: T,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in input.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let found = cell(c)
+                    .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(found);
+            }
+            cells.resize(before + width, fill.clone());
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotated(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flipped(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored along its main diagonal.
+    pub fn transposed(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// The `width` by `height` part of the grid whose top left is `(x, y)`.
+    pub fn subgrid(&self, (x, y): Pos, width: usize, height: usize) -> Self {
+        Grid::from_fn(width, height, |(dx, dy)| self[(x + dx, y + dy)].clone())
+    }
+
+    /// Copies `other` into the grid with its top left at `(x, y)`.
+    pub fn paste(&mut self, (x, y): Pos, other: &Grid<T>) {
+        for (dy, row) in other.rows().enumerate() {
+            let start = (y + dy) * self.width + x;
+            self.cells[start..start + row.len()].clone_from_slice(row);
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(rows: &str) -> Grid<char> {
+        Grid::parse(rows, Some, "a letter").unwrap()
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = letters("abc\ndef");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.render(|&c| c), "abc\ndef");
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn ragged_rows_and_stray_characters_are_errors() {
+        let err = Grid::parse("..\n...", Some, "").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row 2 cells wide");
+
+        let err = Grid::parse("..\n.x", |c| (c == '.').then_some(()), "`.`").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let padded = Grid::parse_padded(" |\n |  \n", ' ', Some, "").unwrap();
+        assert_eq!(padded.render(|&c| c), " |  \n |  ");
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(grid.step((0, 1), (-1, 0)), None);
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let grid = letters("ab\ncd\nef");
+        assert_eq!(grid.rotated().render(|&c| c), "eca\nfdb");
+        assert_eq!(grid.flipped().render(|&c| c), "ba\ndc\nfe");
+        assert_eq!(grid.transposed().render(|&c| c), "ace\nbdf");
+        assert_eq!(grid.rotated().rotated().rotated().rotated(), grid);
+    }
+
+    #[test]
+    fn rows_and_columns_wrap() {
+        let mut grid = letters("abc\ndef");
+        grid.rotate_row(0, 4);
+        grid.rotate_column(2, 1);
+        assert_eq!(grid.render(|&c| c), "caf\ndeb");
+    }
+
+    #[test]
+    fn cut_and_paste() {
+        let mut grid = letters("abcd\nefgh\nijkl");
+        let block = grid.subgrid((1, 1), 2, 2);
+        assert_eq!(block.render(|&c| c), "fg\njk");
+        grid.paste((0, 0), &block);
+        assert_eq!(grid.render(|&c| c), "fgcd\njkgh\nijkl");
+    }
+}
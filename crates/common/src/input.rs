@@ -0,0 +1,197 @@
+//! Finding and reading puzzle inputs.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory of inputs laid out as
+/// `<year>/<day>.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the input for a puzzle comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// An explicit file.
+    Path(PathBuf),
+    /// Standard input, spelled `-` on the command line.
+    Stdin,
+    /// The input text itself.
+    Inline(String),
+    /// `$AOC_INPUT_DIR/<year>/<day>.txt`, falling back to the `input.txt` in
+    /// the day's folder of this repository.
+    Default,
+}
+
+impl Source {
+    /// Interprets a command line path, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+/// A puzzle input and where it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawInput {
+    /// The file the input came from, or `<stdin>` or `<input-str>`.
+    pub origin: String,
+    pub text: String,
+}
+
+/// Why an input could not be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the default locations held an input.
+    NotFound {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    /// The input was found but could not be read.
+    Io { source: String, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound { year, day, tried } => {
+                write!(f, "no input found for {year} day {day}; tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Io { source, error } => write!(f, "cannot read {source}: {error}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Loads the input of a puzzle from `source`.
+pub fn load(year: u16, day: u8, source: &Source) -> Result<RawInput, InputError> {
+    match source {
+        Source::Path(path) => read_file(path),
+        Source::Stdin => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| InputError::Io {
+                    source: "standard input".to_string(),
+                    error,
+                })?;
+            Ok(RawInput {
+                origin: "<stdin>".to_string(),
+                text,
+            })
+        }
+        Source::Inline(text) => Ok(RawInput {
+            origin: "<input-str>".to_string(),
+            text: text.clone(),
+        }),
+        Source::Default => {
+            let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+            let tried = candidates(year, day, input_dir.as_deref());
+            match tried.iter().find(|path| path.is_file()) {
+                Some(path) => read_file(path),
+                None => Err(InputError::NotFound { year, day, tried }),
+            }
+        }
+    }
+}
+
+/// Every path [`Source::Default`] looks at, in order.
+pub fn candidates(year: u16, day: u8, input_dir: Option<&Path>) -> Vec<PathBuf> {
+    let names = [day.to_string(), format!("{day:02}")];
+    let mut paths = Vec::new();
+    if let Some(dir) = input_dir {
+        let year_dir = dir.join(year.to_string());
+        paths.extend(
+            names
+                .iter()
+                .map(|name| year_dir.join(format!("{name}.txt"))),
+        );
+    }
+    let year_dir = repo_root().join(year.to_string());
+    paths.extend(
+        names
+            .iter()
+            .map(|name| year_dir.join(name).join("input.txt")),
+    );
+    paths.dedup();
+    paths
+}
+
+/// The root of this repository, which holds one folder per year.
+pub fn repo_root() -> &'static Path {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    crate_dir
+        .ancestors()
+        .nth(2)
+        .expect("crates/common lives two levels below the repository root")
+}
+
+fn read_file(path: &Path) -> Result<RawInput, InputError> {
+    let origin = path.display().to_string();
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(RawInput { origin, text }),
+        Err(error) => Err(InputError::Io {
+            source: origin,
+            error,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_dir_is_searched_before_the_repository() {
+        let paths = candidates(2017, 3, Some(Path::new("/inputs")));
+        assert_eq!(paths[0], Path::new("/inputs/2017/3.txt"));
+        assert_eq!(paths[1], Path::new("/inputs/2017/03.txt"));
+        assert!(paths[2].ends_with("2017/3/input.txt"));
+        assert!(paths[3].ends_with("2017/03/input.txt"));
+    }
+
+    #[test]
+    fn two_digit_days_are_only_tried_once() {
+        assert_eq!(candidates(2016, 12, None).len(), 1);
+    }
+
+    #[test]
+    fn missing_input_names_every_path_tried() {
+        let err = InputError::NotFound {
+            year: 2015,
+            day: 7,
+            tried: vec![PathBuf::from("a/7.txt"), PathBuf::from("b/7/input.txt")],
+        };
+        assert_eq!(
+            err.to_string(),
+            "no input found for 2015 day 7; tried:\n  a/7.txt\n  b/7/input.txt"
+        );
+    }
+
+    #[test]
+    fn inline_and_dash() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        let inline = Source::Inline("1122".to_string());
+        let input = load(2017, 1, &inline).unwrap();
+        assert_eq!(input.text, "1122");
+        assert_eq!(input.origin, "<input-str>");
+    }
+}
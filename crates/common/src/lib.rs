@@ -0,0 +1,92 @@
+//! Pieces shared by every day of every year.
+
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+
+use std::any::Any;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub use grid::Grid;
+pub use parse::ParseError;
+
+/// A single Advent of Code puzzle.
+///
+/// `parse` turns the raw puzzle input into the day's own representation, and
+/// both parts answer from that representation so it is only built once. Input
+/// that does not have the expected shape is reported as a [`ParseError`]
+/// rather than a panic.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Whether the parts answer the puzzle. The stubs made by `aoc new` say
+    /// `false`, so that runs skip them instead of printing a placeholder.
+    const SOLVED: bool = true;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// Object-safe view of a [`Solution`], so the runner can keep every day in a
+/// single table.
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn solved(&self) -> bool;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, input: &dyn Any) -> String;
+    fn part2(&self, input: &dyn Any) -> String;
+}
+
+/// Adapts a [`Solution`] to [`Puzzle`]; build one with [`puzzle`].
+pub struct Erased<S>(PhantomData<fn() -> S>);
+
+/// Type-erases the solution `S`.
+pub const fn puzzle<S: Solution>() -> Erased<S> {
+    Erased(PhantomData)
+}
+
+impl<S> Puzzle for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solved(&self) -> bool {
+        S::SOLVED
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> String {
+        S::part1(downcast::<S>(input)).to_string()
+    }
+
+    fn part2(&self, input: &dyn Any) -> String {
+        S::part2(downcast::<S>(input)).to_string()
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different puzzle")
+}
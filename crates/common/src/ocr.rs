@@ -0,0 +1,297 @@
+//! Reading the block letters that some puzzles draw instead of giving an
+//! answer as text.
+//!
+//! There are two fonts: letters 4 cells wide and 6 tall, one every 5 columns,
+//! and letters 6 wide and 10 tall, one every 8. The height of the picture
+//! says which font it is drawn in.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::Grid;
+
+/// The letters of one size, with the columns from one letter to the next.
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    pub pitch: usize,
+    /// Every letter known so far, drawn with `#` and `.`; a letter may be
+    /// narrower than the font, and one as wide as the pitch.
+    pub glyphs: &'static [(char, &'static [&'static str])],
+}
+
+pub const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+pub const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Why a picture could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// No font is this many rows tall.
+    Height(usize),
+    /// The letter at `index`, counting from 0, is not in the font; `bitmap`
+    /// draws it with `#` and `.` so it can be added.
+    Unknown { index: usize, bitmap: String },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(
+                f,
+                "no font is {height} rows tall; letters are {} or {} rows tall",
+                SMALL.height, LARGE.height
+            ),
+            OcrError::Unknown { index, bitmap } => {
+                write!(f, "letter {} is not in the font:\n{bitmap}", index + 1)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the letters in `picture`, lit where the cell is `true`.
+pub fn read(picture: &Grid<bool>) -> Result<String, OcrError> {
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == picture.height())
+        .ok_or(OcrError::Height(picture.height()))?;
+    read_in(&font, picture)
+}
+
+/// Reads the letters in `picture` in `font`, the first one starting at the
+/// left edge. A blank letter reads as a space, and blanks at the end are
+/// left out.
+pub fn read_in(font: &Font, picture: &Grid<bool>) -> Result<String, OcrError> {
+    if picture.height() != font.height {
+        return Err(OcrError::Height(picture.height()));
+    }
+    let text: String = (0..picture.width().div_ceil(font.pitch))
+        .map(|index| {
+            // The letter's columns, blank past the edge of the picture
+            let rows: Vec<String> = (0..font.height)
+                .map(|y| {
+                    (index * font.pitch..(index + 1) * font.pitch)
+                        .map(|x| match picture.get((x, y)) {
+                            Some(true) => '#',
+                            _ => '.',
+                        })
+                        .collect()
+                })
+                .collect();
+            let matches = |glyph: &[&str]| {
+                rows.iter()
+                    .zip(glyph)
+                    .all(|(row, line)| row.trim_end_matches('.') == line.trim_end_matches('.'))
+            };
+            if rows.iter().all(|row| !row.contains('#')) {
+                return Ok(' ');
+            }
+            font.glyphs
+                .iter()
+                .find(|(_, glyph)| matches(glyph))
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| OcrError::Unknown {
+                    index,
+                    bitmap: rows.join("\n"),
+                })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(text.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n"), |c| Some(c == '#'), "").unwrap()
+    }
+
+    #[test]
+    fn reads_both_fonts() {
+        let small = picture(&[
+            "#..#.####.#....#.....##..#...#",
+            "#..#.#....#....#....#..#.#...#",
+            "####.###..#....#....#..#..#.#.",
+            "#..#.#....#....#....#..#...#..",
+            "#..#.#....#....#....#..#...#..",
+            "#..#.####.####.####..##....#..",
+        ]);
+        assert_eq!(read(&small).unwrap(), "HELLOY");
+
+        // The picture ends where the last letter does
+        let large = picture(&[
+            "#....#..######",
+            "#....#.......#",
+            "#....#.......#",
+            "#....#......#.",
+            "######.....#..",
+            "#....#....#...",
+            "#....#...#....",
+            "#....#..#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]);
+        assert_eq!(read(&large).unwrap(), "HZ");
+
+        let spaced = picture(&[
+            "#.........#....",
+            "#.........#....",
+            "#.........#....",
+            "#.........#....",
+            "#.........#....",
+            "####......####.",
+        ]);
+        assert_eq!(read(&spaced).unwrap(), "L L");
+    }
+
+    #[test]
+    fn unknown_letters_are_drawn() {
+        let unknown = picture(&["#.#", ".#.", "#.#", ".#.", "#.#", ".#."]);
+        assert_eq!(
+            read(&unknown),
+            Err(OcrError::Unknown {
+                index: 0,
+                bitmap: "#.#..\n.#...\n#.#..\n.#...\n#.#..\n.#...".to_string()
+            })
+        );
+        assert_eq!(read(&picture(&["#", "#"])), Err(OcrError::Height(2)));
+    }
+}
@@ -0,0 +1,158 @@
+//! Errors for puzzle inputs that do not have the expected shape, and small
+//! helpers to produce them.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where a puzzle input stopped making sense, and what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input was read from, once known.
+    pub file: Option<String>,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, in characters, of the offending text.
+    pub column: usize,
+    /// The offending text. Empty when something is missing.
+    pub text: String,
+    /// What was expected in its place.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which must be a slice of `input`: a line of it,
+    /// a word, or an empty slice where something is missing. The position is
+    /// that of `text` within `input`.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves the error `lines` lines down, for errors reported relative to a
+    /// later part of the input.
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Records the file the input came from.
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{file}:{}:{}: ", self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        if self.text.is_empty() {
+            write!(f, "expected {}, found nothing", self.expected)
+        } else {
+            write!(f, "expected {}, found `{}`", self.expected, self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of `input` with `parse_line`. Errors are reported
+/// relative to the line passed to `parse_line`, and moved to that line of
+/// `input` here.
+pub fn lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.below(i)))
+        .collect()
+}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "a number"))
+}
+
+/// The `index`th whitespace separated word of `line`, described as
+/// `expected` when it is missing.
+pub fn word<'a>(line: &'a str, index: usize, expected: &str) -> Result<&'a str, ParseError> {
+    line.split_whitespace()
+        .nth(index)
+        .ok_or_else(|| ParseError::at(line, &line[line.len()..], expected))
+}
+
+/// `text`, a slice of `input`, if every character of it is `allowed`.
+/// Otherwise an error at the first character that is not.
+pub fn only<'a>(
+    input: &str,
+    text: &'a str,
+    allowed: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    match text.char_indices().find(|&(_, c)| !allowed(c)) {
+        Some((i, c)) => Err(ParseError::at(input, &text[i..i + c.len_utf8()], expected)),
+        None => Ok(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_is_found_from_the_slice() {
+        let input = "cpy 1 a\ninc a\njnz a x2";
+        let token = &input[input.len() - 2..];
+        let err = ParseError::at(input, token, "a number");
+        assert_eq!((err.line, err.column), (3, 7));
+        assert_eq!(err.text, "x2");
+    }
+
+    #[test]
+    fn lines_reports_the_failing_line() {
+        let err = lines("1\n2\nthree\n4", |line| number::<u32>(line, line)).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a number, found `three`"
+        );
+    }
+
+    #[test]
+    fn only_points_at_the_first_stray_character() {
+        let input = "UDLR\nULxéR";
+        let err = only(input, &input[5..], |c| "UDLR".contains(c), "a move").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+        assert_eq!(
+            only("10", "10", |c| c == '0' || c == '1', "a bit"),
+            Ok("10")
+        );
+    }
+
+    #[test]
+    fn missing_words_point_at_the_end_of_the_line() {
+        let err = word("set a", 2, "a value").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(
+            err.in_file("2017/18/input.txt").to_string(),
+            "2017/18/input.txt:1:6: expected a value, found nothing"
+        );
+    }
+}
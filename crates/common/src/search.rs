@@ -0,0 +1,338 @@
+//! Searches over graphs whose states are generated as they are reached.
+//!
+//! Every search starts from a single state and asks a successor function for
+//! the neighbours of each state it expands. States must be hashable so that
+//! each is expanded once; the path to the goal is rebuilt from the parent of
+//! every state reached.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path cost: anything that can be added up and compared, with
+/// `Default::default()` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// The most states waiting to be expanded at once; for IDA*, the deepest
+    /// path tried.
+    pub frontier_peak: usize,
+}
+
+/// A cheapest path to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// Every state from the start to the goal, both included.
+    pub path: Vec<S>,
+    pub stats: Stats,
+}
+
+impl<S, C> Found<S, C> {
+    /// The goal that was reached.
+    pub fn goal(&self) -> &S {
+        self.path.last().expect("a path holds at least the start")
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    // Every state reached, with its parent and its distance from the start.
+    let mut nodes = vec![(start.clone(), None, 0)];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([0]);
+    let mut stats = Stats::default();
+
+    while let Some(index) = queue.pop_front() {
+        let steps = nodes[index].2;
+        if is_goal(&nodes[index].0) {
+            return Some(Found {
+                cost: steps,
+                path: rebuild(&nodes, index),
+                stats,
+            });
+        }
+        stats.expanded += 1;
+        for next in successors(&nodes[index].0) {
+            if seen.insert(next.clone()) {
+                queue.push_back(nodes.len());
+                nodes.push((next, Some(index), steps + 1));
+            }
+        }
+        stats.frontier_peak = stats.frontier_peak.max(queue.len());
+    }
+    None
+}
+
+/// The number of steps to every state reachable from `start` in at most
+/// `max_steps` steps.
+pub fn distances<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    max_steps: usize,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, n)) = queue.pop_front() {
+        if n == max_steps {
+            continue;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = steps.entry(next.clone()) {
+                entry.insert(n + 1);
+                queue.push_back((next, n + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// Dijkstra's algorithm. `successors` gives each neighbour with the cost of
+/// the step to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the cost left to a goal
+/// for the result to be a cheapest path.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state reached, with its parent and the cheapest cost found to it.
+    let mut nodes: Vec<(S, Option<usize>, C)> = vec![(start.clone(), None, C::default())];
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0, C::default()))]);
+    let mut stats = Stats::default();
+
+    while let Some(Reverse((_, current, cost))) = frontier.pop() {
+        if cost > nodes[current].2 {
+            continue; // A cheaper way here was found after this one was queued.
+        }
+        if is_goal(&nodes[current].0) {
+            return Some(Found {
+                cost,
+                path: rebuild(&nodes, current),
+                stats,
+            });
+        }
+        stats.expanded += 1;
+        for (next, step) in successors(&nodes[current].0) {
+            let next_cost = cost + step;
+            let next_index = match index.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(nodes.len());
+                    nodes.push((next.clone(), Some(current), next_cost));
+                    nodes.len() - 1
+                }
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if next_cost >= node.2 {
+                        continue;
+                    }
+                    (node.1, node.2) = (Some(current), next_cost);
+                    *entry.get()
+                }
+            };
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_index,
+                next_cost,
+            )));
+        }
+        stats.frontier_peak = stats.frontier_peak.max(frontier.len());
+    }
+    None
+}
+
+/// Iterative deepening A*: depth-first searches bounded by the estimated
+/// total cost, raised each round to the smallest estimate that went over.
+/// Memory grows with the path length only, at the price of expanding states
+/// again every round.
+pub fn idastar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut bound = heuristic(&start);
+    let mut path = vec![start];
+    let mut stats = Stats::default();
+    loop {
+        let mut search = Deepening {
+            successors: &mut successors,
+            heuristic: &mut heuristic,
+            is_goal: &mut is_goal,
+            stats: &mut stats,
+        };
+        match search.probe(&mut path, C::default(), bound) {
+            Probe::Found(cost) => return Some(Found { cost, path, stats }),
+            Probe::Over(Some(next)) => bound = next,
+            Probe::Over(None) => return None,
+        }
+    }
+}
+
+enum Probe<C> {
+    Found(C),
+    /// The smallest estimate above the bound, if any.
+    Over(Option<C>),
+}
+
+struct Deepening<'a, N, H, G> {
+    successors: &'a mut N,
+    heuristic: &'a mut H,
+    is_goal: &'a mut G,
+    stats: &'a mut Stats,
+}
+
+impl<N, H, G> Deepening<'_, N, H, G> {
+    fn probe<S, C, I>(&mut self, path: &mut Vec<S>, cost: C, bound: C) -> Probe<C>
+    where
+        S: Clone + Eq,
+        C: Cost,
+        I: IntoIterator<Item = (S, C)>,
+        N: FnMut(&S) -> I,
+        H: FnMut(&S) -> C,
+        G: FnMut(&S) -> bool,
+    {
+        let state = path.last().expect("the path holds the start").clone();
+        let estimate = cost + (self.heuristic)(&state);
+        if estimate > bound {
+            return Probe::Over(Some(estimate));
+        }
+        if (self.is_goal)(&state) {
+            return Probe::Found(cost);
+        }
+        self.stats.expanded += 1;
+        self.stats.frontier_peak = self.stats.frontier_peak.max(path.len());
+
+        let mut over = None;
+        for (next, step) in (self.successors)(&state) {
+            if path.contains(&next) {
+                continue;
+            }
+            path.push(next);
+            match self.probe(path, cost + step, bound) {
+                Probe::Found(cost) => return Probe::Found(cost),
+                Probe::Over(Some(estimate)) => {
+                    over = Some(over.map_or(estimate, |o: C| o.min(estimate)));
+                }
+                Probe::Over(None) => {}
+            }
+            path.pop();
+        }
+        Probe::Over(over)
+    }
+}
+
+/// The path from the start to `nodes[index]`, following parents back.
+fn rebuild<S: Clone, C>(nodes: &[(S, Option<usize>, C)], mut index: usize) -> Vec<S> {
+    let mut path = vec![nodes[index].0.clone()];
+    while let Some(parent) = nodes[index].1 {
+        path.push(nodes[parent].0.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of states 0..=10 where each state steps to its neighbours, plus
+    // a costly shortcut from 0 straight to 10.
+    fn line(n: &i32) -> Vec<(i32, u32)> {
+        let mut next = vec![];
+        if *n > 0 {
+            next.push((n - 1, 1));
+        }
+        if *n < 10 {
+            next.push((n + 1, 1));
+        }
+        if *n == 0 {
+            next.push((10, 25));
+        }
+        next
+    }
+
+    #[test]
+    fn bfs_returns_the_path() {
+        let found = bfs(0, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path, [0, 1, 2, 4, 5, 10]);
+        assert_eq!(*found.goal(), 10);
+        assert!(found.stats.expanded > 0 && found.stats.frontier_peak > 0);
+        assert!(bfs(0, |&n| (n < 10).then_some(n + 2), |&n| n == 3).is_none());
+    }
+
+    #[test]
+    fn distances_stop_at_the_limit() {
+        let reach = distances(0, |&n: &i32| [n - 1, n + 1], 3);
+        assert_eq!(reach.len(), 7);
+        assert_eq!(reach[&-3], 3);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        let dijkstra = dijkstra(0, line, |&n| n == 10).unwrap();
+        assert_eq!((dijkstra.cost, dijkstra.path.len()), (10, 11));
+
+        let heuristic = |&n: &i32| (10 - n) as u32;
+        let astar = astar(0, line, heuristic, |&n| n == 10).unwrap();
+        assert_eq!(astar.path, dijkstra.path);
+        assert!(astar.stats.expanded <= dijkstra.stats.expanded);
+
+        let idastar = idastar(0, line, heuristic, |&n| n == 10).unwrap();
+        assert_eq!((idastar.cost, idastar.path), (10, dijkstra.path));
+        assert_eq!(idastar.stats.frontier_peak, 10);
+    }
+
+    #[test]
+    fn unreachable_goals() {
+        assert!(dijkstra(0, line, |&n| n == 11).is_none());
+        assert!(idastar(0, line, |_| 0, |&n| n == 11).is_none());
+    }
+}
@@ -0,0 +1,161 @@
+//! The Knot Hash of 2017: a circular list of numbers, twisted by reversing a
+//! run of it for every input length, round after round.
+
+use std::fmt;
+
+/// The lengths added after the input, unless [`KnotHasher::salt`] says
+/// otherwise.
+pub const STANDARD_SALT: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// Builds a Knot Hash. The standard one twists a list of 256 numbers through
+/// 64 rounds of the input bytes followed by [`STANDARD_SALT`].
+///
+/// Every round replays the whole input, so [`KnotHasher::update`] only
+/// collects it and the twisting happens in [`KnotHasher::finalize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnotHasher {
+    size: usize,
+    rounds: usize,
+    salt: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+impl Default for KnotHasher {
+    fn default() -> Self {
+        KnotHasher::new()
+    }
+}
+
+impl KnotHasher {
+    /// The standard hasher, with no input yet.
+    pub fn new() -> Self {
+        KnotHasher {
+            size: 256,
+            rounds: 64,
+            salt: STANDARD_SALT.to_vec(),
+            lengths: Vec::new(),
+        }
+    }
+
+    /// Twists a list of `size` numbers, from 1 to 256.
+    pub fn size(mut self, size: usize) -> Self {
+        assert!(
+            (1..=256).contains(&size),
+            "a knot list holds 1 to 256 numbers, not {size}"
+        );
+        self.size = size;
+        self
+    }
+
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// The lengths added after the input in every round.
+    pub fn salt(mut self, salt: &[usize]) -> Self {
+        self.salt = salt.to_vec();
+        self
+    }
+
+    /// Adds `bytes` to the input, each one a length.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.lengths
+            .extend(bytes.iter().map(|&byte| usize::from(byte)));
+    }
+
+    /// Adds lengths to the input as they are, for lengths that are not bytes.
+    pub fn update_lengths(&mut self, lengths: &[usize]) {
+        self.lengths.extend(lengths);
+    }
+
+    /// Runs the rounds over the input. A length longer than the list is
+    /// skipped, as the puzzle says.
+    pub fn finalize(self) -> KnotHash {
+        let size = self.size;
+        let mut list: Vec<u8> = (0..size).map(|n| n as u8).collect();
+        let (mut position, mut skip) = (0, 0);
+        for _ in 0..self.rounds {
+            for &length in self.lengths.iter().chain(&self.salt) {
+                if length > size {
+                    continue;
+                }
+                for i in 0..length / 2 {
+                    list.swap((position + i) % size, (position + length - 1 - i) % size);
+                }
+                position = (position + length + skip) % size;
+                skip += 1;
+            }
+        }
+        KnotHash { sparse: list }
+    }
+}
+
+/// The standard Knot Hash of `bytes`.
+pub fn hash(bytes: &[u8]) -> KnotHash {
+    let mut hasher = KnotHasher::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// A twisted list. Shown as the hex digits of its dense hash.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KnotHash {
+    sparse: Vec<u8>,
+}
+
+impl KnotHash {
+    /// The list as the rounds left it.
+    pub fn sparse(&self) -> &[u8] {
+        &self.sparse
+    }
+
+    /// Every block of 16 numbers of the list XORed together; the last block
+    /// is shorter when the size is not a multiple of 16.
+    pub fn dense(&self) -> Vec<u8> {
+        (self.sparse.chunks(16))
+            .map(|block| block.iter().fold(0, |acc, &n| acc ^ n))
+            .collect()
+    }
+
+    /// The dense hash as two hex digits a byte.
+    pub fn hex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for KnotHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.dense() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_round() {
+        let mut hasher = KnotHasher::new().size(5).rounds(1).salt(&[]);
+        hasher.update_lengths(&[3, 4, 1, 5]);
+        assert_eq!(hasher.finalize().sparse(), [3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn standard_hashes() {
+        assert_eq!(hash(b"").hex(), "a2582a3a0e66e6e86e3812dcb672a272");
+        assert_eq!(hash(b"AoC 2017").hex(), "33efeb34ea91902bb2f59c9920caa6cd");
+        assert_eq!(hash(b"1,2,3").hex(), "3efbe78a8d82f29979031a4aa0b16a9d");
+        assert_eq!(hash(b"1,2,4").hex(), "63960835bcdc130f0b66d7ff4f6a5a8e");
+
+        let mut hasher = KnotHasher::new();
+        hasher.update(b"AoC");
+        hasher.update(b" 2017");
+        let streamed = hasher.finalize();
+        assert_eq!(streamed, hash(b"AoC 2017"));
+        assert_eq!(streamed.dense()[..2], [0x33, 0xef]);
+    }
+}
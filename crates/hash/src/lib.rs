@@ -0,0 +1,9 @@
+//! The hashes the puzzles mine and build.
+//!
+//! [`mining`] searches for MD5 digests of a salt followed by a counting index,
+//! as the hash-mining days do, checking the digest nibbles directly rather than
+//! its hex text and hashing on every core. [`knot`] is the Knot Hash of 2017,
+//! with its list size, rounds and salt open to change.
+
+pub mod knot;
+pub mod mining;
@@ -0,0 +1,225 @@
+//! MD5 digests of a salt followed by an index, searched in index order with
+//! the indices split across threads.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::Write as _;
+use std::num::NonZero;
+use std::ops::Range;
+use std::thread;
+
+use md5::{Digest as _, Md5};
+
+/// Indices each thread hashes in one go while searching.
+const BATCH: u64 = 4096;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// An MD5 digest, read as its 32 hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Digest(pub [u8; 16]);
+
+impl Digest {
+    /// The digest of `parts` one after the other.
+    pub fn of(parts: &[&[u8]]) -> Digest {
+        let mut hasher = Md5::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        Digest(hasher.finalize().into())
+    }
+
+    /// The hex digit at `index`, from 0 to 15.
+    pub fn nibble(&self, index: usize) -> u8 {
+        let byte = self.0[index / 2];
+        if index.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0xf
+        }
+    }
+
+    /// The 32 hex digits, from 0 to 15 each.
+    pub fn nibbles(&self) -> [u8; 32] {
+        std::array::from_fn(|i| self.nibble(i))
+    }
+
+    /// Whether the hex digits start with `count` zeros.
+    pub fn starts_with_zeros(&self, count: usize) -> bool {
+        (0..count).all(|i| self.nibble(i) == 0)
+    }
+
+    /// The hex digits as lowercase ASCII.
+    pub fn hex(&self) -> [u8; 32] {
+        self.nibbles().map(|nibble| HEX[nibble as usize])
+    }
+
+    /// Hashes the hex digits again, `times` times over, as key stretching
+    /// does.
+    pub fn stretch(self, times: usize) -> Digest {
+        (0..times).fold(self, |digest, _| Digest::of(&[&digest.hex()]))
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for nibble in self.nibbles() {
+            write!(f, "{nibble:x}")?;
+        }
+        Ok(())
+    }
+}
+
+// Hashes the salt once and every index after it, reusing one buffer for the
+// digits.
+struct Salted {
+    prefix: Md5,
+    digits: Vec<u8>,
+}
+
+impl Salted {
+    fn new(salt: &str) -> Self {
+        Salted {
+            prefix: Md5::new_with_prefix(salt),
+            digits: Vec::with_capacity(20),
+        }
+    }
+
+    fn digest(&mut self, index: u64) -> Digest {
+        self.digits.clear();
+        write!(self.digits, "{index}").expect("writing to a Vec cannot fail");
+        Digest(
+            self.prefix
+                .clone()
+                .chain_update(&self.digits)
+                .finalize()
+                .into(),
+        )
+    }
+}
+
+/// The digest of `salt` followed by `index` in decimal.
+pub fn salted(salt: &str, index: u64) -> Digest {
+    Salted::new(salt).digest(index)
+}
+
+fn threads() -> u64 {
+    thread::available_parallelism().map_or(1, NonZero::get) as u64
+}
+
+// Splits `range` into one run of indices per thread, and joins what `work`
+// makes of each run in index order.
+fn split<T: Send>(
+    range: Range<u64>,
+    threads: u64,
+    work: impl Fn(Range<u64>) -> Vec<T> + Sync,
+) -> Vec<T> {
+    if threads == 1 {
+        return work(range);
+    }
+    let size = (range.end - range.start).div_ceil(threads);
+    let work = &work;
+    thread::scope(|scope| {
+        let runs: Vec<_> = (0..threads)
+            .map(|t| {
+                let start = (range.start + t * size).min(range.end);
+                let end = (start + size).min(range.end);
+                scope.spawn(move || work(start..end))
+            })
+            .collect();
+        runs.into_iter()
+            .flat_map(|run| run.join().expect("a hashing thread panicked"))
+            .collect()
+    })
+}
+
+/// The digest of `salt` followed by every index in `range`, each stretched
+/// `stretch` times, in index order.
+pub fn digests(salt: &str, range: Range<u64>, stretch: usize) -> Vec<Digest> {
+    split(range, threads(), |run| {
+        let mut salted = Salted::new(salt);
+        run.map(|index| salted.digest(index).stretch(stretch))
+            .collect()
+    })
+}
+
+/// Every index from `start` on whose salted digest `check` accepts, with what
+/// `check` made of it, earliest first. See [`search`].
+pub struct Search<'a, T, F> {
+    salt: &'a str,
+    next: u64,
+    found: VecDeque<(u64, T)>,
+    check: F,
+}
+
+/// Searches the digests of `salt` followed by `start`, `start + 1` and so on.
+/// Each thread hashes its own run of indices; the matches come out in index
+/// order all the same.
+pub fn search<T, F>(salt: &str, start: u64, check: F) -> Search<'_, T, F>
+where
+    T: Send,
+    F: Fn(&Digest) -> Option<T> + Sync,
+{
+    Search {
+        salt,
+        next: start,
+        found: VecDeque::new(),
+        check,
+    }
+}
+
+impl<T, F> Iterator for Search<'_, T, F>
+where
+    T: Send,
+    F: Fn(&Digest) -> Option<T> + Sync,
+{
+    type Item = (u64, T);
+
+    fn next(&mut self) -> Option<(u64, T)> {
+        while self.found.is_empty() {
+            let threads = threads();
+            let end = self.next + threads * BATCH;
+            let (salt, check) = (self.salt, &self.check);
+            self.found.extend(split(self.next..end, threads, |run| {
+                let mut salted = Salted::new(salt);
+                run.filter_map(|index| Some((index, check(&salted.digest(index))?)))
+                    .collect()
+            }));
+            self.next = end;
+        }
+        self.found.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digests_read_as_hex() {
+        let digest = salted("abc", 5017308);
+        assert!(digest.to_string().starts_with("000008f82"));
+        assert!(digest.starts_with_zeros(5));
+        assert!(!digest.starts_with_zeros(6));
+        assert_eq!(digest.nibble(5), 8);
+        assert_eq!(&digest.hex()[..9], b"000008f82");
+        assert_eq!(digest, Digest::of(&[b"abc", b"5017", b"308"]));
+        assert_eq!(
+            salted("abc", 0).stretch(2016).to_string(),
+            "a107ff634856bb300138cac6568c0f24"
+        );
+    }
+
+    #[test]
+    fn search_keeps_index_order() {
+        let found: Vec<(u64, u8)> = search("abc", 3_000_000, |d| {
+            d.starts_with_zeros(5).then(|| d.nibble(5))
+        })
+        .take(3)
+        .collect();
+        assert_eq!(found, [(3231929, 1), (5017308, 8), (5278568, 0xf)]);
+        assert_eq!(digests("abc", 39..40, 0), [salted("abc", 39)]);
+        let runs = split(3..13, 4, |run| vec![run]);
+        assert_eq!(runs, [3..6, 6..9, 9..12, 12..13]);
+    }
+}
@@ -0,0 +1,247 @@
+//! Assembunny, the language of 2016's Easter Bunny HQ: `cpy`, `inc`, `dec` and
+//! `jnz`, plus `tgl`, which rewrites the program as it runs, and `out`.
+
+use std::fmt;
+
+use aoc_common::{ParseError, parse};
+
+use crate::operand::{end, opcode};
+use crate::{Flow, Instruction as _, Machine, Operand, Reg, Step};
+
+/// An instruction. Operands that the program text requires to be registers
+/// are still [`Operand`]s, since `tgl` can turn, say, `jnz 1 2` into the
+/// meaningless `cpy 1 2`, which is then skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+}
+
+impl Instruction {
+    /// The instruction `tgl` turns this one into.
+    pub fn toggled(self) -> Self {
+        match self {
+            Instruction::Inc(x) => Instruction::Dec(x),
+            Instruction::Dec(x) | Instruction::Tgl(x) | Instruction::Out(x) => Instruction::Inc(x),
+            Instruction::Jnz(x, y) => Instruction::Cpy(x, y),
+            Instruction::Cpy(x, y) => Instruction::Jnz(x, y),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::write_instruction(*self, f)
+    }
+}
+
+impl crate::Instruction for Instruction {
+    const MNEMONICS: &'static [&'static str] = &["cpy", "inc", "dec", "jnz", "tgl", "out"];
+
+    fn opcode(&self) -> usize {
+        match self {
+            Instruction::Cpy(..) => 0,
+            Instruction::Inc(_) => 1,
+            Instruction::Dec(_) => 2,
+            Instruction::Jnz(..) => 3,
+            Instruction::Tgl(_) => 4,
+            Instruction::Out(_) => 5,
+        }
+    }
+
+    fn operands(self) -> Vec<Operand> {
+        match self {
+            Instruction::Cpy(x, y) | Instruction::Jnz(x, y) => vec![x, y],
+            Instruction::Inc(x)
+            | Instruction::Dec(x)
+            | Instruction::Tgl(x)
+            | Instruction::Out(x) => {
+                vec![x]
+            }
+        }
+    }
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        match self {
+            Instruction::Cpy(x, Operand::Reg(y)) => machine.registers[y] = machine.value(x),
+            Instruction::Inc(Operand::Reg(x)) => machine.registers[x] += 1,
+            Instruction::Dec(Operand::Reg(x)) => machine.registers[x] -= 1,
+            Instruction::Jnz(x, y) if machine.value(x) != 0 => return Flow::Jump(machine.value(y)),
+            Instruction::Tgl(x) => {
+                let target = usize::try_from(machine.pc + machine.value(x)).ok();
+                if let Some(target) = target.and_then(|t| machine.program.get_mut(t)) {
+                    *target = target.toggled();
+                }
+            }
+            Instruction::Out(x) => return Flow::Output(machine.value(x)),
+            Instruction::Cpy(..)
+            | Instruction::Inc(_)
+            | Instruction::Dec(_)
+            | Instruction::Jnz(..) => {}
+        }
+        Flow::Next
+    }
+}
+
+/// Like [`Machine::run`], but the loops assembunny uses to add and multiply
+/// are carried out in one go. They are looked for whenever the program counter
+/// reaches one, so code that `tgl` has rewritten into such a loop is sped up
+/// too. The instructions a loop stands for are not counted.
+pub fn run_fast(machine: &mut Machine<Instruction>) -> Step {
+    loop {
+        match step_fast(machine) {
+            Step::Ran | Step::Output(_) => {}
+            stop => return stop,
+        }
+    }
+}
+
+/// Like [`Machine::step`], but a whole add or multiply loop is a single step,
+/// as in [`run_fast`].
+pub fn step_fast(machine: &mut Machine<Instruction>) -> Step {
+    if shortcut(machine) {
+        Step::Ran
+    } else {
+        machine.step()
+    }
+}
+
+// Carries out the loop at the program counter, if there is one and it would
+// end: its counters must start above zero.
+fn shortcut(machine: &mut Machine<Instruction>) -> bool {
+    let Ok(pc) = usize::try_from(machine.pc) else {
+        return false;
+    };
+    let code = machine.program.get(pc..).unwrap_or_default();
+    let (multiply, add) = (multiply_loop(code), add_loop(code));
+
+    if let Some((times, (x, c), d)) = multiply {
+        let (times, by) = (machine.value(times), machine.registers[d]);
+        if times > 0 && by > 0 {
+            machine.registers[x] += times * by;
+            machine.registers[c] = 0;
+            machine.registers[d] = 0;
+            machine.pc += 6;
+            return true;
+        }
+    }
+    if let Some((x, y)) = add
+        && machine.registers[y] > 0
+    {
+        machine.registers[x] += machine.registers[y];
+        machine.registers[y] = 0;
+        machine.pc += 3;
+        return true;
+    }
+    false
+}
+
+// `inc x`, `dec y`, `jnz y -2` in either order of the first two: x += y.
+fn add_loop(code: &[Instruction]) -> Option<(Reg, Reg)> {
+    use Instruction::{Dec, Inc, Jnz};
+    use Operand::{Reg as R, Value};
+
+    match *code.get(..3)? {
+        [Inc(R(x)), Dec(R(y)), Jnz(R(z), Value(-2))]
+        | [Dec(R(y)), Inc(R(x)), Jnz(R(z), Value(-2))]
+            if y == z && x != y =>
+        {
+            Some((x, y))
+        }
+        _ => None,
+    }
+}
+
+// `cpy n c`, an add loop of c into x, `dec d`, `jnz d -5`: x += n * d.
+fn multiply_loop(code: &[Instruction]) -> Option<(Operand, (Reg, Reg), Reg)> {
+    use Instruction::{Cpy, Dec, Jnz};
+    use Operand::{Reg as R, Value};
+
+    let Cpy(n, R(c)) = *code.first()? else {
+        return None;
+    };
+    let (x, counter) = add_loop(&code[1..])?;
+    let [Dec(R(d)), Jnz(R(e), Value(-5))] = *code.get(4..6)? else {
+        return None;
+    };
+    let distinct = counter == c && d == e && d != x && d != c;
+    let fixed = !matches!(n, R(r) if [x, c, d].contains(&r));
+    (distinct && fixed).then_some((n, (x, c), d))
+}
+
+fn parse_instruction(line: &str, mnemonics: &[&str]) -> Result<Instruction, ParseError> {
+    let register = |index| Reg::parse(line, index, 'd').map(Operand::Reg);
+    let operand = |index| Operand::parse(line, index, 'd');
+    let (instruction, arity) = match opcode(line, mnemonics)? {
+        "cpy" => (Instruction::Cpy(operand(1)?, register(2)?), 2),
+        "inc" => (Instruction::Inc(register(1)?), 1),
+        "dec" => (Instruction::Dec(register(1)?), 1),
+        "jnz" => (Instruction::Jnz(operand(1)?, operand(2)?), 2),
+        "tgl" => (Instruction::Tgl(operand(1)?), 1),
+        _ => (Instruction::Out(operand(1)?), 1),
+    };
+    end(line, arity, instruction)
+}
+
+/// Reads a program, one instruction per line, that only uses the instructions
+/// in `mnemonics`: every day of assembunny knows a different part of it.
+pub fn parse(input: &str, mnemonics: &[&str]) -> Result<Vec<Instruction>, ParseError> {
+    debug_assert!(mnemonics.iter().all(|m| Instruction::MNEMONICS.contains(m)));
+    parse::lines(input.trim_end(), |line| {
+        parse_instruction(line.trim(), mnemonics)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggling_rewrites_the_program() {
+        let program = parse(
+            "cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a",
+            &["cpy", "dec", "tgl"],
+        )
+        .unwrap();
+        let mut machine = Machine::new(program);
+        machine.run();
+        assert_eq!(machine.register('a'), 3);
+        assert_eq!(
+            machine.program[3],
+            Instruction::Inc(Operand::Reg(Reg::new('a')))
+        );
+    }
+
+    #[test]
+    fn only_the_given_instructions_are_known() {
+        let err = parse("cpy 41 a\ntgl a", &["cpy", "inc", "dec", "jnz"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected one of cpy, inc, dec, jnz, found `tgl`"
+        );
+        let program = parse("out 5", &["out"]).unwrap();
+        assert_eq!(program, [Instruction::Out(Operand::Value(5))]);
+    }
+
+    #[test]
+    fn loops_are_shortcut() {
+        // d = 6 * 4 with b copied in as the inner counter, then a = 5 + d.
+        let program = parse(
+            "cpy 6 b\ncpy 4 c\ncpy b a\ninc d\ndec a\njnz a -2\ndec c\njnz c -5\n\
+             cpy 5 a\ndec d\ninc a\njnz d -2",
+            &["cpy", "inc", "dec", "jnz"],
+        )
+        .unwrap();
+        let mut slow = Machine::new(program.clone());
+        assert_eq!(slow.run(), Step::Halted);
+        let mut fast = Machine::new(program);
+        assert_eq!(run_fast(&mut fast), Step::Halted);
+        assert_eq!(fast.registers, slow.registers);
+        assert_eq!(fast.register('a'), 29);
+        assert!(fast.executed() < 10);
+    }
+}
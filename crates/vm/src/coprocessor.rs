@@ -0,0 +1,73 @@
+//! The experimental coprocessor of 2017, with `set`, `sub`, `mul` and `jnz`
+//! over the registers `a` to `h`.
+
+use std::fmt;
+
+use aoc_common::{ParseError, parse};
+
+use crate::operand::{end, opcode};
+use crate::{Flow, Instruction as _, Machine, Operand, Reg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Set(Reg, Operand),
+    Sub(Reg, Operand),
+    Mul(Reg, Operand),
+    Jnz(Operand, Operand),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::write_instruction(*self, f)
+    }
+}
+
+impl crate::Instruction for Instruction {
+    const MNEMONICS: &'static [&'static str] = &["set", "sub", "mul", "jnz"];
+
+    fn opcode(&self) -> usize {
+        match self {
+            Instruction::Set(..) => 0,
+            Instruction::Sub(..) => 1,
+            Instruction::Mul(..) => 2,
+            Instruction::Jnz(..) => 3,
+        }
+    }
+
+    fn operands(self) -> Vec<Operand> {
+        match self {
+            Instruction::Set(x, y) | Instruction::Sub(x, y) | Instruction::Mul(x, y) => {
+                vec![Operand::Reg(x), y]
+            }
+            Instruction::Jnz(x, y) => vec![x, y],
+        }
+    }
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        match self {
+            Instruction::Set(x, y) => machine.registers[x] = machine.value(y),
+            Instruction::Sub(x, y) => machine.registers[x] -= machine.value(y),
+            Instruction::Mul(x, y) => machine.registers[x] *= machine.value(y),
+            Instruction::Jnz(x, y) if machine.value(x) != 0 => return Flow::Jump(machine.value(y)),
+            Instruction::Jnz(..) => {}
+        }
+        Flow::Next
+    }
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let reg = || Reg::parse(line, 1, 'h');
+    let operand = |index| Operand::parse(line, index, 'h');
+    let instruction = match opcode(line, Instruction::MNEMONICS)? {
+        "set" => Instruction::Set(reg()?, operand(2)?),
+        "sub" => Instruction::Sub(reg()?, operand(2)?),
+        "mul" => Instruction::Mul(reg()?, operand(2)?),
+        _ => Instruction::Jnz(operand(1)?, operand(2)?),
+    };
+    end(line, 2, instruction)
+}
+
+/// Reads a program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input.trim_end(), |line| parse_instruction(line.trim()))
+}
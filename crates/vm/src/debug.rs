@@ -0,0 +1,324 @@
+//! Stepping through a program: breakpoints on a program counter or a kind of
+//! instruction, watchpoints on registers, the last few instructions carried
+//! out, and a trace of every one of them.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{Instruction, Machine, Operand, Reg, Step, Word};
+
+/// Where [`Debugger::run`] stops, before carrying out the instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// The instruction at this program counter.
+    Pc(Word),
+    /// Any instruction with this mnemonic.
+    Mnemonic(String),
+}
+
+impl Breakpoint {
+    fn hit<I: Instruction>(&self, machine: &Machine<I>) -> bool {
+        match self {
+            Breakpoint::Pc(pc) => machine.pc == *pc,
+            Breakpoint::Mnemonic(mnemonic) => machine
+                .current()
+                .is_some_and(|i| I::MNEMONICS[i.opcode()] == mnemonic),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Pc(pc) => write!(f, "pc {pc}"),
+            Breakpoint::Mnemonic(mnemonic) => write!(f, "`{mnemonic}`"),
+        }
+    }
+}
+
+/// Why the debugger stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// The next instruction is at a breakpoint.
+    Breakpoint(Breakpoint),
+    /// The last instruction changed a watched register.
+    Watch {
+        reg: Reg,
+        old: Word,
+        new: Word,
+    },
+    /// The next instruction waits for input.
+    Blocked,
+    Halted,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Breakpoint(breakpoint) => write!(f, "breakpoint on {breakpoint}"),
+            Stop::Watch { reg, old, new } => write!(f, "{reg} changed from {old} to {new}"),
+            Stop::Blocked => write!(f, "waiting for input"),
+            Stop::Halted => write!(f, "halted"),
+        }
+    }
+}
+
+/// An instruction that was carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Executed<I> {
+    /// Its number in the run, counting from 1.
+    pub number: u64,
+    pub pc: Word,
+    pub instruction: I,
+}
+
+impl<I: fmt::Display> fmt::Display for Executed<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{} at {}: {}", self.number, self.pc, self.instruction)
+    }
+}
+
+/// A machine run under watch.
+pub struct Debugger<I> {
+    pub machine: Machine<I>,
+    pub breakpoints: Vec<Breakpoint>,
+    pub watches: Vec<Reg>,
+    history: VecDeque<Executed<I>>,
+    history_len: usize,
+    // The registers the program names, which are the ones worth showing.
+    used: Vec<Reg>,
+    trace: Option<Box<dyn Write>>,
+    // The breakpoint last stopped at, passed over on the way on.
+    resume: Option<Word>,
+}
+
+impl<I: Instruction + fmt::Display> Debugger<I> {
+    /// Debugs `machine`, remembering the last `history_len` instructions it
+    /// carries out.
+    pub fn new(machine: Machine<I>, history_len: usize) -> Self {
+        let mut used: Vec<Reg> = machine
+            .program
+            .iter()
+            .flat_map(|i| i.operands())
+            .filter_map(|operand| match operand {
+                Operand::Reg(reg) => Some(reg),
+                Operand::Value(_) => None,
+            })
+            .collect();
+        used.sort_by_key(|reg| reg.name());
+        used.dedup();
+        Debugger {
+            machine,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+            history: VecDeque::with_capacity(history_len),
+            history_len,
+            used,
+            trace: None,
+            resume: None,
+        }
+    }
+
+    /// Writes every instruction carried out from now on to `out`, one line
+    /// each: its number, program counter, the instruction and the registers
+    /// after it, separated by tabs.
+    pub fn trace_to(&mut self, out: impl Write + 'static) {
+        self.trace = Some(Box::new(out));
+    }
+
+    /// Carries out one instruction, unless the program cannot go on or the
+    /// instruction is at a breakpoint other than the one just stopped at.
+    /// Says why to stop: before the instruction for those, after it when it
+    /// changed a watched register.
+    pub fn step(&mut self) -> io::Result<Option<Stop>> {
+        let (pc, before) = (self.machine.pc, self.machine.registers);
+        let Some(instruction) = self.machine.current() else {
+            return Ok(Some(Stop::Halted));
+        };
+        if self.resume != Some(pc)
+            && let Some(hit) = self.breakpoints.iter().find(|b| b.hit(&self.machine))
+        {
+            self.resume = Some(pc);
+            return Ok(Some(Stop::Breakpoint(hit.clone())));
+        }
+        if self.machine.step() == Step::Blocked {
+            return Ok(Some(Stop::Blocked));
+        }
+        self.resume = None;
+
+        let executed = Executed {
+            number: self.machine.executed(),
+            pc,
+            instruction,
+        };
+        if let Some(trace) = &mut self.trace {
+            let registers = self
+                .used
+                .iter()
+                .map(|&reg| format!("{reg}={}", self.machine.registers[reg]))
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(
+                trace,
+                "{}\t{pc}\t{instruction}\t{registers}",
+                executed.number
+            )?;
+        }
+        if self.history.len() == self.history_len {
+            self.history.pop_front();
+        }
+        if self.history_len > 0 {
+            self.history.push_back(executed);
+        }
+
+        let after = self.machine.registers;
+        let watch = self.watches.iter().find(|&&reg| before[reg] != after[reg]);
+        Ok(watch.map(|&reg| Stop::Watch {
+            reg,
+            old: before[reg],
+            new: after[reg],
+        }))
+    }
+
+    /// Steps until there is a reason to stop.
+    pub fn run(&mut self) -> io::Result<Stop> {
+        loop {
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+    }
+
+    /// The last instructions carried out, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = &Executed<I>> {
+        self.history.iter()
+    }
+
+    /// The program counter and every register the program names, plus the
+    /// watched ones, as `pc=3 a=1 b=0`.
+    pub fn registers(&self) -> String {
+        let mut shown = self.used.clone();
+        shown.extend(self.watches.iter().filter(|reg| !self.used.contains(reg)));
+        let mut line = format!("pc={}", self.machine.pc);
+        for reg in shown {
+            line += &format!(" {reg}={}", self.machine.registers[reg]);
+        }
+        line
+    }
+
+    /// Writes out whatever the trace still holds.
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &mut self.trace {
+            Some(trace) => trace.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::assembunny;
+
+    // A writer whose contents stay readable after it is handed over.
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn debugger() -> Debugger<assembunny::Instruction> {
+        let program = assembunny::parse(
+            "cpy 3 a\ninc b\ndec a\njnz a -2\nout b",
+            &["cpy", "inc", "dec", "jnz", "out"],
+        )
+        .unwrap();
+        Debugger::new(Machine::new(program), 3)
+    }
+
+    #[test]
+    fn stops_at_breakpoints_and_watches() {
+        let mut debugger = debugger();
+        debugger
+            .breakpoints
+            .push(Breakpoint::Mnemonic("jnz".to_string()));
+        assert_eq!(
+            debugger.run().unwrap(),
+            Stop::Breakpoint(Breakpoint::Mnemonic("jnz".into()))
+        );
+        assert_eq!(debugger.registers(), "pc=3 a=2 b=1");
+
+        debugger.breakpoints.clear();
+        debugger.breakpoints.push(Breakpoint::Pc(4));
+        debugger.watches.push(Reg::new('b'));
+        let watch = Stop::Watch {
+            reg: Reg::new('b'),
+            old: 1,
+            new: 2,
+        };
+        assert_eq!(debugger.run().unwrap(), watch);
+        assert_eq!(
+            debugger.run().unwrap(),
+            Stop::Watch {
+                reg: Reg::new('b'),
+                old: 2,
+                new: 3
+            }
+        );
+        assert_eq!(debugger.run().unwrap(), Stop::Breakpoint(Breakpoint::Pc(4)));
+        assert_eq!(debugger.run().unwrap(), Stop::Halted);
+        assert_eq!(debugger.machine.output, [3]);
+    }
+
+    #[test]
+    fn breakpoints_stop_before_the_first_instruction() {
+        let mut debugger = debugger();
+        debugger.breakpoints.push(Breakpoint::Pc(0));
+        assert_eq!(debugger.run().unwrap(), Stop::Breakpoint(Breakpoint::Pc(0)));
+        assert_eq!(debugger.machine.executed(), 0);
+        assert_eq!(debugger.run().unwrap(), Stop::Halted);
+    }
+
+    #[test]
+    fn a_watch_does_not_hide_a_breakpoint() {
+        let mut debugger = debugger();
+        debugger.watches.push(Reg::new('a'));
+        debugger.breakpoints.push(Breakpoint::Pc(1));
+        let watch = Stop::Watch {
+            reg: Reg::new('a'),
+            old: 0,
+            new: 3,
+        };
+        assert_eq!(debugger.run().unwrap(), watch);
+        assert_eq!(debugger.run().unwrap(), Stop::Breakpoint(Breakpoint::Pc(1)));
+        assert_eq!(debugger.machine.executed(), 1);
+    }
+
+    #[test]
+    fn keeps_the_last_instructions() {
+        let mut debugger = debugger();
+        let trace = Shared::default();
+        debugger.trace_to(trace.clone());
+        assert_eq!(debugger.run().unwrap(), Stop::Halted);
+
+        let history: Vec<String> = debugger.history().map(|e| e.to_string()).collect();
+        assert_eq!(
+            history,
+            ["#9 at 2: dec a", "#10 at 3: jnz a -2", "#11 at 4: out b"]
+        );
+        let trace = String::from_utf8(trace.0.take()).unwrap();
+        assert_eq!(trace.lines().count(), 11);
+        assert_eq!(trace.lines().next(), Some("1\t0\tcpy 3 a\ta=3 b=0"));
+    }
+}
@@ -0,0 +1,92 @@
+//! The duet of 2017, read the second way: `snd` sends a value to the other
+//! program and `rcv` waits for one, with `set`, `add`, `mul`, `mod` and `jgz`
+//! in between.
+
+use std::fmt;
+
+use aoc_common::{ParseError, parse};
+
+use crate::operand::{end, opcode};
+use crate::{Flow, Instruction as _, Machine, Operand, Reg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Snd(Operand),
+    Set(Reg, Operand),
+    Add(Reg, Operand),
+    Mul(Reg, Operand),
+    Mod(Reg, Operand),
+    Rcv(Reg),
+    Jgz(Operand, Operand),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::write_instruction(*self, f)
+    }
+}
+
+impl crate::Instruction for Instruction {
+    const MNEMONICS: &'static [&'static str] = &["snd", "set", "add", "mul", "mod", "rcv", "jgz"];
+
+    fn opcode(&self) -> usize {
+        match self {
+            Instruction::Snd(_) => 0,
+            Instruction::Set(..) => 1,
+            Instruction::Add(..) => 2,
+            Instruction::Mul(..) => 3,
+            Instruction::Mod(..) => 4,
+            Instruction::Rcv(_) => 5,
+            Instruction::Jgz(..) => 6,
+        }
+    }
+
+    fn operands(self) -> Vec<Operand> {
+        match self {
+            Instruction::Snd(x) => vec![x],
+            Instruction::Set(x, y)
+            | Instruction::Add(x, y)
+            | Instruction::Mul(x, y)
+            | Instruction::Mod(x, y) => vec![Operand::Reg(x), y],
+            Instruction::Rcv(x) => vec![Operand::Reg(x)],
+            Instruction::Jgz(x, y) => vec![x, y],
+        }
+    }
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        match self {
+            Instruction::Snd(x) => return Flow::Output(machine.value(x)),
+            Instruction::Set(x, y) => machine.registers[x] = machine.value(y),
+            Instruction::Add(x, y) => machine.registers[x] += machine.value(y),
+            Instruction::Mul(x, y) => machine.registers[x] *= machine.value(y),
+            Instruction::Mod(x, y) => machine.registers[x] %= machine.value(y),
+            Instruction::Rcv(x) => match machine.input.pop_front() {
+                Some(value) => machine.registers[x] = value,
+                None => return Flow::Wait,
+            },
+            Instruction::Jgz(x, y) if machine.value(x) > 0 => return Flow::Jump(machine.value(y)),
+            Instruction::Jgz(..) => {}
+        }
+        Flow::Next
+    }
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let reg = || Reg::parse(line, 1, 'z');
+    let operand = |index| Operand::parse(line, index, 'z');
+    let (instruction, arity) = match opcode(line, Instruction::MNEMONICS)? {
+        "snd" => (Instruction::Snd(operand(1)?), 1),
+        "set" => (Instruction::Set(reg()?, operand(2)?), 2),
+        "add" => (Instruction::Add(reg()?, operand(2)?), 2),
+        "mul" => (Instruction::Mul(reg()?, operand(2)?), 2),
+        "mod" => (Instruction::Mod(reg()?, operand(2)?), 2),
+        "rcv" => (Instruction::Rcv(reg()?), 1),
+        _ => (Instruction::Jgz(operand(1)?, operand(2)?), 2),
+    };
+    end(line, arity, instruction)
+}
+
+/// Reads a program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input.trim_end(), |line| parse_instruction(line.trim()))
+}
@@ -0,0 +1,241 @@
+//! A register machine for the assembly-style puzzles.
+//!
+//! The machine is shared; what the instructions do is not. Every instruction
+//! set ([`turing`], [`assembunny`], [`duet`] and [`coprocessor`]) implements
+//! [`Instruction`], and a [`Machine`] runs a program of any of them. The
+//! machine holds the registers, the program counter and the queues to and from
+//! other machines, and counts every instruction it carries out.
+
+pub mod assembunny;
+pub mod coprocessor;
+pub mod debug;
+pub mod duet;
+pub mod network;
+mod operand;
+pub mod turing;
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub use operand::{Operand, Reg};
+
+/// The value held by a register.
+pub type Word = i64;
+
+static MAX_STEPS: AtomicU64 = AtomicU64::new(100_000_000);
+
+/// How many steps a day lets a program take without the result it waits for,
+/// such as its next output, before giving up on it. 100 million unless
+/// [`set_max_steps`] says otherwise.
+pub fn max_steps() -> u64 {
+    MAX_STEPS.load(Ordering::Relaxed)
+}
+
+/// Sets [`max_steps`] for every day run from now on; `aoc --max-steps` calls
+/// this.
+pub fn set_max_steps(steps: u64) {
+    MAX_STEPS.store(steps, Ordering::Relaxed);
+}
+
+/// One instruction of an instruction set.
+pub trait Instruction: Copy {
+    /// The mnemonic of every opcode, indexed by [`Instruction::opcode`].
+    const MNEMONICS: &'static [&'static str];
+
+    fn opcode(&self) -> usize;
+
+    /// The registers and values the instruction names, in written order.
+    fn operands(self) -> Vec<Operand>;
+
+    /// Carries out the instruction on `machine`, except for moving the
+    /// program counter, which the returned [`Flow`] decides.
+    fn execute(self, machine: &mut Machine<Self>) -> Flow;
+}
+
+/// Where an instruction leaves the program counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// On to the next instruction.
+    Next,
+    /// Moved by this offset.
+    Jump(Word),
+    /// On to the next instruction, after sending a value out.
+    Output(Word),
+    /// Where it is: the instruction needs input that has not arrived yet, and
+    /// does not count as carried out.
+    Wait,
+}
+
+/// Writes `instruction` as its mnemonic followed by its operands, which is
+/// how the instruction sets display their instructions.
+fn write_instruction<I: Instruction>(instruction: I, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", I::MNEMONICS[instruction.opcode()])?;
+    for operand in instruction.operands() {
+        write!(f, " {operand}")?;
+    }
+    Ok(())
+}
+
+/// What a single [`Machine::step`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Ran,
+    Output(Word),
+    /// Waiting for input.
+    Blocked,
+    /// The program counter is outside the program.
+    Halted,
+}
+
+/// The registers `a` to `z`, all starting at 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Registers([Word; 26]);
+
+impl Index<Reg> for Registers {
+    type Output = Word;
+
+    fn index(&self, reg: Reg) -> &Word {
+        &self.0[reg.index()]
+    }
+}
+
+impl IndexMut<Reg> for Registers {
+    fn index_mut(&mut self, reg: Reg) -> &mut Word {
+        &mut self.0[reg.index()]
+    }
+}
+
+/// A program being run.
+#[derive(Debug, Clone)]
+pub struct Machine<I> {
+    /// The program, which some instruction sets rewrite as they run.
+    pub program: Vec<I>,
+    pub registers: Registers,
+    pub pc: Word,
+    /// Values waiting to be received.
+    pub input: VecDeque<Word>,
+    /// Values sent out and not yet taken.
+    pub output: VecDeque<Word>,
+    executed: u64,
+    counts: Vec<u64>,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>) -> Self {
+        Machine {
+            program,
+            registers: Registers::default(),
+            pc: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            executed: 0,
+            counts: vec![0; I::MNEMONICS.len()],
+        }
+    }
+
+    /// The value of register `name`.
+    pub fn register(&self, name: char) -> Word {
+        self.registers[Reg::new(name)]
+    }
+
+    pub fn set_register(&mut self, name: char, value: Word) {
+        self.registers[Reg::new(name)] = value;
+    }
+
+    pub fn value(&self, operand: Operand) -> Word {
+        match operand {
+            Operand::Reg(reg) => self.registers[reg],
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// The instruction at the program counter, unless the program has
+    /// halted.
+    pub fn current(&self) -> Option<I> {
+        let pc = usize::try_from(self.pc).ok()?;
+        self.program.get(pc).copied()
+    }
+
+    /// Carries out the instruction at the program counter.
+    pub fn step(&mut self) -> Step {
+        let Some(instruction) = self.current() else {
+            return Step::Halted;
+        };
+        let step = match instruction.execute(self) {
+            Flow::Wait => return Step::Blocked,
+            Flow::Next => {
+                self.pc += 1;
+                Step::Ran
+            }
+            Flow::Jump(offset) => {
+                self.pc += offset;
+                Step::Ran
+            }
+            Flow::Output(value) => {
+                self.output.push_back(value);
+                self.pc += 1;
+                Step::Output(value)
+            }
+        };
+        self.executed += 1;
+        self.counts[instruction.opcode()] += 1;
+        step
+    }
+
+    /// Steps until the program halts or waits for input, and says which.
+    pub fn run(&mut self) -> Step {
+        loop {
+            match self.step() {
+                Step::Ran | Step::Output(_) => {}
+                stop => return stop,
+            }
+        }
+    }
+
+    /// How many instructions have been carried out.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    /// How many instructions with this mnemonic have been carried out.
+    pub fn count(&self, mnemonic: &str) -> u64 {
+        let opcode = I::MNEMONICS
+            .iter()
+            .position(|&m| m == mnemonic)
+            .unwrap_or_else(|| panic!("there is no `{mnemonic}` instruction"));
+        self.counts[opcode]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_what_ran() {
+        let program = coprocessor::parse("set b 3\nmul a 2\nsub b 1\njnz b -2").unwrap();
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.step(), Step::Ran);
+        assert_eq!((machine.pc, machine.register('b')), (1, 3));
+        assert_eq!(machine.run(), Step::Halted);
+        assert_eq!(machine.count("mul"), 3);
+        assert_eq!(machine.executed(), 10);
+        assert_eq!(machine.step(), Step::Halted);
+    }
+
+    #[test]
+    fn waits_for_input() {
+        let program = duet::parse("snd 5\nrcv a\nsnd a").unwrap();
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.step(), Step::Output(5));
+        assert_eq!(machine.run(), Step::Blocked);
+        assert_eq!((machine.pc, machine.count("rcv")), (1, 0));
+
+        machine.input.push_back(7);
+        assert_eq!(machine.run(), Step::Halted);
+        assert_eq!(machine.output, [5, 7]);
+        assert_eq!(machine.count("snd"), 2);
+    }
+}
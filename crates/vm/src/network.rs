@@ -0,0 +1,235 @@
+//! Several machines run together, each passing what it sends on to others,
+//! until every one has halted or waits for input that will never come, or
+//! together they have used up their steps.
+
+use std::fmt;
+
+use crate::{Instruction, Machine, Step, Word};
+
+/// Steps a machine takes before the next one gets its turn, so that one that
+/// never waits cannot starve the others.
+const SLICE: usize = 10_000;
+
+/// Which machines receive what a machine sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Machine i sends to machine i + 1, and the last one to the first.
+    Ring,
+    /// Machines 0 and 1 send to each other, as do 2 and 3, and so on.
+    Pairwise,
+    /// Every machine sends to all the others.
+    Broadcast,
+}
+
+impl Topology {
+    fn receivers(self, from: usize, count: usize) -> Vec<usize> {
+        match self {
+            Topology::Ring if count > 1 => vec![(from + 1) % count],
+            Topology::Ring => vec![],
+            Topology::Pairwise => vec![from ^ 1],
+            Topology::Broadcast => (0..count).filter(|&to| to != from).collect(),
+        }
+    }
+}
+
+/// A machine that was left waiting for input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blocked<I> {
+    pub machine: usize,
+    pub pc: Word,
+    /// The instruction it waits on.
+    pub instruction: I,
+}
+
+/// How a run of a [`Network`] went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<I> {
+    /// Values sent by each machine.
+    pub sent: Vec<u64>,
+    /// Values received by each machine.
+    pub received: Vec<u64>,
+    /// Instructions carried out by all the machines together.
+    pub steps: u64,
+    /// The machines that had not halted, with the instruction each stopped
+    /// at. Unless the run ran out of steps they all wait for input, and the
+    /// network is deadlocked.
+    pub blocked: Vec<Blocked<I>>,
+    /// Whether the run was cut short by its step budget while some machine
+    /// could still move.
+    pub out_of_steps: bool,
+}
+
+impl<I: fmt::Display> fmt::Display for Report<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.out_of_steps {
+            writeln!(f, "ran out of steps after {} steps", self.steps)?;
+        } else if self.blocked.is_empty() {
+            writeln!(f, "every program halted after {} steps", self.steps)?;
+        } else {
+            writeln!(f, "deadlocked after {} steps", self.steps)?;
+        }
+        for (i, (sent, received)) in self.sent.iter().zip(&self.received).enumerate() {
+            write!(f, "program {i}: sent {sent}, received {received}, ")?;
+            match self.blocked.iter().find(|b| b.machine == i) {
+                Some(b) if self.out_of_steps => {
+                    writeln!(f, "stopped at `{}` at {}", b.instruction, b.pc)?
+                }
+                Some(b) => writeln!(f, "waiting on `{}` at {}", b.instruction, b.pc)?,
+                None => writeln!(f, "halted")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Machines connected by a [`Topology`].
+pub struct Network<I> {
+    pub machines: Vec<Machine<I>>,
+    topology: Topology,
+    sent: Vec<u64>,
+    delivered: Vec<u64>,
+}
+
+impl<I: Instruction> Network<I> {
+    /// Connects `machines`. Pairs need an even number of them.
+    pub fn new(machines: Vec<Machine<I>>, topology: Topology) -> Result<Self, String> {
+        let count = machines.len();
+        if topology == Topology::Pairwise && count % 2 == 1 {
+            return Err(format!("{count} programs cannot be paired up"));
+        }
+        Ok(Network {
+            machines,
+            topology,
+            sent: vec![0; count],
+            delivered: vec![0; count],
+        })
+    }
+
+    /// Gives every machine a turn in order, passing on what it sent after
+    /// each, until a whole round goes by without any machine moving or they
+    /// have carried out `max_steps` instructions between them.
+    pub fn run(mut self, max_steps: u64) -> Report<I> {
+        let count = self.machines.len();
+        let mut steps = 0;
+        let out_of_steps = loop {
+            let mut moved = false;
+            for i in 0..count {
+                let machine = &mut self.machines[i];
+                let before = machine.executed();
+                let slice = (max_steps - steps).min(SLICE as u64);
+                for _ in 0..slice {
+                    if let Step::Blocked | Step::Halted = machine.step() {
+                        break;
+                    }
+                }
+                moved |= machine.executed() > before;
+                steps += machine.executed() - before;
+
+                let values: Vec<Word> = machine.output.drain(..).collect();
+                self.sent[i] += values.len() as u64;
+                for to in self.topology.receivers(i, count) {
+                    self.machines[to].input.extend(&values);
+                    self.delivered[to] += values.len() as u64;
+                }
+            }
+            if !moved {
+                break false;
+            }
+            if steps == max_steps {
+                break true;
+            }
+        };
+
+        let received = (self.machines.iter().zip(&self.delivered))
+            .map(|(machine, delivered)| delivered - machine.input.len() as u64)
+            .collect();
+        let blocked = (self.machines.iter().enumerate())
+            .filter_map(|(i, machine)| {
+                Some(Blocked {
+                    machine: i,
+                    pc: machine.pc,
+                    instruction: machine.current()?,
+                })
+            })
+            .collect();
+        Report {
+            sent: self.sent,
+            received,
+            steps,
+            blocked,
+            out_of_steps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::duet;
+
+    fn machines(text: &str, count: usize) -> Vec<Machine<duet::Instruction>> {
+        let program = duet::parse(text).unwrap();
+        (0..count)
+            .map(|id| {
+                let mut machine = Machine::new(program.clone());
+                machine.set_register('p', id as Word);
+                machine
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_ring_passes_a_token_around() {
+        // Program 0 starts the token; every program adds its id and passes it
+        // on, twice, so the token goes round the ring two times.
+        let text = "jgz p 2\nsnd 0\nrcv a\nadd a p\nsnd a\nrcv a\nadd a p\nsnd a";
+        let report = Network::new(machines(text, 3), Topology::Ring)
+            .unwrap()
+            .run(1000);
+        assert_eq!(report.sent, [3, 2, 2]);
+        assert_eq!(report.received, [2, 2, 2]);
+        assert_eq!(report.blocked.len(), 0);
+        assert!(report.to_string().starts_with("every program halted"));
+    }
+
+    #[test]
+    fn deadlocks_are_reported() {
+        let report = Network::new(
+            machines("snd p\nrcv a\nrcv b\nrcv c", 3),
+            Topology::Broadcast,
+        )
+        .unwrap()
+        .run(1000);
+        assert_eq!(report.sent, [1, 1, 1]);
+        assert_eq!(report.received, [2, 2, 2]);
+        assert_eq!(report.steps, 9);
+        assert_eq!(
+            report.to_string(),
+            "deadlocked after 9 steps\n\
+             program 0: sent 1, received 2, waiting on `rcv c` at 3\n\
+             program 1: sent 1, received 2, waiting on `rcv c` at 3\n\
+             program 2: sent 1, received 2, waiting on `rcv c` at 3\n"
+        );
+        assert!(Network::new(machines("snd p", 3), Topology::Pairwise).is_err());
+    }
+
+    #[test]
+    fn programs_that_never_wait_run_out_of_steps() {
+        let report = Network::new(machines("snd p\njgz 1 -1", 2), Topology::Pairwise)
+            .unwrap()
+            .run(25);
+        assert!(report.out_of_steps);
+        assert_eq!(report.steps, 25);
+        assert_eq!(report.sent, [13, 0]);
+        assert!(
+            report
+                .to_string()
+                .starts_with("ran out of steps after 25 steps\n")
+        );
+        assert!(
+            report
+                .to_string()
+                .contains("program 1: sent 0, received 0, stopped at `snd p` at 0")
+        );
+    }
+}
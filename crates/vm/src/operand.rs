@@ -0,0 +1,104 @@
+//! Registers and operands, and reading them from a line of a program.
+
+use std::fmt;
+
+use aoc_common::{ParseError, parse};
+
+use crate::Word;
+
+/// One of the registers `a` to `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reg(u8);
+
+impl Reg {
+    /// The register called `name`, which must be a lowercase letter.
+    pub fn new(name: char) -> Self {
+        assert!(name.is_ascii_lowercase(), "there is no register `{name}`");
+        Reg(name as u8 - b'a')
+    }
+
+    pub fn name(self) -> char {
+        char::from(b'a' + self.0)
+    }
+
+    pub(crate) fn index(self) -> usize {
+        usize::from(self.0)
+    }
+
+    /// Reads word `index` of `line` as one of the registers `a` to `last`.
+    pub fn parse(line: &str, index: usize, last: char) -> Result<Reg, ParseError> {
+        let word = parse::word(line, index, "a register")?;
+        Reg::named(line, word, word, last)
+    }
+
+    /// The register called `name`, found as `word` in `line`, if it is one of
+    /// `a` to `last`.
+    pub(crate) fn named(line: &str, word: &str, name: &str, last: char) -> Result<Reg, ParseError> {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if ('a'..=last).contains(&c) => Ok(Reg::new(c)),
+            _ => Err(ParseError::at(
+                line,
+                word,
+                format!("a register `a` to `{last}`"),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Reg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// What an instruction reads: a register or a literal value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Reg(Reg),
+    Value(Word),
+}
+
+impl Operand {
+    /// Reads word `index` of `line` as a value or one of the registers `a` to
+    /// `last`.
+    pub fn parse(line: &str, index: usize, last: char) -> Result<Operand, ParseError> {
+        let word = parse::word(line, index, "a register or a value")?;
+        if let Ok(value) = word.parse() {
+            return Ok(Operand::Value(value));
+        }
+        Reg::named(line, word, word, last)
+            .map(Operand::Reg)
+            .map_err(|_| {
+                ParseError::at(line, word, format!("a register `a` to `{last}` or a value"))
+            })
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Reg(reg) => write!(f, "{reg}"),
+            Operand::Value(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The opcode of `line`, which must be one of `mnemonics`.
+pub(crate) fn opcode<'a>(line: &'a str, mnemonics: &[&str]) -> Result<&'a str, ParseError> {
+    let opcode = parse::word(line, 0, "an instruction")?;
+    if mnemonics.contains(&opcode) {
+        Ok(opcode)
+    } else {
+        let expected = format!("one of {}", mnemonics.join(", "));
+        Err(ParseError::at(line, opcode, expected))
+    }
+}
+
+/// `instruction`, if `line` holds nothing past its `arity` operands.
+pub(crate) fn end<I>(line: &str, arity: usize, instruction: I) -> Result<I, ParseError> {
+    match line.split_whitespace().nth(arity + 1) {
+        Some(extra) => Err(ParseError::at(line, extra, "the end of the instruction")),
+        None => Ok(instruction),
+    }
+}
@@ -0,0 +1,92 @@
+//! The two-register computer of 2015's Turing lock, with `hlf`, `tpl`, `inc`,
+//! `jmp`, `jie` and `jio`.
+
+use std::fmt;
+
+use aoc_common::{ParseError, parse};
+
+use crate::operand::{end, opcode};
+use crate::{Flow, Instruction as _, Machine, Operand, Reg, Word};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Hlf(Reg),
+    Tpl(Reg),
+    Inc(Reg),
+    Jmp(Word),
+    /// Jumps if the register is even.
+    Jie(Reg, Word),
+    /// Jumps if the register is one.
+    Jio(Reg, Word),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::write_instruction(*self, f)
+    }
+}
+
+impl crate::Instruction for Instruction {
+    const MNEMONICS: &'static [&'static str] = &["hlf", "tpl", "inc", "jmp", "jie", "jio"];
+
+    fn opcode(&self) -> usize {
+        match self {
+            Instruction::Hlf(_) => 0,
+            Instruction::Tpl(_) => 1,
+            Instruction::Inc(_) => 2,
+            Instruction::Jmp(_) => 3,
+            Instruction::Jie(..) => 4,
+            Instruction::Jio(..) => 5,
+        }
+    }
+
+    fn operands(self) -> Vec<Operand> {
+        match self {
+            Instruction::Hlf(r) | Instruction::Tpl(r) | Instruction::Inc(r) => {
+                vec![Operand::Reg(r)]
+            }
+            Instruction::Jmp(offset) => vec![Operand::Value(offset)],
+            Instruction::Jie(r, offset) | Instruction::Jio(r, offset) => {
+                vec![Operand::Reg(r), Operand::Value(offset)]
+            }
+        }
+    }
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        let registers = &mut machine.registers;
+        match self {
+            Instruction::Hlf(r) => registers[r] /= 2,
+            Instruction::Tpl(r) => registers[r] *= 3,
+            Instruction::Inc(r) => registers[r] += 1,
+            Instruction::Jmp(offset) => return Flow::Jump(offset),
+            Instruction::Jie(r, offset) if registers[r] % 2 == 0 => return Flow::Jump(offset),
+            Instruction::Jio(r, offset) if registers[r] == 1 => return Flow::Jump(offset),
+            Instruction::Jie(..) | Instruction::Jio(..) => {}
+        }
+        Flow::Next
+    }
+}
+
+// A register, written with a trailing comma when an offset follows.
+fn parse_register(line: &str, index: usize) -> Result<Reg, ParseError> {
+    let word = parse::word(line, index, "a register")?;
+    Reg::named(line, word, word.trim_end_matches(','), 'b')
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let offset = |index| parse::number(line, parse::word(line, index, "an offset")?);
+    let (instruction, arity) = match opcode(line, Instruction::MNEMONICS)? {
+        "hlf" => (Instruction::Hlf(parse_register(line, 1)?), 1),
+        "tpl" => (Instruction::Tpl(parse_register(line, 1)?), 1),
+        "inc" => (Instruction::Inc(parse_register(line, 1)?), 1),
+        "jmp" => (Instruction::Jmp(offset(1)?), 1),
+        "jie" => (Instruction::Jie(parse_register(line, 1)?, offset(2)?), 2),
+        _ => (Instruction::Jio(parse_register(line, 1)?, offset(2)?), 2),
+    };
+    end(line, arity, instruction)
+}
+
+/// Reads a program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input.trim_end(), |line| parse_instruction(line.trim()))
+}